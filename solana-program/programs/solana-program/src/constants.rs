@@ -4,6 +4,39 @@ use anchor_lang::prelude::*;
 #[constant]
 pub const SEED_GLOBAL_STATE: &[u8] = b"global_state";
 
+#[constant]
+pub const SEED_ADMIN_MULTISIG: &[u8] = b"admin_multisig";
+
+#[constant]
+pub const SEED_ADMIN_PROPOSAL: &[u8] = b"admin_proposal";
+
+#[constant]
+pub const SEED_PENDING_CONFIG: &[u8] = b"pending_config";
+
+#[constant]
+pub const SEED_ADMIN_AUTHORIZATION: &[u8] = b"admin_authorization";
+
+#[constant]
+pub const SEED_TREASURY: &[u8] = b"treasury";
+
+#[constant]
+pub const SEED_TREASURY_BUDGET: &[u8] = b"treasury_budget";
+
+#[constant]
+pub const SEED_TREASURY_PAYOUT: &[u8] = b"treasury_payout";
+
+#[constant]
+pub const SEED_GOVERNANCE: &[u8] = b"governance";
+
+#[constant]
+pub const SEED_VOTER_LOCK: &[u8] = b"voter_lock";
+
+#[constant]
+pub const SEED_GOVERNANCE_PROPOSAL: &[u8] = b"gov_proposal";
+
+#[constant]
+pub const SEED_VOTE_RECORD: &[u8] = b"vote_record";
+
 #[constant]
 pub const SEED_USER_ACCOUNT: &[u8] = b"user_account";
 
@@ -34,14 +67,78 @@ pub const SEED_STAKER_POSITION: &[u8] = b"staker_position";
 #[constant]
 pub const SEED_MODERATOR_STAKING_VAULT: &[u8] = b"moderator_staking_vault";
 
+#[constant]
+pub const SEED_MODERATOR_REGISTRY: &[u8] = b"moderator_registry";
+
+#[constant]
+pub const SEED_SERVICE_REGISTRY: &[u8] = b"service_registry";
+
+#[constant]
+pub const SEED_CREATOR_BOND: &[u8] = b"creator_bond";
+
+#[constant]
+pub const SEED_CENSORSHIP_REFUND: &[u8] = b"censorship_refund";
+
+#[constant]
+pub const SEED_PURCHASE_RECORD: &[u8] = b"purchase_record";
+
+#[constant]
+pub const SEED_PRICE_OBSERVATION: &[u8] = b"price_observation";
+
+#[constant]
+pub const SEED_CREATOR_VESTING: &[u8] = b"creator_vesting";
+
 // Time Constants
 pub const SECONDS_IN_DAY: i64 = 86400;
 pub const CLAIM_VAULT_VESTING_SECONDS: i64 = 6 * 30 * SECONDS_IN_DAY; // 6 months
-pub const ESCROW_EXPIRY_SECONDS: i64 = 24 * 3600; // 24 hours
 
-// Purchase Split (50/50 between stakers and peers escrow)
-pub const SPLIT_TO_STAKERS: u64 = 50; // 50% to collection token stakers
-pub const SPLIT_TO_PEERS_ESCROW: u64 = 50; // 50% to peers escrow
+// Creator Vesting (creator share of supply; schedule chosen at create_collection)
+pub const DEFAULT_CREATOR_VESTING_CLIFF_SECONDS: i64 = 90 * SECONDS_IN_DAY;
+pub const DEFAULT_CREATOR_VESTING_DURATION_SECONDS: i64 = 365 * SECONDS_IN_DAY;
+pub const MIN_CREATOR_VESTING_DURATION_SECONDS: i64 = 90 * SECONDS_IN_DAY; // Shortest credible unlock
+pub const MAX_CREATOR_VESTING_DURATION_SECONDS: i64 = 4 * 365 * SECONDS_IN_DAY;
+pub const ESCROW_EXPIRY_SECONDS: i64 = 24 * 3600; // 24 hours
+pub const COUNTER_NOTICE_RESPONSE_SECONDS: i64 = 14 * SECONDS_IN_DAY; // Claimant response window after a counter-notice
+pub const MODERATOR_ASSIGNMENT_TIMEOUT_SECONDS: i64 = 3 * SECONDS_IN_DAY; // Assigned moderators must resolve within this window
+pub const CONFIG_CHANGE_DELAY_SECONDS: i64 = 2 * SECONDS_IN_DAY; // Minimum timelock on GlobalState parameter changes
+pub const MIN_GOVERNANCE_VOTING_PERIOD_SECONDS: i64 = SECONDS_IN_DAY; // Shortest allowed governance voting window
+pub const MAX_GOVERNANCE_VOTING_PERIOD_SECONDS: i64 = 30 * SECONDS_IN_DAY;
+
+// Purchase Split (defaults; live values are stored in GlobalState and may be overridden per collection)
+// The peers escrow receives the post-fee amount not sent to stakers or paid as creator royalty
+pub const DEFAULT_STAKER_SPLIT_BPS: u16 = 5000; // 50% to collection token stakers
+pub const MIN_STAKER_SPLIT_BPS: u16 = 1000;     // Stakers always receive at least 10%
+pub const MAX_STAKER_SPLIT_BPS: u16 = 9000;     // Peers escrow always receives at least 10%
+pub const MIN_PEER_ESCROW_BPS: u16 = 1000;      // Floor for the escrow once a creator royalty is taken
+pub const MAX_CREATOR_ROYALTY_BPS: u16 = 2000;  // 20% of post-fee purchases paid directly to the owner
+
+// Creator Allocation (share of minted supply sent to the creator in mint_collection_tokens)
+pub const DEFAULT_CREATOR_SHARE_BPS: u16 = 1000; // 10%
+pub const MAX_CREATOR_SHARE_BPS: u16 = 2000;     // 20%
+pub const DEFAULT_CLAIM_SHARE_BPS: u16 = 1000;   // 10% of minted supply reserved for the claim vault
+
+// Collection Supply (mint_collection_tokens)
+// GlobalState bounds are in whole tokens; the cap keeps max supply in base units within u64
+// at MAX_COLLECTION_DECIMALS
+pub const DEFAULT_MIN_COLLECTION_SUPPLY: u64 = 1_000;
+pub const DEFAULT_MAX_COLLECTION_SUPPLY: u64 = 1_000_000_000;
+pub const COLLECTION_SUPPLY_CAP: u64 = 10_000_000_000;
+pub const DEFAULT_COLLECTION_DECIMALS: u8 = 6;
+pub const MAX_COLLECTION_DECIMALS: u8 = 9;
+
+// Emergency Pause Scopes (GlobalState.paused_flags)
+pub const PAUSE_PURCHASES: u8 = 1 << 0;   // purchase_access, create_access_escrow, record_price_observation
+pub const PAUSE_RELEASES: u8 = 1 << 1;    // release_escrow, burn_expired_escrow
+pub const PAUSE_STAKING: u8 = 1 << 2;     // stake_moderator, stake_collection_tokens, deposit_creator_bond, governance locks
+pub const PAUSE_UNSTAKING: u8 = 1 << 3;   // Withdrawals: unstake, reward and creator vesting claims, censorship refunds, governance unlocks
+pub const PAUSE_MINTING: u8 = 1 << 4;     // create_collection, collection updates, mint_collection_tokens, launch steps and Orca pool setup
+pub const PAUSE_MODERATION: u8 = 1 << 5;  // Ticket creation, resolution, counter-notices, reassignment
+pub const PAUSE_GOVERNANCE: u8 = 1 << 6;  // Governance proposals, votes and execution (pause changes stay open)
+pub const PAUSE_LIQUIDITY: u8 = 1 << 7;   // Orca LP fee collection, position rebalancing and vested liquidity claims
+pub const PAUSE_ALL: u8 = PAUSE_PURCHASES | PAUSE_RELEASES | PAUSE_STAKING | PAUSE_UNSTAKING | PAUSE_MINTING | PAUSE_MODERATION | PAUSE_GOVERNANCE | PAUSE_LIQUIDITY;
+
+// Hard cap on the protocol purchase fee; no admin or governance action can exceed it
+pub const MAX_PROTOCOL_FEE_BPS: u16 = 1000; // 10%
 
 // Fee Percentages (Basis Points) - Legacy, kept for backward compatibility
 pub const FEE_BASIS_POINTS: u16 = 1000; // 10%
@@ -56,14 +153,67 @@ pub const DEFAULT_PURCHASE_FEE_BASIS_POINTS: u16 = 200; // 2% default purchase f
 // Precision for reward calculations
 pub const REWARD_PRECISION: u128 = 1_000_000_000_000; // 1e12
 
+// Orca LP Fee Routing (fees harvested by collect_orca_fees)
+pub const DEFAULT_LP_FEE_STAKER_BPS: u16 = 5000; // 50% to the staking pool, the rest to the owner
+pub const MIN_LP_FEE_STAKER_BPS: u16 = 2000; // 20% - stakers always share in LP fees
+pub const MAX_LP_FEE_STAKER_BPS: u16 = 8000; // 80% - the owner always keeps a share
+pub const LIQUIDITY_REBALANCE_COOLDOWN_SECONDS: i64 = SECONDS_IN_DAY; // Minimum gap between liquidity withdrawals
+
+// Launch Price Band (initialize_orca_pool)
+// At the initial pool price, one access (access_threshold_usd) must cost between these many
+// whole collection tokens. CAPGM is valued via the GlobalState.stablecoin_pool Whirlpool.
+pub const MIN_LAUNCH_TOKENS_PER_ACCESS: u64 = 1;
+pub const MAX_LAUNCH_TOKENS_PER_ACCESS: u64 = 1_000_000;
+
+// Pool Price Oracle (record_price_observation)
+// Collections without an external oracle_feed are priced from TWAPs of their own Whirlpool
+// and the GlobalState.stablecoin_pool. Cranks are rate limited so the PRICE_OBSERVATION_SLOTS
+// ring spans at least TWAP_MIN_WINDOW_SECONDS, which bounds the weight of any one block.
+// Each sample may move the recorded sqrt price by at most PRICE_OBSERVATION_MAX_STEP_BPS, so
+// a pool pushed for one block only shifts the average by one clamped step.
+pub const PRICE_OBSERVATION_INTERVAL_SECONDS: i64 = 5 * 60; // Minimum gap between cranks
+pub const TWAP_MIN_WINDOW_SECONDS: i64 = 30 * 60;
+pub const TWAP_MIN_SAMPLES: u8 = 6; // Samples (each from its own slot) a TWAP needs
+pub const PRICE_OBSERVATION_MAX_AGE_SECONDS: i64 = 15 * 60; // Latest sample must be this fresh
+pub const PRICE_OBSERVATION_MAX_STEP_BPS: u128 = 100; // ~2% price move per sample
+
 // Liquidity Requirements (in lamports/base units)
 // Minimum CAPGM required to pair with 80% of collection tokens
 // Set to ~$50-100 worth of CAPGM to prevent spam collections
 // Actual value should be adjusted based on CAPGM token price
 pub const MIN_INITIAL_CAPGM_LIQUIDITY: u64 = 50_000_000; // 50 CAPGM tokens (6 decimals)
 // Note: This acts as a "Cost of Business" to prevent spam while remaining
-// accessible for serious creators. Creator can recover this via their 10%
-// allocation appreciation and staking rewards.
+// accessible for serious creators. The CAPGM itself stays locked in the pool; the
+// unlocked share vests back to the creator through claim_vested_liquidity.
+
+// LP Lock (decrease_orca_liquidity, close_orca_position, claim_vested_liquidity)
+// No liquidity leaves the position for LP_LOCK_PERIOD_SECONDS after launch. Afterwards the
+// creator's CAPGM deposit, less the permanently locked share, vests linearly over
+// LP_VESTING_DURATION_SECONDS. Withdrawals may never hold more than the unlocked share of
+// either launch deposit outside the pool (anti-rug floor).
+pub const LP_LOCK_PERIOD_SECONDS: i64 = 180 * SECONDS_IN_DAY;
+pub const LP_VESTING_DURATION_SECONDS: i64 = 365 * SECONDS_IN_DAY;
+pub const LP_PERMANENT_LOCK_BPS: u16 = 5000; // 50% of each launch deposit stays in the pool
+
+// Moderator Specializations (bitflags stored in ModeratorStake.specializations)
+// Each TicketType requires one specialization - see TicketType::required_specialization
+pub const SPECIALIZATION_ILLEGAL_CONTENT: u8 = 1 << 0; // ContentReport tickets
+pub const SPECIALIZATION_COPYRIGHT: u8 = 1 << 1;       // CopyrightClaim tickets
+pub const SPECIALIZATION_CID_CENSORSHIP: u8 = 1 << 2;  // CidCensorship tickets
+pub const SPECIALIZATION_ALL: u8 = SPECIALIZATION_ILLEGAL_CONTENT | SPECIALIZATION_COPYRIGHT | SPECIALIZATION_CID_CENSORSHIP;
+
+// Moderator Assignment
+// Tickets are assigned to a stake-weighted random subset of registered moderators,
+// seeded from the SlotHashes sysvar, to prevent front-running of resolve instructions
+pub const MODERATORS_PER_TICKET: usize = 3;
+
+// Service Registry
+// CAPGM bond an operator must post to list an indexer or node registry endpoint.
+// Forfeited to the treasury when a ServiceEndpointReport ticket against the endpoint is upheld.
+// A deregistered bond stays in the vault (and slashable) for SERVICE_UNBONDING_PERIOD_SECONDS,
+// long enough to report misbehaviour just before the exit and have the ticket resolved.
+pub const MIN_SERVICE_ENDPOINT_BOND: u64 = 100_000_000; // 100 CAPGM (6 decimals)
+pub const SERVICE_UNBONDING_PERIOD_SECONDS: i64 = 14 * SECONDS_IN_DAY;
 
 // Peer List Limits
 // Maximum number of peers that can be paid in a single release_escrow transaction
@@ -34,4 +34,76 @@ pub enum ProtocolError {
     InvalidAccount,
     #[msg("Peer list length exceeds maximum allowed limit.")]
     PeerListTooLong,
-}
\ No newline at end of file
+    #[msg("No censorship refund is available for this ticket.")]
+    NoRefundAvailable,
+    #[msg("A counter-notice has already been submitted for this ticket.")]
+    CounterNoticeAlreadySubmitted,
+    #[msg("The counter-notice response window has not elapsed yet.")]
+    CounterNoticeWindowActive,
+    #[msg("Moderator is not registered for this ticket type.")]
+    ModeratorNotEligible,
+    #[msg("Moderator registry is full.")]
+    ModeratorRegistryFull,
+    #[msg("Moderator is not assigned to this ticket.")]
+    ModeratorNotAssigned,
+    #[msg("No registered moderator is eligible to resolve this ticket.")]
+    NoEligibleModerators,
+    #[msg("The moderator assignment has not timed out yet.")]
+    AssignmentNotExpired,
+    #[msg("Invalid multisig signer set or threshold.")]
+    InvalidMultisigConfig,
+    #[msg("Signer has already approved this proposal.")]
+    ProposalAlreadyApproved,
+    #[msg("Proposal has already been executed.")]
+    ProposalAlreadyExecuted,
+    #[msg("Proposal does not have enough approvals.")]
+    InsufficientApprovals,
+    #[msg("The admin authorization does not match this instruction.")]
+    AuthorizationMismatch,
+    #[msg("The timelock for this change has not elapsed yet.")]
+    TimelockNotElapsed,
+    #[msg("This operation is paused.")]
+    ProtocolPaused,
+    #[msg("Disbursement exceeds the remaining treasury budget.")]
+    BudgetExceeded,
+    #[msg("Locked CAPGM is below the governance proposal threshold.")]
+    BelowProposalThreshold,
+    #[msg("Voting on this proposal has closed.")]
+    VotingClosed,
+    #[msg("Proposal did not reach quorum or a majority in favour.")]
+    ProposalNotPassed,
+    #[msg("Governance does not hold the protocol admin authority.")]
+    GovernanceNotAdmin,
+    #[msg("Tokens are locked until the proposals they voted on have closed.")]
+    TokensLocked,
+    #[msg("Account is already on the current layout version.")]
+    AccountAlreadyMigrated,
+    #[msg("Service registry is full.")]
+    ServiceRegistryFull,
+    #[msg("Service endpoint is not registered.")]
+    ServiceEndpointNotFound,
+    #[msg("Video count can only grow, and not after copyright claims have been paid.")]
+    InvalidVideoCount,
+    #[msg("Collection is not at the required launch stage.")]
+    InvalidLaunchStage,
+    #[msg("Payment mint is not accepted for swap purchases.")]
+    UnsupportedPaymentMint,
+    #[msg("Swap returned less than the requested minimum.")]
+    SlippageExceeded,
+    #[msg("Whirlpool config, token order or initial price is not allowed.")]
+    InvalidPoolConfig,
+    #[msg("Liquidity is still locked, or the withdrawal exceeds the vested amount.")]
+    LiquidityLocked,
+    #[msg("A price observation was recorded too recently.")]
+    ObservationTooRecent,
+    #[msg("No vested creator tokens are available to claim.")]
+    NothingVested,
+    #[msg("Collection supply or decimals are outside the protocol bounds.")]
+    InvalidSupply,
+    #[msg("Mint supply does not match the amount minted.")]
+    SupplyMismatch,
+    #[msg("Collection ID must contain at least one ASCII letter or digit.")]
+    InvalidCollectionId,
+    #[msg("The ticket has not been resolved in the reporter's favour.")]
+    TicketNotUpheld,
+}
@@ -17,6 +17,10 @@ use mpl_token_metadata::{
 use crate::state::*;
 use crate::errors::ProtocolError;
 use crate::constants::*;
+use crate::instructions::orca::{
+    access_cost_in_tokens, whirlpool_swap_cpi, WhirlpoolSwapAccounts, MEMO_PROGRAM_ID,
+    ORCA_WHIRLPOOL_PROGRAM_ID,
+};
 
 // ============================================================================
 // Events
@@ -100,6 +104,16 @@ pub struct PurchaseAccess<'info> {
     )]
     pub access_nft_mint: AccountInfo<'info>,
 
+    /// Purchase Record PDA - will be created; proof of purchase for censorship refunds
+    #[account(
+        init,
+        payer = purchaser,
+        space = PurchaseRecord::MAX_SIZE,
+        seeds = [SEED_PURCHASE_RECORD, access_nft_mint.key().as_ref()],
+        bump
+    )]
+    pub purchase_record: Account<'info, PurchaseRecord>,
+
     /// Purchaser's NFT token account (Associated Token Account for the access NFT)
     /// CHECK: Created manually after mint initialization to avoid init order issues
     #[account(mut)]
@@ -115,7 +129,8 @@ pub struct PurchaseAccess<'info> {
     /// Global state to get treasury address
     #[account(
         seeds = [SEED_GLOBAL_STATE],
-        bump = global_state.bump
+        bump = global_state.bump,
+        constraint = !global_state.is_paused(PAUSE_PURCHASES) @ ProtocolError::ProtocolPaused
     )]
     pub global_state: Account<'info, GlobalState>,
 
@@ -128,6 +143,29 @@ pub struct PurchaseAccess<'info> {
     )]
     pub treasury_token_account: InterfaceAccount<'info, TokenAccount>,
 
+    /// Collection owner's token account (receives the creator royalty)
+    /// Required only when collection.creator_royalty_bps > 0
+    #[account(
+        mut,
+        constraint = creator_token_account.owner == collection.owner @ ProtocolError::Unauthorized,
+        constraint = creator_token_account.mint == collection_mint.key() @ ProtocolError::Unauthorized
+    )]
+    pub creator_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    /// TWAP of the collection's Whirlpool; required when collection.oracle_feed is unset
+    #[account(
+        seeds = [SEED_PRICE_OBSERVATION, collection.pool_address.as_ref()],
+        bump = pool_observation.bump
+    )]
+    pub pool_observation: Option<Box<Account<'info, PriceObservation>>>,
+
+    /// TWAP of GlobalState.stablecoin_pool; required when collection.oracle_feed is unset
+    #[account(
+        seeds = [SEED_PRICE_OBSERVATION, global_state.stablecoin_pool.as_ref()],
+        bump = stablecoin_observation.bump
+    )]
+    pub stablecoin_observation: Option<Box<Account<'info, PriceObservation>>>,
+
     pub token_program: Interface<'info, TokenInterface>,
     /// Token-2022 program for NFT with extensions
     pub token_2022_program: Program<'info, Token2022>,
@@ -149,20 +187,73 @@ pub struct PurchaseAccess<'info> {
 }
 
 /// Purchase access to a collection
-/// Splits payment: 50% to staking pool (for token holders), 50% to escrow (for peers)
+/// Splits payment between the staking pool (for token holders), escrow (for peers) and an
+/// optional creator royalty, using GlobalState.staker_split_bps or the collection's override
+/// (default 50/50, no royalty)
 /// Mints a non-transferable Access NFT to the purchaser as proof of access rights
 /// Note: Any remainder (dust) from odd amounts is added to the staking pool
 pub fn purchase_access(
     ctx: Context<PurchaseAccess>,
     total_amount: u64,
     cid_hash: [u8; 32],
+) -> Result<()> {
+    process_purchase(ctx.accounts, &ctx.bumps, total_amount, cid_hash)
+}
+
+/// Collection tokens (base units) one access (access_threshold_usd) costs at the TWAPs of
+/// the collection pool and the stablecoin/CAPGM pool
+fn twap_access_amount(accounts: &PurchaseAccess, now: i64) -> Result<u64> {
+    let pool_observation = accounts.pool_observation
+        .as_ref()
+        .ok_or(ProtocolError::InvalidOraclePrice)?;
+    let stablecoin_observation = accounts.stablecoin_observation
+        .as_ref()
+        .ok_or(ProtocolError::InvalidOraclePrice)?;
+    let global_state = &accounts.global_state;
+
+    let token_sqrt_price = pool_observation.twap_sqrt_price(now)?;
+    let stablecoin_sqrt_price = stablecoin_observation.twap_sqrt_price(now)?;
+    require!(
+        token_sqrt_price > 0 && stablecoin_sqrt_price > 0,
+        ProtocolError::InvalidOraclePrice
+    );
+    let stablecoin_is_a = global_state.stablecoin_mint < global_state.capgm_mint;
+    let stablecoin_decimals = if stablecoin_is_a {
+        stablecoin_observation.decimals_a
+    } else {
+        stablecoin_observation.decimals_b
+    };
+
+    let amount = access_cost_in_tokens(
+        accounts.collection.access_threshold_usd,
+        stablecoin_decimals,
+        token_sqrt_price,
+        stablecoin_sqrt_price,
+        stablecoin_is_a,
+    )?;
+    u64::try_from(amount).map_err(|_| ProtocolError::MathOverflow.into())
+}
+
+/// Shared by purchase_access and purchase_access_with_swap once the purchaser
+/// holds `total_amount` collection tokens
+fn process_purchase(
+    accounts: &mut PurchaseAccess,
+    bumps: &PurchaseAccessBumps,
+    total_amount: u64,
+    cid_hash: [u8; 32],
 ) -> Result<()> {
     require!(total_amount > 0, ProtocolError::InsufficientFunds);
 
-    let clock = &ctx.accounts.clock;
-    let access_escrow = &mut ctx.accounts.access_escrow;
-    let staking_pool = &mut ctx.accounts.staking_pool;
-    let collection = &ctx.accounts.collection;
+    // Collections without an external oracle_feed must pay at least one access at the pool TWAP
+    if accounts.collection.oracle_feed == Pubkey::default() {
+        let min_amount = twap_access_amount(accounts, accounts.clock.unix_timestamp)?;
+        require!(total_amount >= min_amount, ProtocolError::InsufficientFunds);
+    }
+
+    let clock = &accounts.clock;
+    let access_escrow = &mut accounts.access_escrow;
+    let staking_pool = &mut accounts.staking_pool;
+    let collection = &accounts.collection;
 
     // ⚠️ SECURITY: Prevent purchases of blacklisted collections
     // This enforces the blacklist at the blockchain level, preventing direct on-chain bypass
@@ -170,6 +261,9 @@ pub fn purchase_access(
     // Design Requirement 5.2: "official client will refuse to resolve... effectively de-platforming"
     require!(!collection.is_blacklisted, ProtocolError::Unauthorized);
 
+    // Purchases open only once the launch has completed (liquidity is live)
+    require!(collection.launch_stage == LaunchStage::Launched, ProtocolError::InvalidLaunchStage);
+
     // Verify cid_hash matches collection's cid_hash
     require!(
         cid_hash == collection.cid_hash,
@@ -181,7 +275,7 @@ pub fn purchase_access(
     // Fees are manually collected and sent to treasury, not automatically deducted
     // Default is 2% (200 basis points), but can be updated by admin via update_global_state
     // ============================================================================
-    let fee_basis_points = ctx.accounts.global_state.fee_basis_points as u64;
+    let fee_basis_points = accounts.global_state.fee_basis_points as u64;
     let fee_denominator = 10000u64;
     
     // Calculate total fee on purchase (ceiling division to favor treasury)
@@ -198,22 +292,39 @@ pub fn purchase_access(
         .checked_sub(total_fee)
         .ok_or(ProtocolError::MathOverflow)?;
 
-    // Calculate 50/50 split of remaining amount (after fee)
+    // Split remaining amount (after fee) using the live schedule:
+    // collection override if set, otherwise GlobalState (default 50/50);
+    // the creator royalty comes out of the escrow side
+    let staker_split_bps = collection.staker_split_override_bps
+        .unwrap_or(accounts.global_state.staker_split_bps) as u64;
+    let creator_royalty_bps = collection.creator_royalty_bps as u64;
+    let escrow_split_bps = fee_denominator
+        .checked_sub(staker_split_bps)
+        .and_then(|bps| bps.checked_sub(creator_royalty_bps))
+        .ok_or(ProtocolError::InvalidFeeConfig)?;
+
     let amount_to_stakers = amount_after_fee
-        .checked_mul(SPLIT_TO_STAKERS)
+        .checked_mul(staker_split_bps)
         .ok_or(ProtocolError::MathOverflow)?
-        .checked_div(100)
+        .checked_div(fee_denominator)
         .ok_or(ProtocolError::MathOverflow)?;
     
     let amount_to_escrow = amount_after_fee
-        .checked_mul(SPLIT_TO_PEERS_ESCROW)
+        .checked_mul(escrow_split_bps)
         .ok_or(ProtocolError::MathOverflow)?
-        .checked_div(100)
+        .checked_div(fee_denominator)
+        .ok_or(ProtocolError::MathOverflow)?;
+
+    let amount_to_creator = amount_after_fee
+        .checked_mul(creator_royalty_bps)
+        .ok_or(ProtocolError::MathOverflow)?
+        .checked_div(fee_denominator)
         .ok_or(ProtocolError::MathOverflow)?;
     
     // Handle remainder (dust) from odd amounts - add to staking pool
     let total_split = amount_to_stakers
         .checked_add(amount_to_escrow)
+        .and_then(|sum| sum.checked_add(amount_to_creator))
         .ok_or(ProtocolError::MathOverflow)?;
     let remainder = amount_after_fee
         .checked_sub(total_split)
@@ -233,28 +344,28 @@ pub fn purchase_access(
         ExtensionType::NonTransferable,
     ]).map_err(|_| ProtocolError::MathOverflow)?;
     
-    let rent = ctx.accounts.rent.minimum_balance(space);
+    let rent = accounts.rent.minimum_balance(space);
     let space_u64 = u64::try_from(space).map_err(|_| ProtocolError::MathOverflow)?;
     
     // Create the mint account
     invoke_signed(
         &system_instruction::create_account(
-            ctx.accounts.purchaser.key,
-            ctx.accounts.access_nft_mint.key,
+            accounts.purchaser.key,
+            accounts.access_nft_mint.key,
             rent,
             space_u64,
             &token_2022::ID,
         ),
         &[
-            ctx.accounts.purchaser.to_account_info(),
-            ctx.accounts.access_nft_mint.to_account_info(),
-            ctx.accounts.system_program.to_account_info(),
+            accounts.purchaser.to_account_info(),
+            accounts.access_nft_mint.to_account_info(),
+            accounts.system_program.to_account_info(),
         ],
         &[],
     )?;
 
     // Initialize NonTransferable extension
-    let mut mint_data = ctx.accounts.access_nft_mint.try_borrow_mut_data()?;
+    let mut mint_data = accounts.access_nft_mint.try_borrow_mut_data()?;
     let mut mint_with_extension = StateWithExtensionsMut::<MintState>::unpack_uninitialized(&mut mint_data)?;
     
     // Initialize the NonTransferable extension first (required before mint init)
@@ -262,7 +373,7 @@ pub fn purchase_access(
     
     // Initialize the mint: supply=1, decimals=0, freeze_authority=collection (for moderation)
     mint_with_extension.base = MintState {
-        mint_authority: anchor_lang::solana_program::program_option::COption::Some(*ctx.accounts.purchaser.key),
+        mint_authority: anchor_lang::solana_program::program_option::COption::Some(*accounts.purchaser.key),
         supply: 0, // Will be minted next
         decimals: 0,
         is_initialized: true,
@@ -271,7 +382,7 @@ pub fn purchase_access(
     
     drop(mint_data); // Release the borrow
 
-    msg!("NonTransferable Access NFT mint created: {}", ctx.accounts.access_nft_mint.key());
+    msg!("NonTransferable Access NFT mint created: {}", accounts.access_nft_mint.key());
 
     // ============================================================================
     // CRITICAL: Create Associated Token Account (must be done after mint exists)
@@ -281,20 +392,20 @@ pub fn purchase_access(
     // Anchor's init_if_needed would fail because the mint doesn't exist yet when
     // the Accounts struct is validated
     let create_ata_accounts = Create {
-        payer: ctx.accounts.purchaser.to_account_info(),
-        associated_token: ctx.accounts.purchaser_nft_account.to_account_info(),
-        authority: ctx.accounts.purchaser.to_account_info(),
-        mint: ctx.accounts.access_nft_mint.to_account_info(),
-        system_program: ctx.accounts.system_program.to_account_info(),
-        token_program: ctx.accounts.token_2022_program.to_account_info(),
+        payer: accounts.purchaser.to_account_info(),
+        associated_token: accounts.purchaser_nft_account.to_account_info(),
+        authority: accounts.purchaser.to_account_info(),
+        mint: accounts.access_nft_mint.to_account_info(),
+        system_program: accounts.system_program.to_account_info(),
+        token_program: accounts.token_2022_program.to_account_info(),
     };
     let create_ata_ctx = CpiContext::new(
-        ctx.accounts.associated_token_program.to_account_info(),
+        accounts.associated_token_program.to_account_info(),
         create_ata_accounts
     );
     create(create_ata_ctx)?;
     
-    msg!("Created Associated Token Account for purchaser: {}", ctx.accounts.purchaser.key());
+    msg!("Created Associated Token Account for purchaser: {}", accounts.purchaser.key());
 
     // ============================================================================
     // CRITICAL: Mint 1 token to purchaser's Associated Token Account
@@ -302,17 +413,17 @@ pub fn purchase_access(
     
     // Now mint exactly 1 token to the purchaser's ATA
     let mint_to_accounts = MintTo {
-        mint: ctx.accounts.access_nft_mint.to_account_info(),
-        to: ctx.accounts.purchaser_nft_account.to_account_info(),
-        authority: ctx.accounts.purchaser.to_account_info(),
+        mint: accounts.access_nft_mint.to_account_info(),
+        to: accounts.purchaser_nft_account.to_account_info(),
+        authority: accounts.purchaser.to_account_info(),
     };
     let mint_to_ctx = CpiContext::new(
-        ctx.accounts.token_2022_program.to_account_info(),
+        accounts.token_2022_program.to_account_info(),
         mint_to_accounts,
     );
     mint_to(mint_to_ctx, 1)?; // Mint exactly 1 token (NFT)
     
-    msg!("Minted 1 Access NFT token to purchaser: {}", ctx.accounts.purchaser.key());
+    msg!("Minted 1 Access NFT token to purchaser: {}", accounts.purchaser.key());
 
     // ============================================================================
     // CRITICAL: Create Metaplex Token Metadata Account
@@ -351,12 +462,12 @@ pub fn purchase_access(
     
     // Create metadata account via CPI to Metaplex Token Metadata program
     let create_metadata_instruction = create_metadata_accounts_v3(
-        ctx.accounts.token_metadata_program.key(),
-        ctx.accounts.metadata_account.key(),
-        ctx.accounts.access_nft_mint.key(),
-        ctx.accounts.purchaser.key(), // mint_authority
-        ctx.accounts.purchaser.key(), // payer
-        ctx.accounts.purchaser.key(), // update_authority
+        accounts.token_metadata_program.key(),
+        accounts.metadata_account.key(),
+        accounts.access_nft_mint.key(),
+        accounts.purchaser.key(), // mint_authority
+        accounts.purchaser.key(), // payer
+        accounts.purchaser.key(), // update_authority
         metadata_data,
         false, // is_mutable: Immutable metadata ensures integrity
         None,  // collection_details
@@ -366,23 +477,23 @@ pub fn purchase_access(
     invoke_signed(
         &create_metadata_instruction,
         &[
-            ctx.accounts.metadata_account.to_account_info(),
-            ctx.accounts.access_nft_mint.to_account_info(),
-            ctx.accounts.purchaser.to_account_info(), // mint_authority
-            ctx.accounts.purchaser.to_account_info(), // payer
-            ctx.accounts.purchaser.to_account_info(), // update_authority
-            ctx.accounts.token_metadata_program.to_account_info(),
-            ctx.accounts.system_program.to_account_info(),
-            ctx.accounts.rent.to_account_info(),
+            accounts.metadata_account.to_account_info(),
+            accounts.access_nft_mint.to_account_info(),
+            accounts.purchaser.to_account_info(), // mint_authority
+            accounts.purchaser.to_account_info(), // payer
+            accounts.purchaser.to_account_info(), // update_authority
+            accounts.token_metadata_program.to_account_info(),
+            accounts.system_program.to_account_info(),
+            accounts.rent.to_account_info(),
         ],
         &[], // Purchaser signs the transaction, so no additional signers needed
     )?;
     
     msg!(
         "Created Metaplex metadata for Access NFT: {} Collection: {} Purchaser: {} PurchasedAt: {}",
-        ctx.accounts.access_nft_mint.key(),
+        accounts.access_nft_mint.key(),
         collection_id_str,
-        ctx.accounts.purchaser.key(),
+        accounts.purchaser.key(),
         clock.unix_timestamp
     );
 
@@ -391,11 +502,11 @@ pub fn purchase_access(
     // This ensures the supply stays at exactly 1 (making it a true NFT)
     // ============================================================================
     
-    let purchaser_key = ctx.accounts.purchaser.key();
-    let mint_key = ctx.accounts.access_nft_mint.key();
+    let purchaser_key = accounts.purchaser.key();
+    let mint_key = accounts.access_nft_mint.key();
     
     let set_authority_ix = set_authority(
-        ctx.accounts.token_2022_program.key,
+        accounts.token_2022_program.key,
         &mint_key,
         None, // New authority = None (revoked)
         AuthorityType::MintTokens,
@@ -406,30 +517,31 @@ pub fn purchase_access(
     invoke_signed(
         &set_authority_ix,
         &[
-            ctx.accounts.access_nft_mint.to_account_info(),
-            ctx.accounts.purchaser.to_account_info(),
-            ctx.accounts.token_2022_program.to_account_info(),
+            accounts.access_nft_mint.to_account_info(),
+            accounts.purchaser.to_account_info(),
+            accounts.token_2022_program.to_account_info(),
         ],
         &[],
     )?;
     
-    msg!("Revoked mint authority for Access NFT: {}", ctx.accounts.access_nft_mint.key());
+    msg!("Revoked mint authority for Access NFT: {}", accounts.access_nft_mint.key());
     
     // Store the mint address for escrow reference
-    let nft_mint_key = ctx.accounts.access_nft_mint.key();
+    let nft_mint_key = accounts.access_nft_mint.key();
 
     // ============================================================================
     // STEP 2: Initialize the escrow with NFT reference
     // ============================================================================
     
-    access_escrow.purchaser = ctx.accounts.purchaser.key();
+    access_escrow.purchaser = accounts.purchaser.key();
     access_escrow.collection = collection.key();
     access_escrow.access_nft_mint = nft_mint_key;
     access_escrow.cid_hash = cid_hash;
     access_escrow.amount_locked = amount_to_escrow; // Full amount (no fees deducted)
     access_escrow.created_at = clock.unix_timestamp;
     access_escrow.is_cid_revealed = false;
-    access_escrow.bump = ctx.bumps.access_escrow;
+    access_escrow.version = AccessEscrow::VERSION;
+    access_escrow.bump = bumps.access_escrow;
 
     // ============================================================================
     // STEP 3: Transfer purchase fee to treasury (manual fee collection on purchases)
@@ -438,27 +550,27 @@ pub fn purchase_access(
     
     if total_fee > 0 {
         let transfer_fee = TransferChecked {
-            from: ctx.accounts.purchaser_token_account.to_account_info(),
-            mint: ctx.accounts.collection_mint.to_account_info(),
-            to: ctx.accounts.treasury_token_account.to_account_info(),
-            authority: ctx.accounts.purchaser.to_account_info(),
+            from: accounts.purchaser_token_account.to_account_info(),
+            mint: accounts.collection_mint.to_account_info(),
+            to: accounts.treasury_token_account.to_account_info(),
+            authority: accounts.purchaser.to_account_info(),
         };
-        let cpi_ctx_fee = CpiContext::new(ctx.accounts.token_program.to_account_info(), transfer_fee);
-        anchor_spl::token_interface::transfer_checked(cpi_ctx_fee, total_fee, ctx.accounts.collection_mint.decimals)?;
+        let cpi_ctx_fee = CpiContext::new(accounts.token_program.to_account_info(), transfer_fee);
+        anchor_spl::token_interface::transfer_checked(cpi_ctx_fee, total_fee, accounts.collection_mint.decimals)?;
     }
 
     // ============================================================================
-    // STEP 4: Transfer 50% to staking pool (after fee deduction, including remainder)
+    // STEP 4: Transfer the staker split to staking pool (after fee deduction, including remainder)
     // ============================================================================
     
     let transfer_to_pool = TransferChecked {
-        from: ctx.accounts.purchaser_token_account.to_account_info(),
-        mint: ctx.accounts.collection_mint.to_account_info(),
-        to: ctx.accounts.pool_token_account.to_account_info(),
-        authority: ctx.accounts.purchaser.to_account_info(),
+        from: accounts.purchaser_token_account.to_account_info(),
+        mint: accounts.collection_mint.to_account_info(),
+        to: accounts.pool_token_account.to_account_info(),
+        authority: accounts.purchaser.to_account_info(),
     };
-    let cpi_ctx_pool = CpiContext::new(ctx.accounts.token_program.to_account_info(), transfer_to_pool);
-    anchor_spl::token_interface::transfer_checked(cpi_ctx_pool, final_amount_to_stakers, ctx.accounts.collection_mint.decimals)?;
+    let cpi_ctx_pool = CpiContext::new(accounts.token_program.to_account_info(), transfer_to_pool);
+    anchor_spl::token_interface::transfer_checked(cpi_ctx_pool, final_amount_to_stakers, accounts.collection_mint.decimals)?;
 
     // Distribute rewards to stakers (full amount including remainder, no fees deducted)
     if staking_pool.total_staked > 0 {
@@ -474,34 +586,263 @@ pub fn purchase_access(
     }
 
     // ============================================================================
-    // STEP 5: Transfer 50% to escrow (after fee deduction)
+    // STEP 5: Transfer the escrow split to escrow (after fee deduction)
     // ============================================================================
     
     let transfer_to_escrow = TransferChecked {
-        from: ctx.accounts.purchaser_token_account.to_account_info(),
-        mint: ctx.accounts.collection_mint.to_account_info(),
-        to: ctx.accounts.escrow_token_account.to_account_info(),
-        authority: ctx.accounts.purchaser.to_account_info(),
+        from: accounts.purchaser_token_account.to_account_info(),
+        mint: accounts.collection_mint.to_account_info(),
+        to: accounts.escrow_token_account.to_account_info(),
+        authority: accounts.purchaser.to_account_info(),
     };
-    let cpi_ctx_escrow = CpiContext::new(ctx.accounts.token_program.to_account_info(), transfer_to_escrow);
-    anchor_spl::token_interface::transfer_checked(cpi_ctx_escrow, amount_to_escrow, ctx.accounts.collection_mint.decimals)?;
+    let cpi_ctx_escrow = CpiContext::new(accounts.token_program.to_account_info(), transfer_to_escrow);
+    anchor_spl::token_interface::transfer_checked(cpi_ctx_escrow, amount_to_escrow, accounts.collection_mint.decimals)?;
+
+    // ============================================================================
+    // STEP 6: Pay the creator royalty (if the owner configured one)
+    // ============================================================================
+
+    if amount_to_creator > 0 {
+        let creator_token_account = accounts.creator_token_account
+            .as_ref()
+            .ok_or(ProtocolError::InvalidAccount)?;
+        let transfer_to_creator = TransferChecked {
+            from: accounts.purchaser_token_account.to_account_info(),
+            mint: accounts.collection_mint.to_account_info(),
+            to: creator_token_account.to_account_info(),
+            authority: accounts.purchaser.to_account_info(),
+        };
+        let cpi_ctx_creator = CpiContext::new(accounts.token_program.to_account_info(), transfer_to_creator);
+        anchor_spl::token_interface::transfer_checked(cpi_ctx_creator, amount_to_creator, accounts.collection_mint.decimals)?;
+    }
 
     msg!(
-        "AccessPurchased: Purchaser={} Collection={} NFT={} Total={} Fee={} ToStakers={} ToEscrow={} Remainder={} ExpiresAt={}",
-        ctx.accounts.purchaser.key(),
+        "AccessPurchased: Purchaser={} Collection={} NFT={} Total={} Fee={} ToStakers={} ToEscrow={} ToCreator={} Remainder={} ExpiresAt={}",
+        accounts.purchaser.key(),
         collection.collection_id,
         nft_mint_key,
         total_amount,
         total_fee,
         final_amount_to_stakers,
         amount_to_escrow,
+        amount_to_creator,
         remainder,
         clock.unix_timestamp + ESCROW_EXPIRY_SECONDS
     );
 
+    // Record and count the purchase so censorship refunds can be split pro-rata across
+    // the purchasers who bought before the censorship
+    let purchase_record = &mut accounts.purchase_record;
+    purchase_record.collection = accounts.collection.key();
+    purchase_record.purchaser = accounts.purchaser.key();
+    purchase_record.access_nft_mint = nft_mint_key;
+    purchase_record.purchase_index = accounts.collection.total_purchases;
+    purchase_record.purchased_at = clock.unix_timestamp;
+    purchase_record.version = PurchaseRecord::VERSION;
+    purchase_record.bump = bumps.purchase_record;
+
+    let collection = &mut accounts.collection;
+    collection.total_purchases = collection.total_purchases
+        .checked_add(1)
+        .ok_or(ProtocolError::MathOverflow)?;
+
     Ok(())
 }
 
+// ============================================================================
+// Purchase Access With Swap - Pay in CAPGM or the configured stablecoin
+// ============================================================================
+
+#[derive(Accounts)]
+pub struct PurchaseAccessWithSwap<'info> {
+    /// Regular purchase accounts; purchase.purchaser_token_account receives the swap output
+    pub purchase: PurchaseAccess<'info>,
+
+    #[account(
+        constraint = capgm_mint.key() == purchase.global_state.capgm_mint @ ProtocolError::InvalidAccount
+    )]
+    pub capgm_mint: InterfaceAccount<'info, Mint>,
+
+    /// Purchaser's CAPGM account: the payment source, or the intermediate account
+    /// when paying in the stablecoin
+    #[account(
+        mut,
+        constraint = purchaser_capgm_account.owner == purchase.purchaser.key() @ ProtocolError::Unauthorized,
+        constraint = purchaser_capgm_account.mint == capgm_mint.key() @ ProtocolError::Unauthorized
+    )]
+    pub purchaser_capgm_account: InterfaceAccount<'info, TokenAccount>,
+
+    /// Required only when paying in the stablecoin configured in GlobalState
+    #[account(
+        constraint = purchase.global_state.stablecoin_mint != Pubkey::default() @ ProtocolError::UnsupportedPaymentMint,
+        constraint = stablecoin_mint.key() == purchase.global_state.stablecoin_mint @ ProtocolError::UnsupportedPaymentMint
+    )]
+    pub stablecoin_mint: Option<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        mut,
+        constraint = purchaser_stablecoin_account.owner == purchase.purchaser.key() @ ProtocolError::Unauthorized,
+        constraint = purchaser_stablecoin_account.mint == purchase.global_state.stablecoin_mint @ ProtocolError::Unauthorized
+    )]
+    pub purchaser_stablecoin_account: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Collection token / CAPGM Whirlpool
+    /// CHECK: Validated against collection
+    #[account(
+        mut,
+        constraint = whirlpool.key() == purchase.collection.pool_address @ ProtocolError::Unauthorized,
+        owner = ORCA_WHIRLPOOL_PROGRAM_ID
+    )]
+    pub whirlpool: UncheckedAccount<'info>,
+
+    /// CHECK: Managed by Orca program
+    #[account(mut)]
+    pub token_vault_a: UncheckedAccount<'info>,
+
+    /// CHECK: Managed by Orca program
+    #[account(mut)]
+    pub token_vault_b: UncheckedAccount<'info>,
+
+    /// CHECK: Managed by Orca program
+    #[account(mut)]
+    pub tick_array_0: UncheckedAccount<'info>,
+
+    /// CHECK: Managed by Orca program
+    #[account(mut)]
+    pub tick_array_1: UncheckedAccount<'info>,
+
+    /// CHECK: Managed by Orca program
+    #[account(mut)]
+    pub tick_array_2: UncheckedAccount<'info>,
+
+    /// CHECK: Managed by Orca program
+    #[account(mut)]
+    pub oracle: UncheckedAccount<'info>,
+
+    /// CHECK: Orca Whirlpool program (validated by address constraint)
+    #[account(address = ORCA_WHIRLPOOL_PROGRAM_ID)]
+    pub whirlpool_program: UncheckedAccount<'info>,
+
+    /// CHECK: SPL Memo program (validated by address constraint)
+    #[account(address = MEMO_PROGRAM_ID)]
+    pub memo_program: UncheckedAccount<'info>,
+}
+
+/// Purchase access paying in CAPGM, or in GlobalState.stablecoin_mint, instead of the
+/// collection token. The payment is swapped through the collection's Whirlpool (a
+/// stablecoin payment first hops into CAPGM through GlobalState.stablecoin_pool) and the
+/// collection tokens received go through the regular purchase_access split.
+/// min_amount_out bounds slippage across the whole route.
+///
+/// Stablecoin payments pass the stablecoin/CAPGM pool as remaining_accounts:
+/// [whirlpool, token_vault_a, token_vault_b, tick_array_0, tick_array_1, tick_array_2, oracle]
+pub fn purchase_access_with_swap<'info>(
+    ctx: Context<'_, '_, '_, 'info, PurchaseAccessWithSwap<'info>>,
+    amount_in: u64,
+    min_amount_out: u64,
+    cid_hash: [u8; 32],
+) -> Result<()> {
+    require!(amount_in > 0 && min_amount_out > 0, ProtocolError::InsufficientFunds);
+
+    let accounts = ctx.accounts;
+    let purchaser = accounts.purchase.purchaser.to_account_info();
+    let token_program = accounts.purchase.token_program.to_account_info();
+    let memo_program = accounts.memo_program.to_account_info();
+
+    // First hop (stablecoin payments only): stablecoin -> CAPGM
+    let (paid_mint, capgm_in) = match (&accounts.stablecoin_mint, &accounts.purchaser_stablecoin_account) {
+        (None, None) => (accounts.capgm_mint.key(), amount_in),
+        (Some(stablecoin_mint), Some(purchaser_stablecoin_account)) => {
+            let [whirlpool, token_vault_a, token_vault_b, tick_array_0, tick_array_1, tick_array_2, oracle] =
+                ctx.remaining_accounts
+            else {
+                return err!(ProtocolError::InvalidAccount);
+            };
+            require!(
+                whirlpool.key() == accounts.purchase.global_state.stablecoin_pool
+                    && *whirlpool.owner == ORCA_WHIRLPOOL_PROGRAM_ID,
+                ProtocolError::InvalidAccount
+            );
+
+            // Whirlpools order their mints by key
+            let stablecoin_is_a = stablecoin_mint.key() < accounts.capgm_mint.key();
+            let stablecoin = (stablecoin_mint.to_account_info(), purchaser_stablecoin_account.to_account_info());
+            let capgm = (accounts.capgm_mint.to_account_info(), accounts.purchaser_capgm_account.to_account_info());
+            let ((token_mint_a, token_owner_account_a), (token_mint_b, token_owner_account_b)) =
+                if stablecoin_is_a { (stablecoin, capgm) } else { (capgm, stablecoin) };
+
+            let capgm_before = accounts.purchaser_capgm_account.amount;
+            whirlpool_swap_cpi(
+                &WhirlpoolSwapAccounts {
+                    token_program: token_program.clone(),
+                    memo_program: memo_program.clone(),
+                    token_authority: purchaser.clone(),
+                    whirlpool: whirlpool.clone(),
+                    token_mint_a,
+                    token_mint_b,
+                    token_owner_account_a,
+                    token_vault_a: token_vault_a.clone(),
+                    token_owner_account_b,
+                    token_vault_b: token_vault_b.clone(),
+                    tick_array_0: tick_array_0.clone(),
+                    tick_array_1: tick_array_1.clone(),
+                    tick_array_2: tick_array_2.clone(),
+                    oracle: oracle.clone(),
+                },
+                amount_in,
+                1,
+                stablecoin_is_a,
+            )?;
+            accounts.purchaser_capgm_account.reload()?;
+            let capgm_out = accounts.purchaser_capgm_account.amount
+                .checked_sub(capgm_before)
+                .ok_or(ProtocolError::MathOverflow)?;
+            (stablecoin_mint.key(), capgm_out)
+        }
+        _ => return err!(ProtocolError::InvalidAccount),
+    };
+
+    // Final hop: CAPGM (token B) -> collection token (token A)
+    let tokens_before = accounts.purchase.purchaser_token_account.amount;
+    whirlpool_swap_cpi(
+        &WhirlpoolSwapAccounts {
+            token_program,
+            memo_program,
+            token_authority: purchaser,
+            whirlpool: accounts.whirlpool.to_account_info(),
+            token_mint_a: accounts.purchase.collection_mint.to_account_info(),
+            token_mint_b: accounts.capgm_mint.to_account_info(),
+            token_owner_account_a: accounts.purchase.purchaser_token_account.to_account_info(),
+            token_vault_a: accounts.token_vault_a.to_account_info(),
+            token_owner_account_b: accounts.purchaser_capgm_account.to_account_info(),
+            token_vault_b: accounts.token_vault_b.to_account_info(),
+            tick_array_0: accounts.tick_array_0.to_account_info(),
+            tick_array_1: accounts.tick_array_1.to_account_info(),
+            tick_array_2: accounts.tick_array_2.to_account_info(),
+            oracle: accounts.oracle.to_account_info(),
+        },
+        capgm_in,
+        min_amount_out,
+        false,
+    )?;
+    accounts.purchase.purchaser_token_account.reload()?;
+    let tokens_out = accounts.purchase.purchaser_token_account.amount
+        .checked_sub(tokens_before)
+        .ok_or(ProtocolError::MathOverflow)?;
+    require!(tokens_out >= min_amount_out, ProtocolError::SlippageExceeded);
+
+    msg!(
+        "AccessSwapExecuted: Purchaser={} PaidMint={} AmountIn={} CapgmIn={} CollectionTokensOut={}",
+        accounts.purchase.purchaser.key(),
+        paid_mint,
+        amount_in,
+        capgm_in,
+        tokens_out
+    );
+
+    process_purchase(&mut accounts.purchase, &ctx.bumps.purchase, tokens_out, cid_hash)
+}
+
 // ============================================================================
 // Legacy Create Access Escrow (kept for backward compatibility)
 // ============================================================================
@@ -511,6 +852,13 @@ pub struct CreateAccessEscrow<'info> {
     #[account(mut)]
     pub purchaser: Signer<'info>,
 
+    #[account(
+        seeds = [SEED_GLOBAL_STATE],
+        bump = global_state.bump,
+        constraint = !global_state.is_paused(PAUSE_PURCHASES) @ ProtocolError::ProtocolPaused
+    )]
+    pub global_state: Account<'info, GlobalState>,
+
     #[account(
         mut,
         seeds = [b"collection", collection.owner.as_ref(), collection.collection_id.as_bytes()],
@@ -566,6 +914,9 @@ pub fn create_access_escrow(
     // This enforces the blacklist at the blockchain level, preventing direct on-chain bypass
     require!(!collection.is_blacklisted, ProtocolError::Unauthorized);
 
+    // Purchases open only once the launch has completed (liquidity is live)
+    require!(collection.launch_stage == LaunchStage::Launched, ProtocolError::InvalidLaunchStage);
+
     // Verify cid_hash matches collection's cid_hash
     require!(
         cid_hash == collection.cid_hash,
@@ -580,6 +931,7 @@ pub fn create_access_escrow(
     access_escrow.amount_locked = amount_locked;
     access_escrow.created_at = clock.unix_timestamp;
     access_escrow.is_cid_revealed = false;
+    access_escrow.version = AccessEscrow::VERSION;
     access_escrow.bump = ctx.bumps.access_escrow;
 
     // Transfer tokens from purchaser to escrow token account
@@ -612,6 +964,13 @@ pub struct ReleaseEscrow<'info> {
     #[account(mut)]
     pub purchaser: Signer<'info>,
 
+    #[account(
+        seeds = [SEED_GLOBAL_STATE],
+        bump = global_state.bump,
+        constraint = !global_state.is_paused(PAUSE_RELEASES) @ ProtocolError::ProtocolPaused
+    )]
+    pub global_state: Account<'info, GlobalState>,
+
     #[account(
         mut,
         seeds = [b"collection", collection.owner.as_ref(), collection.collection_id.as_bytes()],
@@ -1032,6 +1391,13 @@ pub struct BurnExpiredEscrow<'info> {
     #[account(mut)]
     pub caller: Signer<'info>,
 
+    #[account(
+        seeds = [SEED_GLOBAL_STATE],
+        bump = global_state.bump,
+        constraint = !global_state.is_paused(PAUSE_RELEASES) @ ProtocolError::ProtocolPaused
+    )]
+    pub global_state: Account<'info, GlobalState>,
+
     #[account(
         seeds = [b"collection", collection.owner.as_ref(), collection.collection_id.as_bytes()],
         bump
@@ -1213,6 +1579,7 @@ pub fn reveal_cid(
     cid_reveal.pinner = pinner_key;
     cid_reveal.encrypted_cid = encrypted_cid.clone();
     cid_reveal.revealed_at = clock.unix_timestamp;
+    cid_reveal.version = CidReveal::VERSION;
     cid_reveal.bump = ctx.bumps.cid_reveal;
 
     // Mark the escrow as having CID revealed
@@ -1265,6 +1632,7 @@ pub fn initialize_peer_trust_state(ctx: Context<InitializePeerTrustState>) -> Re
     peer_trust_state.total_successful_serves = 0;
     peer_trust_state.trust_score = 0;
     peer_trust_state.last_active = clock.unix_timestamp;
+    peer_trust_state.version = PeerTrustState::VERSION;
 
     msg!(
         "PeerTrustState initialized: Peer={} TrustScore={}",
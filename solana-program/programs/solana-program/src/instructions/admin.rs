@@ -2,6 +2,28 @@
 use anchor_lang::prelude::*;
 use crate::state::*;
 use crate::constants::*;
+use crate::errors::ProtocolError;
+
+#[event]
+pub struct ConfigChangeQueued {
+    pub proposer: Pubkey,
+    pub update: ConfigUpdate,
+    pub execute_after: i64,
+}
+
+#[event]
+pub struct ConfigChangeExecuted {
+    pub executor: Pubkey,
+    pub update: ConfigUpdate,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ConfigChangeCancelled {
+    pub canceller: Pubkey,
+    pub update: ConfigUpdate,
+    pub timestamp: i64,
+}
 
 #[derive(Accounts)]
 pub struct InitializeGlobal<'info> {
@@ -27,18 +49,20 @@ pub struct InitializeGlobal<'info> {
 }
 
 /// Initialize the protocol's GlobalState
-/// fee_basis_points: Purchase fee in basis points (default: 200 = 2%)
+/// fee_basis_points: Purchase fee in basis points (default: 200 = 2%, max: MAX_PROTOCOL_FEE_BPS)
 ///                    This fee is collected on purchases and can be updated via update_global_state
+/// whirlpools_config: The Orca WhirlpoolsConfig collection pools must be created under
 pub fn initialize_protocol(
     ctx: Context<InitializeGlobal>, 
     indexer_url: String, 
     registry_url: String,
     mod_stake_min: u64,
-    fee_basis_points: u16
+    fee_basis_points: u16,
+    whirlpools_config: Pubkey
 ) -> Result<()> {
     require!(indexer_url.len() <= crate::state::MAX_URL_LEN, crate::errors::ProtocolError::StringTooLong);
     require!(registry_url.len() <= crate::state::MAX_URL_LEN, crate::errors::ProtocolError::StringTooLong);
-    require!(fee_basis_points <= 10000, crate::errors::ProtocolError::InvalidFeeConfig); // Max 100%
+    require!(fee_basis_points <= MAX_PROTOCOL_FEE_BPS, crate::errors::ProtocolError::InvalidFeeConfig);
     
     let state = &mut ctx.accounts.global_state;
     state.admin = ctx.accounts.admin.key();
@@ -49,6 +73,17 @@ pub fn initialize_protocol(
     state.capgm_mint = ctx.accounts.capgm_mint.key();
     state.fee_basis_points = fee_basis_points; // Purchase fee (default: 200 = 2%)
     state.updates_disabled = false; // Initially, updates are enabled
+    state.pending_admin = None;
+    state.staker_split_bps = DEFAULT_STAKER_SPLIT_BPS;
+    state.creator_share_bps = DEFAULT_CREATOR_SHARE_BPS;
+    state.paused_flags = 0;
+    state.guardian = None;
+    state.stablecoin_mint = Pubkey::default();
+    state.stablecoin_pool = Pubkey::default();
+    state.whirlpools_config = whirlpools_config;
+    state.min_collection_supply = DEFAULT_MIN_COLLECTION_SUPPLY;
+    state.max_collection_supply = DEFAULT_MAX_COLLECTION_SUPPLY;
+    state.version = GlobalState::VERSION;
     state.bump = ctx.bumps.global_state;
     
     msg!("Protocol initialized with purchase fee: {} basis points ({}%)", fee_basis_points, fee_basis_points as f64 / 100.0);
@@ -61,7 +96,6 @@ pub struct UpdateGlobalState<'info> {
     pub admin: Signer<'info>,
     
     #[account(
-        mut,
         seeds = [SEED_GLOBAL_STATE],
         bump = global_state.bump,
         constraint = global_state.admin == admin.key() @ crate::errors::ProtocolError::Unauthorized,
@@ -74,40 +108,161 @@ pub struct UpdateGlobalState<'info> {
     
     /// CHECK: New CAPGM mint (pass same as current capgm_mint if not updating)
     pub new_capgm_mint: UncheckedAccount<'info>,
+
+    /// CHECK: New swap-purchase stablecoin mint (pass same as current stablecoin_mint if not updating)
+    pub new_stablecoin_mint: UncheckedAccount<'info>,
+
+    /// CHECK: New stablecoin/CAPGM Whirlpool (pass same as current stablecoin_pool if not updating)
+    pub new_stablecoin_pool: UncheckedAccount<'info>,
+
+    /// CHECK: New allowlisted WhirlpoolsConfig (pass same as current whirlpools_config if not updating)
+    pub new_whirlpools_config: UncheckedAccount<'info>,
+
+    /// Only one change can be pending at a time; cancel it to queue a different one
+    #[account(
+        init,
+        payer = admin,
+        space = PendingConfigChange::MAX_SIZE,
+        seeds = [SEED_PENDING_CONFIG, global_state.key().as_ref()],
+        bump
+    )]
+    pub pending_config_change: Account<'info, PendingConfigChange>,
+
+    pub system_program: Program<'info, System>,
+    pub clock: Sysvar<'info, Clock>,
 }
 
-/// Update GlobalState fields. Only the admin can call this, and only if updates are not disabled.
+/// Queue an update to GlobalState fields. Only the admin can call this, and only if updates are not disabled.
 /// All parameters are optional - only provided fields will be updated.
+/// The change is applied by execute_config_change once CONFIG_CHANGE_DELAY_SECONDS have passed,
+/// giving users and integrators time to react (or the admin time to cancel).
 /// 
 /// fee_basis_points: Purchase fee in basis points (e.g., 200 = 2%, 150 = 1.5%)
 ///                   This fee is collected on purchases and sent to the treasury.
-///                   Must be <= 10000 (100% max).
+///                   Must be <= MAX_PROTOCOL_FEE_BPS.
+/// staker_split_bps: Share of the post-fee purchase amount sent to stakers (peers escrow gets the rest)
+/// mint_config: Share of minted collection supply sent to the creator (creator_share_bps) and the
+///                   whole-token supply bounds mint_collection_tokens accepts
+/// new_stablecoin_mint / new_stablecoin_pool: Stablecoin accepted by purchase_access_with_swap and the
+///                   stablecoin/CAPGM Whirlpool it is routed through (Pubkey::default() disables)
+/// new_whirlpools_config: The Orca WhirlpoolsConfig collection pools must be created under
 pub fn update_global_state(
     ctx: Context<UpdateGlobalState>,
     indexer_url: Option<String>,
     registry_url: Option<String>,
     mod_stake_min: Option<u64>,
     fee_basis_points: Option<u16>,
+    staker_split_bps: Option<u16>,
+    mint_config: MintConfigUpdate,
 ) -> Result<()> {
-    let state = &mut ctx.accounts.global_state;
-    
-    // Update fields only if new values are provided
-    if let Some(url) = indexer_url {
+    let state = &ctx.accounts.global_state;
+    let new_treasury = ctx.accounts.new_treasury.key();
+    let new_capgm_mint = ctx.accounts.new_capgm_mint.key();
+    let new_stablecoin_mint = ctx.accounts.new_stablecoin_mint.key();
+    let new_stablecoin_pool = ctx.accounts.new_stablecoin_pool.key();
+    let new_whirlpools_config = ctx.accounts.new_whirlpools_config.key();
+    let update = ConfigUpdate {
+        indexer_url,
+        registry_url,
+        mod_stake_min,
+        fee_basis_points,
+        treasury: (new_treasury != state.treasury).then_some(new_treasury),
+        capgm_mint: (new_capgm_mint != state.capgm_mint).then_some(new_capgm_mint),
+        staker_split_bps,
+        creator_share_bps: mint_config.creator_share_bps,
+        stablecoin_mint: (new_stablecoin_mint != state.stablecoin_mint).then_some(new_stablecoin_mint),
+        stablecoin_pool: (new_stablecoin_pool != state.stablecoin_pool).then_some(new_stablecoin_pool),
+        whirlpools_config: (new_whirlpools_config != state.whirlpools_config).then_some(new_whirlpools_config),
+        min_collection_supply: mint_config.min_collection_supply,
+        max_collection_supply: mint_config.max_collection_supply,
+    };
+
+    queue_config_change(
+        &mut ctx.accounts.pending_config_change,
+        ctx.bumps.pending_config_change,
+        ctx.accounts.admin.key(),
+        ctx.accounts.admin.key(),
+        update,
+        ctx.accounts.clock.unix_timestamp,
+    )
+}
+
+/// Validate an update up front and record it behind the timelock
+fn queue_config_change(
+    pending: &mut PendingConfigChange,
+    bump: u8,
+    proposer: Pubkey,
+    rent_payer: Pubkey,
+    update: ConfigUpdate,
+    now: i64,
+) -> Result<()> {
+    validate_config_update(&update)?;
+
+    let execute_after = now
+        .checked_add(CONFIG_CHANGE_DELAY_SECONDS)
+        .ok_or(ProtocolError::MathOverflow)?;
+    pending.proposer = proposer;
+    pending.rent_payer = rent_payer;
+    pending.update = update;
+    pending.queued_at = now;
+    pending.execute_after = execute_after;
+    pending.version = PendingConfigChange::VERSION;
+    pending.bump = bump;
+
+    emit!(ConfigChangeQueued {
+        proposer,
+        update: pending.update.clone(),
+        execute_after,
+    });
+    msg!("ConfigChangeQueued: Proposer={} ExecuteAfter={}", proposer, execute_after);
+    Ok(())
+}
+
+pub(crate) fn validate_config_update(update: &ConfigUpdate) -> Result<()> {
+    if let Some(url) = &update.indexer_url {
         require!(url.len() <= crate::state::MAX_URL_LEN, crate::errors::ProtocolError::StringTooLong);
+    }
+    if let Some(url) = &update.registry_url {
+        require!(url.len() <= crate::state::MAX_URL_LEN, crate::errors::ProtocolError::StringTooLong);
+    }
+    if let Some(fee_bp) = update.fee_basis_points {
+        require!(fee_bp <= MAX_PROTOCOL_FEE_BPS, crate::errors::ProtocolError::InvalidFeeConfig);
+    }
+    if let Some(split_bps) = update.staker_split_bps {
+        require!(
+            (MIN_STAKER_SPLIT_BPS..=MAX_STAKER_SPLIT_BPS).contains(&split_bps),
+            crate::errors::ProtocolError::InvalidFeeConfig
+        );
+    }
+    if let Some(share_bps) = update.creator_share_bps {
+        require!(share_bps <= MAX_CREATOR_SHARE_BPS, crate::errors::ProtocolError::InvalidFeeConfig);
+    }
+    for supply in [update.min_collection_supply, update.max_collection_supply].into_iter().flatten() {
+        require!(
+            (1..=COLLECTION_SUPPLY_CAP).contains(&supply),
+            crate::errors::ProtocolError::InvalidSupply
+        );
+    }
+    Ok(())
+}
+
+pub(crate) fn apply_config_update(state: &mut GlobalState, update: ConfigUpdate) -> Result<()> {
+    validate_config_update(&update)?;
+
+    // Update fields only if new values are provided
+    if let Some(url) = update.indexer_url {
         state.indexer_api_url = url;
     }
     
-    if let Some(url) = registry_url {
-        require!(url.len() <= crate::state::MAX_URL_LEN, crate::errors::ProtocolError::StringTooLong);
+    if let Some(url) = update.registry_url {
         state.node_registry_url = url;
     }
     
-    if let Some(stake_min) = mod_stake_min {
+    if let Some(stake_min) = update.mod_stake_min {
         state.moderator_stake_minimum = stake_min;
     }
     
-    if let Some(fee_bp) = fee_basis_points {
-        require!(fee_bp <= 10000, crate::errors::ProtocolError::InvalidFeeConfig); // Max 100%
+    if let Some(fee_bp) = update.fee_basis_points {
         let old_fee = state.fee_basis_points;
         state.fee_basis_points = fee_bp;
         msg!("Purchase fee updated: {} -> {} basis points ({}% -> {}%)", 
@@ -116,17 +271,170 @@ pub fn update_global_state(
              fee_bp as f64 / 100.0);
     }
     
-    // Update treasury if a different account is provided
-    if ctx.accounts.new_treasury.key() != state.treasury {
-        state.treasury = ctx.accounts.new_treasury.key();
+    if let Some(treasury) = update.treasury {
+        state.treasury = treasury;
     }
     
-    // Update CAPGM mint if a different account is provided
-    if ctx.accounts.new_capgm_mint.key() != state.capgm_mint {
-        state.capgm_mint = ctx.accounts.new_capgm_mint.key();
+    if let Some(capgm_mint) = update.capgm_mint {
+        state.capgm_mint = capgm_mint;
+    }
+
+    if let Some(split_bps) = update.staker_split_bps {
+        state.staker_split_bps = split_bps;
+    }
+    
+    if let Some(share_bps) = update.creator_share_bps {
+        state.creator_share_bps = share_bps;
+    }
+
+    if let Some(stablecoin_mint) = update.stablecoin_mint {
+        state.stablecoin_mint = stablecoin_mint;
+    }
+
+    if let Some(stablecoin_pool) = update.stablecoin_pool {
+        state.stablecoin_pool = stablecoin_pool;
+    }
+
+    if let Some(whirlpools_config) = update.whirlpools_config {
+        state.whirlpools_config = whirlpools_config;
+    }
+
+    // Bounds may be updated one at a time, so check the resulting pair
+    if let Some(min_supply) = update.min_collection_supply {
+        state.min_collection_supply = min_supply;
+    }
+
+    if let Some(max_supply) = update.max_collection_supply {
+        state.max_collection_supply = max_supply;
     }
+    require!(
+        state.min_collection_supply <= state.max_collection_supply,
+        crate::errors::ProtocolError::InvalidSupply
+    );
     
-    msg!("GlobalState updated by admin: {}", ctx.accounts.admin.key());
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct ExecuteConfigChange<'info> {
+    #[account(mut)]
+    pub executor: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [SEED_GLOBAL_STATE],
+        bump = global_state.bump,
+        constraint = !global_state.updates_disabled @ ProtocolError::Unauthorized
+    )]
+    pub global_state: Account<'info, GlobalState>,
+
+    /// CHECK: Receives the pending account's rent; must be the account that paid for it
+    #[account(
+        mut,
+        address = pending_config_change.rent_payer @ ProtocolError::Unauthorized
+    )]
+    pub rent_payer: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [SEED_PENDING_CONFIG, global_state.key().as_ref()],
+        bump = pending_config_change.bump,
+        close = rent_payer
+    )]
+    pub pending_config_change: Account<'info, PendingConfigChange>,
+
+    pub clock: Sysvar<'info, Clock>,
+}
+
+/// Permissionless: apply a queued change once its timelock has elapsed
+pub fn execute_config_change(ctx: Context<ExecuteConfigChange>) -> Result<()> {
+    let pending = &ctx.accounts.pending_config_change;
+    let now = ctx.accounts.clock.unix_timestamp;
+    require!(now >= pending.execute_after, ProtocolError::TimelockNotElapsed);
+
+    let update = pending.update.clone();
+    apply_config_update(&mut ctx.accounts.global_state, update.clone())?;
+
+    emit!(ConfigChangeExecuted {
+        executor: ctx.accounts.executor.key(),
+        update,
+        timestamp: now,
+    });
+    msg!("GlobalState updated: Executor={}", ctx.accounts.executor.key());
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct CancelConfigChange<'info> {
+    /// Admin key, a multisig signer, or the relayer of an AdminAuthorization
+    #[account(mut)]
+    pub canceller: Signer<'info>,
+
+    #[account(
+        seeds = [SEED_GLOBAL_STATE],
+        bump = global_state.bump
+    )]
+    pub global_state: Account<'info, GlobalState>,
+
+    /// Lets a single multisig signer cancel a change the multisig did not queue
+    #[account(
+        seeds = [SEED_ADMIN_MULTISIG],
+        bump = multisig.bump
+    )]
+    pub multisig: Option<Account<'info, AdminMultisig>>,
+
+    /// Required to cancel a change queued by the admin PDA itself
+    #[account(
+        mut,
+        close = canceller,
+        seeds = [SEED_ADMIN_AUTHORIZATION, admin_authorization.proposal.as_ref()],
+        bump = admin_authorization.bump
+    )]
+    pub admin_authorization: Option<Account<'info, AdminAuthorization>>,
+
+    /// CHECK: Receives the pending account's rent; must be the account that paid for it
+    #[account(
+        mut,
+        address = pending_config_change.rent_payer @ ProtocolError::Unauthorized
+    )]
+    pub rent_payer: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [SEED_PENDING_CONFIG, global_state.key().as_ref()],
+        bump = pending_config_change.bump,
+        close = rent_payer
+    )]
+    pub pending_config_change: Account<'info, PendingConfigChange>,
+
+    pub clock: Sysvar<'info, Clock>,
+}
+
+/// Discard a queued change. A single multisig signer may cancel a change the multisig
+/// did not approve (one queued by a previous admin key); a change the admin PDA queued
+/// takes a threshold-approved CancelConfigChange, so one key cannot veto an M-of-N decision.
+pub fn cancel_config_change(ctx: Context<CancelConfigChange>) -> Result<()> {
+    let canceller = ctx.accounts.canceller.key();
+    let global_state = &ctx.accounts.global_state;
+    let pending = &ctx.accounts.pending_config_change;
+    let is_multisig_signer = ctx.accounts.multisig.as_ref().is_some_and(|m| {
+        m.key() == global_state.admin && m.signers.contains(&canceller) && pending.proposer != m.key()
+    });
+    if !is_multisig_signer {
+        authorize_admin(
+            global_state,
+            &canceller,
+            &ctx.accounts.admin_authorization,
+            &AdminAction::CancelConfigChange { queued_at: pending.queued_at },
+        )?;
+    }
+
+    emit!(ConfigChangeCancelled {
+        canceller,
+        update: ctx.accounts.pending_config_change.update.clone(),
+        timestamp: ctx.accounts.clock.unix_timestamp,
+    });
+    msg!("ConfigChangeCancelled: Canceller={}", canceller);
     Ok(())
 }
 
@@ -157,3 +465,486 @@ pub fn disable_global_state_updates(ctx: Context<DisableGlobalStateUpdates>) ->
     
     Ok(())
 }
+
+// ============================================================================
+// Admin Handover (two-step)
+// ============================================================================
+
+#[derive(Accounts)]
+pub struct ProposeAdmin<'info> {
+    pub admin: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [SEED_GLOBAL_STATE],
+        bump = global_state.bump,
+        constraint = global_state.admin == admin.key() @ ProtocolError::Unauthorized
+    )]
+    pub global_state: Account<'info, GlobalState>,
+}
+
+/// Nominate a new admin. Control only moves once the nominee signs accept_admin,
+/// so a typo'd or unreachable key cannot lock the protocol out.
+/// Proposing again replaces the pending nominee.
+pub fn propose_admin(ctx: Context<ProposeAdmin>, new_admin: Pubkey) -> Result<()> {
+    require!(new_admin != Pubkey::default(), ProtocolError::InvalidAccount);
+    ctx.accounts.global_state.pending_admin = Some(new_admin);
+
+    msg!(
+        "AdminProposed: CurrentAdmin={} PendingAdmin={}",
+        ctx.accounts.admin.key(),
+        new_admin
+    );
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct AcceptAdmin<'info> {
+    pub new_admin: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [SEED_GLOBAL_STATE],
+        bump = global_state.bump,
+        constraint = global_state.pending_admin == Some(new_admin.key()) @ ProtocolError::Unauthorized
+    )]
+    pub global_state: Account<'info, GlobalState>,
+}
+
+pub fn accept_admin(ctx: Context<AcceptAdmin>) -> Result<()> {
+    let state = &mut ctx.accounts.global_state;
+    let previous_admin = state.admin;
+    state.admin = ctx.accounts.new_admin.key();
+    state.pending_admin = None;
+
+    msg!(
+        "AdminTransferred: PreviousAdmin={} NewAdmin={}",
+        previous_admin,
+        state.admin
+    );
+    Ok(())
+}
+
+// ============================================================================
+// Emergency Pause
+// ============================================================================
+
+#[derive(Accounts)]
+pub struct SetPauseFlags<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [SEED_GLOBAL_STATE],
+        bump = global_state.bump,
+        constraint = global_state.admin == authority.key()
+            || global_state.guardian == Some(authority.key()) @ ProtocolError::Unauthorized
+    )]
+    pub global_state: Account<'info, GlobalState>,
+}
+
+/// Set the PAUSE_* scopes. The admin may set any combination; the guardian may only add
+/// scopes, so a leaked guardian key can halt the protocol but never resume it.
+/// Unpausing only the withdrawal scope (PAUSE_UNSTAKING) lets users exit while everything else is halted.
+pub fn set_pause_flags(ctx: Context<SetPauseFlags>, paused_flags: u8) -> Result<()> {
+    let authority = ctx.accounts.authority.key();
+    let state = &mut ctx.accounts.global_state;
+    if authority != state.admin {
+        require!(
+            paused_flags & state.paused_flags == state.paused_flags,
+            ProtocolError::Unauthorized
+        );
+    }
+    apply_pause_flags(state, paused_flags, authority)
+}
+
+/// Every bit of paused_flags is a scope (PAUSE_ALL == u8::MAX), so any value is valid
+pub(crate) fn apply_pause_flags(state: &mut GlobalState, paused_flags: u8, authority: Pubkey) -> Result<()> {
+    let previous = state.paused_flags;
+    state.paused_flags = paused_flags;
+
+    msg!(
+        "PauseFlagsUpdated: Authority={} Previous={:#010b} Current={:#010b}",
+        authority,
+        previous,
+        paused_flags
+    );
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct SetGuardian<'info> {
+    pub admin: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [SEED_GLOBAL_STATE],
+        bump = global_state.bump,
+        constraint = global_state.admin == admin.key() @ ProtocolError::Unauthorized
+    )]
+    pub global_state: Account<'info, GlobalState>,
+}
+
+/// Assign (or clear) the guardian key allowed to pause the protocol in an emergency
+pub fn set_guardian(ctx: Context<SetGuardian>, guardian: Option<Pubkey>) -> Result<()> {
+    ctx.accounts.global_state.guardian = guardian;
+
+    msg!("GuardianUpdated: Admin={} Guardian={:?}", ctx.accounts.admin.key(), guardian);
+    Ok(())
+}
+
+// ============================================================================
+// Admin Multisig (M-of-N)
+// ============================================================================
+
+fn validate_signer_set(signers: &[Pubkey], threshold: u8) -> Result<()> {
+    require!(
+        !signers.is_empty() && signers.len() <= MAX_ADMIN_SIGNERS,
+        ProtocolError::InvalidMultisigConfig
+    );
+    require!(
+        threshold > 0 && threshold as usize <= signers.len(),
+        ProtocolError::InvalidMultisigConfig
+    );
+    for (i, signer) in signers.iter().enumerate() {
+        require!(!signers[..i].contains(signer), ProtocolError::InvalidMultisigConfig);
+    }
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct CreateAdminMultisig<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [SEED_GLOBAL_STATE],
+        bump = global_state.bump,
+        constraint = global_state.admin == admin.key() @ ProtocolError::Unauthorized
+    )]
+    pub global_state: Account<'info, GlobalState>,
+
+    #[account(
+        init,
+        payer = admin,
+        space = AdminMultisig::MAX_SIZE,
+        seeds = [SEED_ADMIN_MULTISIG],
+        bump
+    )]
+    pub multisig: Account<'info, AdminMultisig>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Hand GlobalState control to an M-of-N signer set.
+/// The multisig PDA becomes GlobalState.admin, so single-key admin instructions stop
+/// working and every privileged change must go through propose/approve/execute.
+pub fn create_admin_multisig(
+    ctx: Context<CreateAdminMultisig>,
+    signers: Vec<Pubkey>,
+    threshold: u8,
+) -> Result<()> {
+    validate_signer_set(&signers, threshold)?;
+
+    let multisig = &mut ctx.accounts.multisig;
+    multisig.signers = signers;
+    multisig.threshold = threshold;
+    multisig.proposal_count = 0;
+    multisig.version = AdminMultisig::VERSION;
+    multisig.bump = ctx.bumps.multisig;
+
+    let state = &mut ctx.accounts.global_state;
+    state.admin = multisig.key();
+    state.pending_admin = None;
+
+    msg!(
+        "AdminMultisigCreated: Multisig={} Signers={} Threshold={}",
+        multisig.key(),
+        multisig.signers.len(),
+        threshold
+    );
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct ProposeAdminAction<'info> {
+    #[account(mut)]
+    pub proposer: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [SEED_ADMIN_MULTISIG],
+        bump = multisig.bump,
+        constraint = multisig.signers.contains(&proposer.key()) @ ProtocolError::Unauthorized
+    )]
+    pub multisig: Account<'info, AdminMultisig>,
+
+    #[account(
+        init,
+        payer = proposer,
+        space = AdminProposal::MAX_SIZE,
+        seeds = [SEED_ADMIN_PROPOSAL, multisig.key().as_ref(), &multisig.proposal_count.to_le_bytes()],
+        bump
+    )]
+    pub proposal: Account<'info, AdminProposal>,
+
+    pub system_program: Program<'info, System>,
+    pub clock: Sysvar<'info, Clock>,
+}
+
+pub fn propose_admin_action(ctx: Context<ProposeAdminAction>, action: AdminAction) -> Result<()> {
+    if let AdminAction::ChangeSigners { signers, threshold } = &action {
+        validate_signer_set(signers, *threshold)?;
+    }
+
+    let multisig = &mut ctx.accounts.multisig;
+    let proposal = &mut ctx.accounts.proposal;
+    proposal.multisig = multisig.key();
+    proposal.proposal_id = multisig.proposal_count;
+    proposal.proposer = ctx.accounts.proposer.key();
+    proposal.action = action;
+    proposal.approvals = vec![ctx.accounts.proposer.key()];
+    proposal.executed = false;
+    proposal.created_at = ctx.accounts.clock.unix_timestamp;
+    proposal.version = AdminProposal::VERSION;
+    proposal.bump = ctx.bumps.proposal;
+
+    multisig.proposal_count = multisig.proposal_count
+        .checked_add(1)
+        .ok_or(ProtocolError::MathOverflow)?;
+
+    msg!(
+        "AdminProposalCreated: Proposal={} Id={} Proposer={} Action={:?}",
+        proposal.key(),
+        proposal.proposal_id,
+        proposal.proposer,
+        proposal.action
+    );
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct ApproveAdminAction<'info> {
+    pub signer: Signer<'info>,
+
+    #[account(
+        seeds = [SEED_ADMIN_MULTISIG],
+        bump = multisig.bump,
+        constraint = multisig.signers.contains(&signer.key()) @ ProtocolError::Unauthorized
+    )]
+    pub multisig: Account<'info, AdminMultisig>,
+
+    #[account(
+        mut,
+        seeds = [SEED_ADMIN_PROPOSAL, multisig.key().as_ref(), &proposal.proposal_id.to_le_bytes()],
+        bump = proposal.bump,
+        constraint = !proposal.executed @ ProtocolError::ProposalAlreadyExecuted
+    )]
+    pub proposal: Account<'info, AdminProposal>,
+}
+
+pub fn approve_admin_action(ctx: Context<ApproveAdminAction>) -> Result<()> {
+    let signer = ctx.accounts.signer.key();
+    let proposal = &mut ctx.accounts.proposal;
+    require!(!proposal.approvals.contains(&signer), ProtocolError::ProposalAlreadyApproved);
+    proposal.approvals.push(signer);
+
+    msg!(
+        "AdminProposalApproved: Proposal={} Signer={} Approvals={}/{}",
+        proposal.key(),
+        signer,
+        proposal.approvals.len(),
+        ctx.accounts.multisig.threshold
+    );
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct ExecuteAdminAction<'info> {
+    #[account(mut)]
+    pub executor: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [SEED_GLOBAL_STATE],
+        bump = global_state.bump,
+        constraint = global_state.admin == multisig.key() @ ProtocolError::Unauthorized
+    )]
+    pub global_state: Account<'info, GlobalState>,
+
+    #[account(
+        mut,
+        seeds = [SEED_ADMIN_MULTISIG],
+        bump = multisig.bump
+    )]
+    pub multisig: Account<'info, AdminMultisig>,
+
+    #[account(
+        mut,
+        seeds = [SEED_ADMIN_PROPOSAL, multisig.key().as_ref(), &proposal.proposal_id.to_le_bytes()],
+        bump = proposal.bump,
+        constraint = !proposal.executed @ ProtocolError::ProposalAlreadyExecuted
+    )]
+    pub proposal: Account<'info, AdminProposal>,
+
+    /// Required for UpdateGlobalState proposals, which are queued behind the config timelock
+    #[account(
+        init,
+        payer = executor,
+        space = PendingConfigChange::MAX_SIZE,
+        seeds = [SEED_PENDING_CONFIG, global_state.key().as_ref()],
+        bump
+    )]
+    pub pending_config_change: Option<Account<'info, PendingConfigChange>>,
+
+    /// Required for actions carried out by their own instruction (treasury, governance setup, slashing)
+    #[account(
+        init,
+        payer = executor,
+        space = AdminAuthorization::MAX_SIZE,
+        seeds = [SEED_ADMIN_AUTHORIZATION, proposal.key().as_ref()],
+        bump
+    )]
+    pub admin_authorization: Option<Account<'info, AdminAuthorization>>,
+
+    pub system_program: Program<'info, System>,
+    pub clock: Sysvar<'info, Clock>,
+}
+
+/// Apply an approved proposal. Anyone may execute once the threshold is met.
+/// Config updates are queued as a PendingConfigChange rather than applied immediately,
+/// and instruction-level actions issue an AdminAuthorization for the matching instruction.
+/// Approvals from keys removed by a later ChangeSigners no longer count.
+pub fn execute_admin_action(ctx: Context<ExecuteAdminAction>) -> Result<()> {
+    let multisig = &mut ctx.accounts.multisig;
+    let proposal = &mut ctx.accounts.proposal;
+    let state = &mut ctx.accounts.global_state;
+
+    let valid_approvals = proposal.approvals.iter()
+        .filter(|a| multisig.signers.contains(a))
+        .count();
+    require!(
+        valid_approvals >= multisig.threshold as usize,
+        ProtocolError::InsufficientApprovals
+    );
+
+    match proposal.action.clone() {
+        AdminAction::UpdateGlobalState(update) => {
+            require!(!state.updates_disabled, ProtocolError::Unauthorized);
+            let pending = ctx.accounts.pending_config_change.as_mut()
+                .ok_or(ProtocolError::InvalidAccount)?;
+            queue_config_change(
+                pending,
+                ctx.bumps.pending_config_change.ok_or(ProtocolError::InvalidAccount)?,
+                multisig.key(),
+                ctx.accounts.executor.key(),
+                *update,
+                ctx.accounts.clock.unix_timestamp,
+            )?;
+        }
+        AdminAction::ChangeSigners { signers, threshold } => {
+            validate_signer_set(&signers, threshold)?;
+            multisig.signers = signers;
+            multisig.threshold = threshold;
+        }
+        action if action.requires_authorization() => {
+            let authorization = ctx.accounts.admin_authorization.as_mut()
+                .ok_or(ProtocolError::InvalidAccount)?;
+            issue_admin_authorization(
+                authorization,
+                ctx.bumps.admin_authorization.ok_or(ProtocolError::InvalidAccount)?,
+                multisig.key(),
+                proposal.key(),
+                action,
+                ctx.accounts.clock.unix_timestamp,
+            );
+        }
+        action => apply_admin_action(state, action, multisig.key())?,
+    }
+
+    proposal.executed = true;
+
+    msg!(
+        "AdminProposalExecuted: Proposal={} Executor={} Approvals={}/{}",
+        proposal.key(),
+        ctx.accounts.executor.key(),
+        valid_approvals,
+        multisig.threshold
+    );
+    Ok(())
+}
+
+// ============================================================================
+// Shared by the multisig and governance executors
+// ============================================================================
+
+/// Apply a GlobalState action on behalf of the admin PDA `authority`.
+/// UpdateGlobalState is applied immediately; the multisig queues it before getting here.
+pub(crate) fn apply_admin_action(state: &mut GlobalState, action: AdminAction, authority: Pubkey) -> Result<()> {
+    match action {
+        AdminAction::UpdateGlobalState(update) => {
+            require!(!state.updates_disabled, ProtocolError::Unauthorized);
+            apply_config_update(state, *update)?;
+        }
+        AdminAction::ProposeAdmin { new_admin } => {
+            require!(new_admin != Pubkey::default(), ProtocolError::InvalidAccount);
+            state.pending_admin = Some(new_admin);
+        }
+        AdminAction::DisableGlobalStateUpdates => {
+            require!(!state.updates_disabled, ProtocolError::Unauthorized);
+            state.updates_disabled = true;
+        }
+        AdminAction::SetPauseFlags { paused_flags } => {
+            apply_pause_flags(state, paused_flags, authority)?;
+        }
+        AdminAction::SetGuardian { guardian } => {
+            state.guardian = guardian;
+        }
+        // Signer changes and instruction-level actions are handled by the caller
+        _ => return err!(ProtocolError::Unauthorized),
+    }
+    Ok(())
+}
+
+pub(crate) fn issue_admin_authorization(
+    authorization: &mut Account<AdminAuthorization>,
+    bump: u8,
+    authorized_by: Pubkey,
+    proposal: Pubkey,
+    action: AdminAction,
+    now: i64,
+) {
+    authorization.authorized_by = authorized_by;
+    authorization.proposal = proposal;
+    authorization.action = action;
+    authorization.issued_at = now;
+    authorization.version = AdminAuthorization::VERSION;
+    authorization.bump = bump;
+
+    msg!(
+        "AdminAuthorizationIssued: Authorization={} AuthorizedBy={} Proposal={} Action={:?}",
+        authorization.key(),
+        authorized_by,
+        proposal,
+        authorization.action
+    );
+}
+
+/// Check an admin-only instruction. Passes when `signer` is the admin key, or when the
+/// admin is a multisig/governance PDA and `authorization` was issued by it for exactly
+/// `expected`. The instruction closes the authorization, so it cannot be replayed.
+pub(crate) fn authorize_admin(
+    global_state: &GlobalState,
+    signer: &Pubkey,
+    authorization: &Option<Account<AdminAuthorization>>,
+    expected: &AdminAction,
+) -> Result<()> {
+    if global_state.admin == *signer {
+        return Ok(());
+    }
+    let authorization = authorization.as_ref().ok_or(ProtocolError::Unauthorized)?;
+    require!(authorization.authorized_by == global_state.admin, ProtocolError::Unauthorized);
+    require!(authorization.action == *expected, ProtocolError::AuthorizationMismatch);
+    Ok(())
+}
@@ -0,0 +1,618 @@
+// solana-program/programs/solana-program/src/instructions/governance.rs
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{TokenInterface, TransferChecked, Mint, TokenAccount};
+use anchor_spl::associated_token::AssociatedToken;
+use crate::state::*;
+use crate::errors::ProtocolError;
+use crate::constants::*;
+use crate::instructions::admin::{apply_admin_action, authorize_admin, issue_admin_authorization, validate_config_update};
+
+// ============================================================================
+// CAPGM Governance
+// ============================================================================
+//
+// CAPGM holders lock tokens in the governance vault and vote on admin actions
+// with their locked balance. A proposal passes with at least quorum_votes in favour and
+// more votes for than against, and can be executed by anyone once execution_delay has
+// passed after voting closes. Execution only works once the admin has handed control to
+// the Governance PDA (propose_admin + accept_governance_admin); until then proposals
+// are advisory and the admin keeps using update_global_state.
+
+#[event]
+pub struct GovernanceProposalCreated {
+    pub proposal_id: u64,
+    pub proposer: Pubkey,
+    pub action: AdminAction,
+    pub voting_ends_at: i64,
+    pub execute_after: i64,
+}
+
+#[event]
+pub struct GovernanceVoteCast {
+    pub proposal_id: u64,
+    pub voter: Pubkey,
+    pub support: bool,
+    pub weight: u64,
+}
+
+#[event]
+pub struct GovernanceProposalExecuted {
+    pub proposal_id: u64,
+    pub executor: Pubkey,
+    pub action: AdminAction,
+    pub timestamp: i64,
+}
+
+#[derive(Accounts)]
+pub struct InitializeGovernance<'info> {
+    /// Admin key, or the relayer of an AdminAuthorization when the admin is a PDA
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    #[account(
+        seeds = [SEED_GLOBAL_STATE],
+        bump = global_state.bump
+    )]
+    pub global_state: Account<'info, GlobalState>,
+
+    #[account(
+        mut,
+        close = admin,
+        seeds = [SEED_ADMIN_AUTHORIZATION, admin_authorization.proposal.as_ref()],
+        bump = admin_authorization.bump
+    )]
+    pub admin_authorization: Option<Account<'info, AdminAuthorization>>,
+
+    #[account(
+        init,
+        payer = admin,
+        space = Governance::MAX_SIZE,
+        seeds = [SEED_GOVERNANCE],
+        bump
+    )]
+    pub governance: Account<'info, Governance>,
+
+    #[account(
+        constraint = capgm_mint.key() == global_state.capgm_mint @ ProtocolError::InvalidAccount
+    )]
+    pub capgm_mint: InterfaceAccount<'info, Mint>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Create the Governance account. Parameters are fixed at initialization.
+/// execution_delay must be at least CONFIG_CHANGE_DELAY_SECONDS so governance changes
+/// get the same notice period as admin changes.
+pub fn initialize_governance(
+    ctx: Context<InitializeGovernance>,
+    quorum_votes: u64,
+    proposal_threshold: u64,
+    voting_period: i64,
+    execution_delay: i64,
+) -> Result<()> {
+    authorize_admin(
+        &ctx.accounts.global_state,
+        &ctx.accounts.admin.key(),
+        &ctx.accounts.admin_authorization,
+        &AdminAction::InitializeGovernance { quorum_votes, proposal_threshold, voting_period, execution_delay },
+    )?;
+    require!(quorum_votes > 0, ProtocolError::InvalidFeeConfig);
+    require!(
+        (MIN_GOVERNANCE_VOTING_PERIOD_SECONDS..=MAX_GOVERNANCE_VOTING_PERIOD_SECONDS).contains(&voting_period),
+        ProtocolError::InvalidFeeConfig
+    );
+    require!(execution_delay >= CONFIG_CHANGE_DELAY_SECONDS, ProtocolError::InvalidFeeConfig);
+
+    let governance = &mut ctx.accounts.governance;
+    governance.capgm_mint = ctx.accounts.capgm_mint.key();
+    governance.quorum_votes = quorum_votes;
+    governance.proposal_threshold = proposal_threshold;
+    governance.voting_period = voting_period;
+    governance.execution_delay = execution_delay;
+    governance.proposal_count = 0;
+    governance.total_locked = 0;
+    governance.version = Governance::VERSION;
+    governance.bump = ctx.bumps.governance;
+
+    msg!(
+        "GovernanceInitialized: Governance={} Quorum={} Threshold={} VotingPeriod={} ExecutionDelay={}",
+        governance.key(),
+        quorum_votes,
+        proposal_threshold,
+        voting_period,
+        execution_delay
+    );
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct AcceptGovernanceAdmin<'info> {
+    #[account(
+        mut,
+        seeds = [SEED_GLOBAL_STATE],
+        bump = global_state.bump,
+        constraint = global_state.pending_admin == Some(governance.key()) @ ProtocolError::Unauthorized
+    )]
+    pub global_state: Account<'info, GlobalState>,
+
+    #[account(
+        seeds = [SEED_GOVERNANCE],
+        bump = governance.bump
+    )]
+    pub governance: Account<'info, Governance>,
+}
+
+/// Permissionless: complete a propose_admin handover to the Governance PDA,
+/// which cannot sign accept_admin itself
+pub fn accept_governance_admin(ctx: Context<AcceptGovernanceAdmin>) -> Result<()> {
+    let state = &mut ctx.accounts.global_state;
+    let previous_admin = state.admin;
+    state.admin = ctx.accounts.governance.key();
+    state.pending_admin = None;
+
+    msg!(
+        "AdminTransferred: PreviousAdmin={} NewAdmin={} (governance)",
+        previous_admin,
+        state.admin
+    );
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct LockGovernanceTokens<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    #[account(
+        seeds = [SEED_GLOBAL_STATE],
+        bump = global_state.bump,
+        constraint = !global_state.is_paused(PAUSE_STAKING) @ ProtocolError::ProtocolPaused
+    )]
+    pub global_state: Account<'info, GlobalState>,
+
+    #[account(
+        mut,
+        seeds = [SEED_GOVERNANCE],
+        bump = governance.bump
+    )]
+    pub governance: Account<'info, Governance>,
+
+    #[account(
+        init_if_needed,
+        payer = owner,
+        space = VoterLock::MAX_SIZE,
+        seeds = [SEED_VOTER_LOCK, owner.key().as_ref()],
+        bump
+    )]
+    pub voter_lock: Account<'info, VoterLock>,
+
+    #[account(
+        mut,
+        token::mint = capgm_mint,
+        token::authority = owner,
+    )]
+    pub owner_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    /// Governance vault (PDA-owned ATA) holding all locked CAPGM
+    #[account(
+        init_if_needed,
+        payer = owner,
+        associated_token::mint = capgm_mint,
+        associated_token::authority = governance,
+        associated_token::token_program = token_program,
+    )]
+    pub governance_vault: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(
+        constraint = capgm_mint.key() == governance.capgm_mint @ ProtocolError::InvalidAccount
+    )]
+    pub capgm_mint: InterfaceAccount<'info, Mint>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
+
+/// Lock CAPGM to gain voting weight. Adding to an existing lock keeps its locked_until.
+pub fn lock_governance_tokens(ctx: Context<LockGovernanceTokens>, amount: u64) -> Result<()> {
+    require!(amount > 0, ProtocolError::InsufficientFunds);
+
+    let transfer_ix = TransferChecked {
+        from: ctx.accounts.owner_token_account.to_account_info(),
+        mint: ctx.accounts.capgm_mint.to_account_info(),
+        to: ctx.accounts.governance_vault.to_account_info(),
+        authority: ctx.accounts.owner.to_account_info(),
+    };
+    let cpi_ctx = CpiContext::new(ctx.accounts.token_program.to_account_info(), transfer_ix);
+    anchor_spl::token_interface::transfer_checked(cpi_ctx, amount, ctx.accounts.capgm_mint.decimals)?;
+
+    let voter_lock = &mut ctx.accounts.voter_lock;
+    if voter_lock.owner == Pubkey::default() {
+        voter_lock.owner = ctx.accounts.owner.key();
+        voter_lock.locked_until = 0;
+        voter_lock.version = VoterLock::VERSION;
+        voter_lock.bump = ctx.bumps.voter_lock;
+    }
+    voter_lock.amount = voter_lock.amount
+        .checked_add(amount)
+        .ok_or(ProtocolError::MathOverflow)?;
+
+    let governance = &mut ctx.accounts.governance;
+    governance.total_locked = governance.total_locked
+        .checked_add(amount)
+        .ok_or(ProtocolError::MathOverflow)?;
+
+    msg!(
+        "GovernanceTokensLocked: Owner={} Amount={} TotalLocked={}",
+        voter_lock.owner,
+        amount,
+        voter_lock.amount
+    );
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct UnlockGovernanceTokens<'info> {
+    pub owner: Signer<'info>,
+
+    #[account(
+        seeds = [SEED_GLOBAL_STATE],
+        bump = global_state.bump,
+        constraint = !global_state.is_paused(PAUSE_UNSTAKING) @ ProtocolError::ProtocolPaused
+    )]
+    pub global_state: Account<'info, GlobalState>,
+
+    #[account(
+        mut,
+        seeds = [SEED_GOVERNANCE],
+        bump = governance.bump
+    )]
+    pub governance: Account<'info, Governance>,
+
+    #[account(
+        mut,
+        seeds = [SEED_VOTER_LOCK, owner.key().as_ref()],
+        bump = voter_lock.bump,
+        has_one = owner @ ProtocolError::Unauthorized
+    )]
+    pub voter_lock: Account<'info, VoterLock>,
+
+    #[account(
+        mut,
+        token::mint = capgm_mint,
+        token::authority = owner,
+    )]
+    pub owner_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        associated_token::mint = capgm_mint,
+        associated_token::authority = governance,
+        associated_token::token_program = token_program,
+    )]
+    pub governance_vault: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(
+        constraint = capgm_mint.key() == governance.capgm_mint @ ProtocolError::InvalidAccount
+    )]
+    pub capgm_mint: InterfaceAccount<'info, Mint>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+    pub clock: Sysvar<'info, Clock>,
+}
+
+/// Withdraw locked CAPGM. Tokens that voted stay locked until every proposal they
+/// voted on has closed, so the same tokens cannot be moved to a new wallet and vote twice.
+pub fn unlock_governance_tokens(ctx: Context<UnlockGovernanceTokens>, amount: u64) -> Result<()> {
+    let voter_lock = &mut ctx.accounts.voter_lock;
+    require!(
+        ctx.accounts.clock.unix_timestamp >= voter_lock.locked_until,
+        ProtocolError::TokensLocked
+    );
+    require!(amount > 0 && amount <= voter_lock.amount, ProtocolError::InsufficientFunds);
+
+    let governance_seeds = [SEED_GOVERNANCE, &[ctx.accounts.governance.bump]];
+    let signer_seeds = &[&governance_seeds[..]];
+
+    let transfer_ix = TransferChecked {
+        from: ctx.accounts.governance_vault.to_account_info(),
+        mint: ctx.accounts.capgm_mint.to_account_info(),
+        to: ctx.accounts.owner_token_account.to_account_info(),
+        authority: ctx.accounts.governance.to_account_info(),
+    };
+    let cpi_ctx = CpiContext::new_with_signer(
+        ctx.accounts.token_program.to_account_info(),
+        transfer_ix,
+        signer_seeds,
+    );
+    anchor_spl::token_interface::transfer_checked(cpi_ctx, amount, ctx.accounts.capgm_mint.decimals)?;
+
+    voter_lock.amount -= amount;
+    let governance = &mut ctx.accounts.governance;
+    governance.total_locked = governance.total_locked.saturating_sub(amount);
+
+    msg!(
+        "GovernanceTokensUnlocked: Owner={} Amount={} Remaining={}",
+        voter_lock.owner,
+        amount,
+        voter_lock.amount
+    );
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(action: AdminAction)]
+pub struct CreateGovernanceProposal<'info> {
+    #[account(mut)]
+    pub proposer: Signer<'info>,
+
+    /// Pause changes stay open so a governance admin can always lift a pause
+    #[account(
+        seeds = [SEED_GLOBAL_STATE],
+        bump = global_state.bump,
+        constraint = !global_state.is_paused(PAUSE_GOVERNANCE)
+            || matches!(action, AdminAction::SetPauseFlags { .. }) @ ProtocolError::ProtocolPaused
+    )]
+    pub global_state: Account<'info, GlobalState>,
+
+    #[account(
+        mut,
+        seeds = [SEED_GOVERNANCE],
+        bump = governance.bump
+    )]
+    pub governance: Account<'info, Governance>,
+
+    #[account(
+        seeds = [SEED_VOTER_LOCK, proposer.key().as_ref()],
+        bump = voter_lock.bump,
+        constraint = voter_lock.owner == proposer.key() @ ProtocolError::Unauthorized,
+        constraint = voter_lock.amount >= governance.proposal_threshold @ ProtocolError::BelowProposalThreshold
+    )]
+    pub voter_lock: Account<'info, VoterLock>,
+
+    #[account(
+        init,
+        payer = proposer,
+        space = GovernanceProposal::MAX_SIZE,
+        seeds = [SEED_GOVERNANCE_PROPOSAL, &governance.proposal_count.to_le_bytes()],
+        bump
+    )]
+    pub proposal: Account<'info, GovernanceProposal>,
+
+    pub system_program: Program<'info, System>,
+    pub clock: Sysvar<'info, Clock>,
+}
+
+/// Propose an admin action. Config updates are validated against the same bounds as
+/// update_global_state before voting opens. Multisig signer changes cannot be proposed here.
+pub fn create_governance_proposal(
+    ctx: Context<CreateGovernanceProposal>,
+    action: AdminAction,
+) -> Result<()> {
+    match &action {
+        AdminAction::UpdateGlobalState(update) => validate_config_update(update)?,
+        AdminAction::ChangeSigners { .. } => return err!(ProtocolError::Unauthorized),
+        _ => {}
+    }
+
+    let governance = &mut ctx.accounts.governance;
+    let now = ctx.accounts.clock.unix_timestamp;
+    let voting_ends_at = now
+        .checked_add(governance.voting_period)
+        .ok_or(ProtocolError::MathOverflow)?;
+    let execute_after = voting_ends_at
+        .checked_add(governance.execution_delay)
+        .ok_or(ProtocolError::MathOverflow)?;
+
+    let proposal = &mut ctx.accounts.proposal;
+    proposal.proposal_id = governance.proposal_count;
+    proposal.proposer = ctx.accounts.proposer.key();
+    proposal.action = action;
+    proposal.votes_for = 0;
+    proposal.votes_against = 0;
+    proposal.created_at = now;
+    proposal.voting_ends_at = voting_ends_at;
+    proposal.execute_after = execute_after;
+    proposal.executed = false;
+    proposal.version = GovernanceProposal::VERSION;
+    proposal.bump = ctx.bumps.proposal;
+
+    governance.proposal_count = governance.proposal_count
+        .checked_add(1)
+        .ok_or(ProtocolError::MathOverflow)?;
+
+    emit!(GovernanceProposalCreated {
+        proposal_id: proposal.proposal_id,
+        proposer: proposal.proposer,
+        action: proposal.action.clone(),
+        voting_ends_at,
+        execute_after,
+    });
+    msg!(
+        "GovernanceProposalCreated: Id={} Proposer={} VotingEndsAt={}",
+        proposal.proposal_id,
+        proposal.proposer,
+        voting_ends_at
+    );
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct CastGovernanceVote<'info> {
+    #[account(mut)]
+    pub voter: Signer<'info>,
+
+    #[account(
+        seeds = [SEED_GLOBAL_STATE],
+        bump = global_state.bump,
+        constraint = !global_state.is_paused(PAUSE_GOVERNANCE)
+            || matches!(proposal.action, AdminAction::SetPauseFlags { .. }) @ ProtocolError::ProtocolPaused
+    )]
+    pub global_state: Account<'info, GlobalState>,
+
+    #[account(
+        mut,
+        seeds = [SEED_GOVERNANCE_PROPOSAL, &proposal.proposal_id.to_le_bytes()],
+        bump = proposal.bump
+    )]
+    pub proposal: Account<'info, GovernanceProposal>,
+
+    #[account(
+        mut,
+        seeds = [SEED_VOTER_LOCK, voter.key().as_ref()],
+        bump = voter_lock.bump,
+        constraint = voter_lock.owner == voter.key() @ ProtocolError::Unauthorized
+    )]
+    pub voter_lock: Account<'info, VoterLock>,
+
+    /// One vote per voter per proposal
+    #[account(
+        init,
+        payer = voter,
+        space = VoteRecord::MAX_SIZE,
+        seeds = [SEED_VOTE_RECORD, proposal.key().as_ref(), voter.key().as_ref()],
+        bump
+    )]
+    pub vote_record: Account<'info, VoteRecord>,
+
+    pub system_program: Program<'info, System>,
+    pub clock: Sysvar<'info, Clock>,
+}
+
+/// Vote with the full locked balance. The lock is extended to the end of this
+/// proposal's voting period.
+pub fn cast_governance_vote(ctx: Context<CastGovernanceVote>, support: bool) -> Result<()> {
+    let proposal = &mut ctx.accounts.proposal;
+    require!(
+        ctx.accounts.clock.unix_timestamp < proposal.voting_ends_at,
+        ProtocolError::VotingClosed
+    );
+
+    let voter_lock = &mut ctx.accounts.voter_lock;
+    let weight = voter_lock.amount;
+    require!(weight > 0, ProtocolError::InsufficientFunds);
+
+    if support {
+        proposal.votes_for = proposal.votes_for
+            .checked_add(weight)
+            .ok_or(ProtocolError::MathOverflow)?;
+    } else {
+        proposal.votes_against = proposal.votes_against
+            .checked_add(weight)
+            .ok_or(ProtocolError::MathOverflow)?;
+    }
+    voter_lock.locked_until = voter_lock.locked_until.max(proposal.voting_ends_at);
+
+    let vote_record = &mut ctx.accounts.vote_record;
+    vote_record.proposal = proposal.key();
+    vote_record.voter = ctx.accounts.voter.key();
+    vote_record.weight = weight;
+    vote_record.support = support;
+    vote_record.version = VoteRecord::VERSION;
+    vote_record.bump = ctx.bumps.vote_record;
+
+    emit!(GovernanceVoteCast {
+        proposal_id: proposal.proposal_id,
+        voter: vote_record.voter,
+        support,
+        weight,
+    });
+    msg!(
+        "GovernanceVoteCast: Id={} Voter={} Support={} Weight={} For={} Against={}",
+        proposal.proposal_id,
+        vote_record.voter,
+        support,
+        weight,
+        proposal.votes_for,
+        proposal.votes_against
+    );
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct ExecuteGovernanceProposal<'info> {
+    #[account(mut)]
+    pub executor: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [SEED_GLOBAL_STATE],
+        bump = global_state.bump,
+        constraint = global_state.admin == governance.key() @ ProtocolError::GovernanceNotAdmin,
+        constraint = !global_state.is_paused(PAUSE_GOVERNANCE)
+            || matches!(proposal.action, AdminAction::SetPauseFlags { .. }) @ ProtocolError::ProtocolPaused
+    )]
+    pub global_state: Account<'info, GlobalState>,
+
+    #[account(
+        seeds = [SEED_GOVERNANCE],
+        bump = governance.bump
+    )]
+    pub governance: Account<'info, Governance>,
+
+    #[account(
+        mut,
+        seeds = [SEED_GOVERNANCE_PROPOSAL, &proposal.proposal_id.to_le_bytes()],
+        bump = proposal.bump,
+        constraint = !proposal.executed @ ProtocolError::ProposalAlreadyExecuted
+    )]
+    pub proposal: Account<'info, GovernanceProposal>,
+
+    /// Required for actions carried out by their own instruction (treasury, slashing)
+    #[account(
+        init,
+        payer = executor,
+        space = AdminAuthorization::MAX_SIZE,
+        seeds = [SEED_ADMIN_AUTHORIZATION, proposal.key().as_ref()],
+        bump
+    )]
+    pub admin_authorization: Option<Account<'info, AdminAuthorization>>,
+
+    pub system_program: Program<'info, System>,
+    pub clock: Sysvar<'info, Clock>,
+}
+
+/// Permissionless: apply a passed proposal once its timelock has elapsed.
+/// Instruction-level actions issue an AdminAuthorization for the matching instruction.
+pub fn execute_governance_proposal(ctx: Context<ExecuteGovernanceProposal>) -> Result<()> {
+    let proposal = &mut ctx.accounts.proposal;
+    let now = ctx.accounts.clock.unix_timestamp;
+    require!(now >= proposal.execute_after, ProtocolError::TimelockNotElapsed);
+    require!(
+        proposal.has_passed(ctx.accounts.governance.quorum_votes),
+        ProtocolError::ProposalNotPassed
+    );
+
+    let action = proposal.action.clone();
+    let governance = ctx.accounts.governance.key();
+    if action.requires_authorization() {
+        let authorization = ctx.accounts.admin_authorization.as_mut()
+            .ok_or(ProtocolError::InvalidAccount)?;
+        issue_admin_authorization(
+            authorization,
+            ctx.bumps.admin_authorization.ok_or(ProtocolError::InvalidAccount)?,
+            governance,
+            proposal.key(),
+            action.clone(),
+            now,
+        );
+    } else {
+        apply_admin_action(&mut ctx.accounts.global_state, action.clone(), governance)?;
+    }
+    proposal.executed = true;
+
+    emit!(GovernanceProposalExecuted {
+        proposal_id: proposal.proposal_id,
+        executor: ctx.accounts.executor.key(),
+        action,
+        timestamp: now,
+    });
+    msg!(
+        "GovernanceProposalExecuted: Id={} For={} Against={} Executor={}",
+        proposal.proposal_id,
+        proposal.votes_for,
+        proposal.votes_against,
+        ctx.accounts.executor.key()
+    );
+    Ok(())
+}
@@ -0,0 +1,249 @@
+// solana-program/programs/solana-program/src/instructions/launch.rs
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Burn, burn, Mint, TokenAccount, TokenInterface, TransferChecked};
+use crate::state::*;
+use crate::errors::ProtocolError;
+use crate::constants::*;
+use crate::instructions::orca::{
+    check_launch_price, read_pubkey, whirlpool_sqrt_price, ORCA_WHIRLPOOL_PROGRAM_ID,
+    WHIRLPOOL_CONFIG_OFFSET, WHIRLPOOL_TOKEN_MINT_A_OFFSET, WHIRLPOOL_TOKEN_MINT_B_OFFSET,
+};
+
+// ============================================================================
+// Collection Launch
+// ============================================================================
+//
+// A launch spans create_collection -> mint_collection_tokens -> initialize_orca_pool ->
+// open_orca_position -> deposit_liquidity_to_orca, each too large to share a transaction.
+// CollectionState.launch_stage records progress: each step requires the previous stage,
+// so clients resume by calling the step after the recorded stage. Purchases open only
+// at Launched. An interrupted launch can instead be aborted, burning the staged
+// liquidity reserve and returning any CAPGM the creator deposited.
+
+#[derive(Accounts)]
+pub struct ResumeLaunch<'info> {
+    pub owner: Signer<'info>,
+
+    #[account(
+        seeds = [SEED_GLOBAL_STATE],
+        bump = global_state.bump,
+        constraint = !global_state.is_paused(PAUSE_MINTING) @ ProtocolError::ProtocolPaused
+    )]
+    pub global_state: Account<'info, GlobalState>,
+
+    #[account(
+        mut,
+        seeds = [b"collection", collection.owner.as_ref(), collection.collection_id.as_bytes()],
+        bump = collection.bump,
+        constraint = collection.owner == owner.key() @ ProtocolError::Unauthorized
+    )]
+    pub collection: Account<'info, CollectionState>,
+
+    #[account(
+        constraint = collection_mint.key() == collection.mint @ ProtocolError::Unauthorized
+    )]
+    pub collection_mint: InterfaceAccount<'info, Mint>,
+
+    #[account(
+        constraint = capgm_mint.key() == global_state.capgm_mint @ ProtocolError::InvalidPoolConfig
+    )]
+    pub capgm_mint: InterfaceAccount<'info, Mint>,
+
+    /// CHECK: Validated against collection; inspected in the handler
+    #[account(
+        constraint = whirlpool.key() == collection.pool_address @ ProtocolError::Unauthorized
+    )]
+    pub whirlpool: UncheckedAccount<'info>,
+
+    /// Stablecoin/CAPGM Whirlpool, read to price an existing pool against the launch band
+    /// CHECK: Validated against global_state; only sqrt_price is read
+    #[account(
+        owner = ORCA_WHIRLPOOL_PROGRAM_ID,
+        constraint = stablecoin_pool.key() == global_state.stablecoin_pool @ ProtocolError::InvalidPoolConfig
+    )]
+    pub stablecoin_pool: UncheckedAccount<'info>,
+
+    #[account(
+        constraint = stablecoin_mint.key() == global_state.stablecoin_mint @ ProtocolError::InvalidPoolConfig
+    )]
+    pub stablecoin_mint: InterfaceAccount<'info, Mint>,
+}
+
+/// Reconcile launch_stage with on-chain state and report the next step.
+/// The Whirlpool address is deterministic, so anyone can initialize it directly through
+/// Orca; initialize_orca_pool would then fail forever. If the pool already exists, it must
+/// pass the checks initialize_orca_pool applies (allowlisted config, collection mint as
+/// token A and CAPGM as token B, launch price band) before the collection skips ahead to
+/// PoolInitialized. A pool initialized at an out-of-band price can only be aborted.
+pub fn resume_launch(ctx: Context<ResumeLaunch>) -> Result<()> {
+    let whirlpool = &ctx.accounts.whirlpool;
+
+    if ctx.accounts.collection.launch_stage == LaunchStage::TokensMinted
+        && *whirlpool.owner == ORCA_WHIRLPOOL_PROGRAM_ID
+        && !whirlpool.data_is_empty()
+    {
+        require!(
+            read_pubkey(whirlpool, WHIRLPOOL_CONFIG_OFFSET)? == ctx.accounts.global_state.whirlpools_config
+                && read_pubkey(whirlpool, WHIRLPOOL_TOKEN_MINT_A_OFFSET)? == ctx.accounts.collection_mint.key()
+                && read_pubkey(whirlpool, WHIRLPOOL_TOKEN_MINT_B_OFFSET)? == ctx.accounts.capgm_mint.key(),
+            ProtocolError::InvalidPoolConfig
+        );
+        check_launch_price(
+            &ctx.accounts.collection,
+            &ctx.accounts.collection_mint,
+            &ctx.accounts.capgm_mint,
+            &ctx.accounts.stablecoin_mint,
+            &ctx.accounts.stablecoin_pool,
+            whirlpool_sqrt_price(whirlpool)?,
+        )?;
+        ctx.accounts.collection.launch_stage = LaunchStage::PoolInitialized;
+    }
+
+    let collection = &ctx.accounts.collection;
+    let next_step = match collection.launch_stage {
+        LaunchStage::Created => "mint_collection_tokens",
+        LaunchStage::TokensMinted => "initialize_orca_pool",
+        LaunchStage::PoolInitialized => "open_orca_position",
+        LaunchStage::PositionOpened => "deposit_liquidity_to_orca",
+        LaunchStage::Launched | LaunchStage::Aborted => "none",
+    };
+
+    msg!(
+        "LaunchResumed: Collection={} Stage={:?} NextStep={}",
+        collection.key(),
+        collection.launch_stage,
+        next_step
+    );
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct AbortLaunch<'info> {
+    pub owner: Signer<'info>,
+
+    #[account(
+        seeds = [SEED_GLOBAL_STATE],
+        bump = global_state.bump,
+        constraint = !global_state.is_paused(PAUSE_MINTING) @ ProtocolError::ProtocolPaused
+    )]
+    pub global_state: Account<'info, GlobalState>,
+
+    #[account(
+        mut,
+        seeds = [b"collection", collection.owner.as_ref(), collection.collection_id.as_bytes()],
+        bump = collection.bump,
+        constraint = collection.owner == owner.key() @ ProtocolError::Unauthorized,
+        constraint = !matches!(collection.launch_stage, LaunchStage::Launched | LaunchStage::Aborted) @ ProtocolError::InvalidLaunchStage
+    )]
+    pub collection: Account<'info, CollectionState>,
+
+    #[account(
+        mut,
+        constraint = collection_mint.key() == collection.mint @ ProtocolError::Unauthorized
+    )]
+    pub collection_mint: InterfaceAccount<'info, Mint>,
+
+    /// Liquidity reserve (Collection ATA) holding the staged liquidity share; required once
+    /// tokens are minted, absent before
+    #[account(
+        mut,
+        associated_token::mint = collection_mint,
+        associated_token::authority = collection,
+        associated_token::token_program = token_program,
+    )]
+    pub liquidity_reserve: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        constraint = capgm_mint.key() == global_state.capgm_mint @ ProtocolError::InvalidAccount
+    )]
+    pub capgm_mint: InterfaceAccount<'info, Mint>,
+
+    /// Collection CAPGM reserve; any balance is returned to the creator
+    #[account(
+        mut,
+        constraint = capgm_reserve.owner == collection.key() @ ProtocolError::Unauthorized,
+        constraint = capgm_reserve.mint == capgm_mint.key() @ ProtocolError::Unauthorized
+    )]
+    pub capgm_reserve: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        token::mint = capgm_mint,
+        token::authority = owner,
+    )]
+    pub owner_capgm_account: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+/// Abandon a launch that has not completed: burn the staged liquidity reserve, return
+/// any CAPGM held in the collection reserve to the creator, and close the collection
+/// to purchases for good. Creator and claim vault allocations are untouched.
+pub fn abort_launch(ctx: Context<AbortLaunch>) -> Result<()> {
+    let collection = &ctx.accounts.collection;
+    let seeds = [
+        b"collection".as_ref(),
+        collection.owner.as_ref(),
+        collection.collection_id.as_bytes(),
+        &[collection.bump],
+    ];
+    let signer_seeds = &[&seeds[..]];
+
+    require!(
+        collection.launch_stage == LaunchStage::Created || ctx.accounts.liquidity_reserve.is_some(),
+        ProtocolError::InvalidAccount
+    );
+
+    let mut burned = 0;
+    if let Some(liquidity_reserve) = &ctx.accounts.liquidity_reserve {
+        burned = liquidity_reserve.amount;
+        if burned > 0 {
+            let burn_accounts = Burn {
+                mint: ctx.accounts.collection_mint.to_account_info(),
+                from: liquidity_reserve.to_account_info(),
+                authority: collection.to_account_info(),
+            };
+            let cpi_ctx = CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                burn_accounts,
+                signer_seeds,
+            );
+            burn(cpi_ctx, burned)?;
+        }
+    }
+
+    let mut returned = 0;
+    if let Some(capgm_reserve) = &ctx.accounts.capgm_reserve {
+        returned = capgm_reserve.amount;
+        if returned > 0 {
+            let owner_capgm_account = ctx.accounts.owner_capgm_account
+                .as_ref()
+                .ok_or(ProtocolError::InvalidAccount)?;
+            let transfer_accounts = TransferChecked {
+                from: capgm_reserve.to_account_info(),
+                mint: ctx.accounts.capgm_mint.to_account_info(),
+                to: owner_capgm_account.to_account_info(),
+                authority: collection.to_account_info(),
+            };
+            let cpi_ctx = CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                transfer_accounts,
+                signer_seeds,
+            );
+            anchor_spl::token_interface::transfer_checked(cpi_ctx, returned, ctx.accounts.capgm_mint.decimals)?;
+        }
+    }
+
+    let collection = &mut ctx.accounts.collection;
+    let aborted_at = collection.launch_stage;
+    collection.launch_stage = LaunchStage::Aborted;
+
+    msg!(
+        "LaunchAborted: Collection={} AbortedAt={:?} ReserveBurned={} CapgmReturned={}",
+        collection.key(),
+        aborted_at,
+        burned,
+        returned
+    );
+    Ok(())
+}
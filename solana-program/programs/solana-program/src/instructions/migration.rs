@@ -0,0 +1,425 @@
+// solana-program/programs/solana-program/src/instructions/migration.rs
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{transfer, Transfer};
+use anchor_lang::Discriminator;
+use anchor_spl::token_interface::TokenAccount;
+use crate::state::*;
+use crate::errors::ProtocolError;
+use crate::constants::*;
+use crate::instructions::orca::{
+    read_pubkey, ORCA_WHIRLPOOL_PROGRAM_ID, POSITION_MINT_OFFSET, POSITION_WHIRLPOOL_OFFSET,
+};
+
+// ============================================================================
+// Account Layout Migrations
+// ============================================================================
+//
+// Every account carries a `version` byte (the type's VERSION const). When a layout
+// changes, the old layout is kept in state.rs as a *V{n} struct and a permissionless
+// migrate_* instruction rewrites existing accounts in place, resizing them and settling
+// the rent difference: the payer tops up growing accounts, and rent freed by shrinking
+// accounts goes back to whoever paid for them originally.
+
+#[derive(Accounts)]
+pub struct MigrateGlobalState<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// CHECK: Legacy layouts do not deserialize as GlobalState; decoded in the handler
+    #[account(
+        mut,
+        seeds = [SEED_GLOBAL_STATE],
+        bump,
+        owner = crate::ID @ ProtocolError::InvalidAccount
+    )]
+    pub global_state: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Move GlobalState from the unversioned layout to GlobalState::VERSION
+pub fn migrate_global_state(ctx: Context<MigrateGlobalState>) -> Result<()> {
+    let info = ctx.accounts.global_state.to_account_info();
+    let migrated = read_legacy::<GlobalState, GlobalStateV0>(&info, |current| {
+        current.version == GlobalState::VERSION
+    })?
+    .migrate();
+
+    let payer = ctx.accounts.payer.to_account_info();
+    let refunded = rewrite_account(
+        &info,
+        &payer,
+        &payer,
+        &ctx.accounts.system_program.to_account_info(),
+        GlobalState::MAX_SIZE,
+        &migrated,
+    )?;
+
+    msg!(
+        "GlobalStateMigrated: Version=0 -> {} Size={} Refunded={}",
+        GlobalState::VERSION,
+        GlobalState::MAX_SIZE,
+        refunded
+    );
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct MigrateCollection<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// CHECK: Legacy layouts do not deserialize as CollectionState; decoded and
+    /// its PDA address verified in the handler
+    #[account(
+        mut,
+        owner = crate::ID @ ProtocolError::InvalidAccount
+    )]
+    pub collection: UncheckedAccount<'info>,
+
+    /// CHECK: Collection owner, who paid the original rent; receives the rent freed by
+    /// dropping deprecated fields. Verified against the decoded collection.
+    #[account(mut)]
+    pub owner: UncheckedAccount<'info>,
+
+    /// CHECK: The collection's open Orca position, if any (legacy layouts did not record its
+    /// mint); its whirlpool and mint are verified in the handler
+    #[account(owner = ORCA_WHIRLPOOL_PROGRAM_ID @ ProtocolError::InvalidAccount)]
+    pub position: Option<UncheckedAccount<'info>>,
+
+    /// The collection's position NFT account, passed with `position`
+    #[account(
+        constraint = position_token_account.owner == collection.key() @ ProtocolError::Unauthorized,
+        constraint = position_token_account.amount == 1 @ ProtocolError::Unauthorized
+    )]
+    pub position_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Move a collection from the unversioned layout to CollectionState::VERSION, dropping the
+/// deprecated content_cid string and turning claimed_bitmap into per-video claimed shares.
+/// A collection with an open Orca position must pass it so position_mint is recorded; only
+/// the owner may migrate without one, since nothing else proves no position exists.
+/// The payer funds any growth; rent freed by a shrinking account goes back to the owner.
+pub fn migrate_collection(ctx: Context<MigrateCollection>) -> Result<()> {
+    let info = ctx.accounts.collection.to_account_info();
+    let mut migrated = read_legacy::<CollectionState, CollectionStateV0>(&info, |current| {
+        current.version == CollectionState::VERSION
+    })?
+    .migrate();
+
+    match (&ctx.accounts.position, &ctx.accounts.position_token_account) {
+        (Some(position), Some(position_token_account)) => {
+            require!(
+                read_pubkey(position, POSITION_WHIRLPOOL_OFFSET)? == migrated.pool_address
+                    && read_pubkey(position, POSITION_MINT_OFFSET)? == position_token_account.mint,
+                ProtocolError::InvalidAccount
+            );
+            migrated.position_mint = position_token_account.mint;
+        }
+        (None, None) => {
+            require!(ctx.accounts.payer.key() == migrated.owner, ProtocolError::Unauthorized);
+        }
+        _ => return err!(ProtocolError::InvalidAccount),
+    }
+
+    let expected = Pubkey::create_program_address(
+        &[
+            b"collection",
+            migrated.owner.as_ref(),
+            migrated.collection_id.as_bytes(),
+            &[migrated.bump],
+        ],
+        &crate::ID,
+    )
+    .map_err(|_| ProtocolError::InvalidAccount)?;
+    require!(expected == info.key(), ProtocolError::InvalidAccount);
+    require!(ctx.accounts.owner.key() == migrated.owner, ProtocolError::Unauthorized);
+
+    let new_len = CollectionState::space(migrated.total_videos);
+    let refunded = rewrite_account(
+        &info,
+        &ctx.accounts.payer.to_account_info(),
+        &ctx.accounts.owner.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+        new_len,
+        &migrated,
+    )?;
+
+    msg!(
+        "CollectionMigrated: Collection={} Version=0 -> {} Size={} Refunded={}",
+        info.key(),
+        CollectionState::VERSION,
+        new_len,
+        refunded
+    );
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct MigrateAccount<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// CHECK: Legacy layouts do not deserialize as the current type; the handler checks the
+    /// discriminator of the type it migrates
+    #[account(
+        mut,
+        owner = crate::ID @ ProtocolError::InvalidAccount
+    )]
+    pub account: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Move a UserAccount to UserAccount::VERSION
+pub fn migrate_user_account(ctx: Context<MigrateAccount>) -> Result<()> {
+    let accounts = &ctx.accounts;
+    migrate_account(
+        &accounts.payer,
+        &accounts.account,
+        &accounts.system_program,
+        "UserAccount",
+        |current: &UserAccount| current.version == UserAccount::VERSION,
+        |legacy: UserAccountV0| Ok(legacy.migrate()),
+        |_| UserAccount::MAX_SIZE,
+    )
+}
+
+/// Move an AccessEscrow to AccessEscrow::VERSION
+pub fn migrate_access_escrow(ctx: Context<MigrateAccount>) -> Result<()> {
+    let accounts = &ctx.accounts;
+    migrate_account(
+        &accounts.payer,
+        &accounts.account,
+        &accounts.system_program,
+        "AccessEscrow",
+        |current: &AccessEscrow| current.version == AccessEscrow::VERSION,
+        |legacy: AccessEscrowV0| Ok(legacy.migrate()),
+        |_| AccessEscrow::MAX_SIZE,
+    )
+}
+
+/// Move a CidReveal to CidReveal::VERSION
+pub fn migrate_cid_reveal(ctx: Context<MigrateAccount>) -> Result<()> {
+    let accounts = &ctx.accounts;
+    migrate_account(
+        &accounts.payer,
+        &accounts.account,
+        &accounts.system_program,
+        "CidReveal",
+        |current: &CidReveal| current.version == CidReveal::VERSION,
+        |legacy: CidRevealV0| Ok(legacy.migrate()),
+        |_| CidReveal::MAX_SIZE,
+    )
+}
+
+/// Move a PeerTrustState to PeerTrustState::VERSION
+pub fn migrate_peer_trust_state(ctx: Context<MigrateAccount>) -> Result<()> {
+    let accounts = &ctx.accounts;
+    migrate_account(
+        &accounts.payer,
+        &accounts.account,
+        &accounts.system_program,
+        "PeerTrustState",
+        |current: &PeerTrustState| current.version == PeerTrustState::VERSION,
+        |legacy: PeerTrustStateV0| Ok(legacy.migrate()),
+        |_| PeerTrustState::MAX_SIZE,
+    )
+}
+
+/// Move a PinnerState to PinnerState::VERSION
+pub fn migrate_pinner_state(ctx: Context<MigrateAccount>) -> Result<()> {
+    let accounts = &ctx.accounts;
+    migrate_account(
+        &accounts.payer,
+        &accounts.account,
+        &accounts.system_program,
+        "PinnerState",
+        |current: &PinnerState| current.version == PinnerState::VERSION,
+        |legacy: PinnerStateV0| Ok(legacy.migrate()),
+        |_| PinnerState::MAX_SIZE,
+    )
+}
+
+/// Move a ModeratorStake to ModeratorStake::VERSION. Legacy moderators keep every
+/// specialization and re-enter the assignment registry on their next stake_moderator.
+pub fn migrate_moderator_stake(ctx: Context<MigrateAccount>) -> Result<()> {
+    let accounts = &ctx.accounts;
+    migrate_account(
+        &accounts.payer,
+        &accounts.account,
+        &accounts.system_program,
+        "ModeratorStake",
+        |current: &ModeratorStake| current.version == ModeratorStake::VERSION,
+        |legacy: ModeratorStakeV0| Ok(legacy.migrate()),
+        |_| ModeratorStake::MAX_SIZE,
+    )
+}
+
+/// Move a CollectionStakingPool to CollectionStakingPool::VERSION
+pub fn migrate_staking_pool(ctx: Context<MigrateAccount>) -> Result<()> {
+    let accounts = &ctx.accounts;
+    migrate_account(
+        &accounts.payer,
+        &accounts.account,
+        &accounts.system_program,
+        "CollectionStakingPool",
+        |current: &CollectionStakingPool| current.version == CollectionStakingPool::VERSION,
+        |legacy: CollectionStakingPoolV0| Ok(legacy.migrate()),
+        |_| CollectionStakingPool::MAX_SIZE,
+    )
+}
+
+/// Move a StakerPosition to StakerPosition::VERSION
+pub fn migrate_staker_position(ctx: Context<MigrateAccount>) -> Result<()> {
+    let accounts = &ctx.accounts;
+    migrate_account(
+        &accounts.payer,
+        &accounts.account,
+        &accounts.system_program,
+        "StakerPosition",
+        |current: &StakerPosition| current.version == StakerPosition::VERSION,
+        |legacy: StakerPositionV0| Ok(legacy.migrate()),
+        |_| StakerPosition::MAX_SIZE,
+    )
+}
+
+#[derive(Accounts)]
+pub struct MigrateModTicket<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// CHECK: Legacy layouts do not deserialize as ModTicket; decoded in the handler
+    #[account(
+        mut,
+        owner = crate::ID @ ProtocolError::InvalidAccount
+    )]
+    pub account: UncheckedAccount<'info>,
+
+    /// The collection an open legacy copyright claim is against (legacy tickets did not record
+    /// it). Only the reporter may name it; without it the claim cannot be resolved.
+    pub collection: Option<Account<'info, CollectionState>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Move a ModTicket to ModTicket::VERSION (see ModTicketV0::migrate)
+pub fn migrate_mod_ticket(ctx: Context<MigrateModTicket>) -> Result<()> {
+    let accounts = &ctx.accounts;
+    let payer = accounts.payer.key();
+    let collection = accounts.collection.as_ref().map(|c| c.key());
+    migrate_account(
+        &accounts.payer,
+        &accounts.account,
+        &accounts.system_program,
+        "ModTicket",
+        |current: &ModTicket| current.version == ModTicket::VERSION,
+        |legacy: ModTicketV0| {
+            if collection.is_some() {
+                require!(
+                    legacy.ticket_type == TicketType::CopyrightClaim && !legacy.resolved,
+                    ProtocolError::InvalidAccount
+                );
+                require!(payer == legacy.reporter, ProtocolError::Unauthorized);
+            }
+            Ok(legacy.migrate(collection.unwrap_or_default()))
+        },
+        |ticket| ModTicket::BASE_SIZE + ticket.claim_indices.len() * 2 + ticket.claim_shares_bps.len() * 2,
+    )
+}
+
+/// Decode `info` as the legacy layout `L` of account type `T`. Fails if `T`'s discriminator
+/// does not match, or if the account already deserializes at the current version.
+fn read_legacy<T, L>(info: &AccountInfo, is_current: impl FnOnce(&T) -> bool) -> Result<L>
+where
+    T: AccountDeserialize + Discriminator,
+    L: AnchorDeserialize,
+{
+    let data = info.try_borrow_data()?;
+    if let Ok(current) = T::try_deserialize(&mut &data[..]) {
+        require!(!is_current(&current), ProtocolError::AccountAlreadyMigrated);
+    }
+    require!(data.starts_with(T::DISCRIMINATOR), ProtocolError::InvalidAccount);
+    L::deserialize(&mut &data[8..]).map_err(|_| ProtocolError::InvalidAccount.into())
+}
+
+/// Rewrite a legacy account of type `T` at its current layout. These layouts only grow,
+/// so the account is never shrunk and the payer funds the extra rent.
+fn migrate_account<'info, T, L>(
+    payer: &Signer<'info>,
+    account: &UncheckedAccount<'info>,
+    system_program: &Program<'info, System>,
+    name: &str,
+    is_current: impl FnOnce(&T) -> bool,
+    migrate: impl FnOnce(L) -> Result<T>,
+    space: impl FnOnce(&T) -> usize,
+) -> Result<()>
+where
+    T: AccountSerialize + AccountDeserialize + Discriminator,
+    L: AnchorDeserialize,
+{
+    let info = account.to_account_info();
+    let migrated = migrate(read_legacy::<T, L>(&info, is_current)?)?;
+    let new_len = space(&migrated).max(info.data_len());
+
+    let payer = payer.to_account_info();
+    rewrite_account(
+        &info,
+        &payer,
+        &payer,
+        &system_program.to_account_info(),
+        new_len,
+        &migrated,
+    )?;
+
+    msg!(
+        "AccountMigrated: Type={} Account={} Version=0 -> 1 Size={}",
+        name,
+        info.key(),
+        new_len
+    );
+    Ok(())
+}
+
+/// Resize `account` to `new_len`, keep it exactly rent-exempt, and write `migrated` over it.
+/// Returns the lamports refunded to `refund_to` (0 if the account grew).
+fn rewrite_account<'info, T: AccountSerialize>(
+    account: &AccountInfo<'info>,
+    payer: &AccountInfo<'info>,
+    refund_to: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+    new_len: usize,
+    migrated: &T,
+) -> Result<u64> {
+    let required = Rent::get()?.minimum_balance(new_len);
+    let current = account.lamports();
+
+    if required > current {
+        let cpi_ctx = CpiContext::new(
+            system_program.clone(),
+            Transfer {
+                from: payer.clone(),
+                to: account.clone(),
+            },
+        );
+        transfer(cpi_ctx, required - current)?;
+    }
+
+    account.resize(new_len)?;
+
+    let refunded = current.saturating_sub(required);
+    if refunded > 0 {
+        **account.try_borrow_mut_lamports()? -= refunded;
+        **refund_to.try_borrow_mut_lamports()? = refund_to
+            .lamports()
+            .checked_add(refunded)
+            .ok_or(ProtocolError::MathOverflow)?;
+    }
+
+    let mut data = account.try_borrow_mut_data()?;
+    data.fill(0);
+    migrated.try_serialize(&mut &mut data[..])?;
+    Ok(refunded)
+}
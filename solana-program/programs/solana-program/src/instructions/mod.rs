@@ -5,6 +5,12 @@ pub mod pinner;
 pub mod moderation;
 pub mod staking;
 pub mod orca;
+pub mod treasury;
+pub mod governance;
+pub mod migration;
+pub mod services;
+pub mod launch;
+pub mod oracle;
 
 pub use admin::*;
 pub use user::*;
@@ -13,3 +19,9 @@ pub use pinner::*;
 pub use moderation::*;
 pub use staking::*;
 pub use orca::*;
+pub use treasury::*;
+pub use governance::*;
+pub use migration::*;
+pub use services::*;
+pub use launch::*;
+pub use oracle::*;
@@ -1,6 +1,7 @@
 // solana-program/programs/solana-program/src/instructions/moderation.rs
 use anchor_lang::prelude::*;
-use anchor_spl::token_interface::{TokenInterface, TransferChecked, Mint};
+use anchor_lang::solana_program::sysvar::slot_hashes;
+use anchor_spl::token_interface::{TokenInterface, TransferChecked, Mint, TokenAccount};
 use crate::state::*;
 use crate::errors::ProtocolError;
 use crate::constants::*;
@@ -17,7 +18,7 @@ pub struct CidCensorshipEvent {
 }
 
 #[derive(Accounts)]
-#[instruction(target_id: String)]
+#[instruction(target_id: String, ticket_type: TicketType, reason: String, evidence_hash: [u8; 32], claim_indices: Vec<u16>, claim_shares_bps: Vec<u16>)]
 pub struct CreateTicket<'info> {
     #[account(mut)]
     pub reporter: Signer<'info>,
@@ -25,9 +26,8 @@ pub struct CreateTicket<'info> {
     #[account(
         init,
         payer = reporter,
-        // Calculate space dynamically: base size + 4 (vec length) + (claim_indices.len() * 2) bytes
-        // For now, use a reasonable default (assume max 32 indices = 64 bytes)
-        space = ModTicket::BASE_SIZE + 64,
+        // Calculate space dynamically: base size + 2 bytes per claimed index + 2 bytes per share
+        space = ModTicket::BASE_SIZE + claim_indices.len() * 2 + claim_shares_bps.len() * 2,
         seeds = [b"ticket", target_id.as_bytes()],
         bump
     )]
@@ -37,6 +37,23 @@ pub struct CreateTicket<'info> {
     /// Used to verify the claim deadline hasn't passed at ticket creation time
     #[account(mut)]
     pub collection: Option<Account<'info, CollectionState>>,
+
+    #[account(
+        seeds = [SEED_GLOBAL_STATE],
+        bump = global_state.bump,
+        constraint = !global_state.is_paused(PAUSE_MODERATION) @ ProtocolError::ProtocolPaused
+    )]
+    pub global_state: Account<'info, GlobalState>,
+
+    #[account(
+        seeds = [SEED_MODERATOR_REGISTRY],
+        bump = moderator_registry.bump
+    )]
+    pub moderator_registry: Account<'info, ModeratorRegistry>,
+
+    /// CHECK: SlotHashes sysvar (randomness seed for moderator assignment), read as raw bytes
+    #[account(address = slot_hashes::ID)]
+    pub slot_hashes: UncheckedAccount<'info>,
     
     pub system_program: Program<'info, System>,
     pub clock: Sysvar<'info, Clock>,
@@ -50,7 +67,8 @@ pub struct ResolveTicket<'info> {
 
     #[account(
         seeds = [SEED_GLOBAL_STATE],
-        bump = global_state.bump
+        bump = global_state.bump,
+        constraint = !global_state.is_paused(PAUSE_MODERATION) @ ProtocolError::ProtocolPaused
     )]
     pub global_state: Account<'info, GlobalState>,
 
@@ -62,11 +80,22 @@ pub struct ResolveTicket<'info> {
     )]
     pub moderator_stake: Account<'info, ModeratorStake>,
     
-    #[account(mut)]
+    /// Copyright claims and CID censorship have their own resolution paths
+    /// (resolve_copyright_claim, resolve_cid_censorship)
+    #[account(
+        mut,
+        constraint = matches!(
+            ticket.ticket_type,
+            TicketType::ContentReport | TicketType::ServiceEndpointReport
+        ) @ ProtocolError::Unauthorized,
+        constraint = moderator_stake.can_resolve(ticket.ticket_type) @ ProtocolError::ModeratorNotEligible,
+        constraint = ticket.is_assigned_to(&moderator.key()) @ ProtocolError::ModeratorNotAssigned
+    )]
     pub ticket: Account<'info, ModTicket>,
     
     /// Optional: Collection account (required if ticket is ContentReport and verdict is true)
-    /// CHECK: Collection account - only needed for ContentReport blacklisting
+    /// CHECK: Collection account - only needed for ContentReport blacklisting; must be the
+    /// collection the ticket was filed against
     #[account(mut)]
     pub collection: Option<Account<'info, CollectionState>>,
 }
@@ -78,7 +107,8 @@ pub struct ResolveCopyrightClaim<'info> {
 
     #[account(
         seeds = [SEED_GLOBAL_STATE],
-        bump = global_state.bump
+        bump = global_state.bump,
+        constraint = !global_state.is_paused(PAUSE_MODERATION) @ ProtocolError::ProtocolPaused
     )]
     pub global_state: Account<'info, GlobalState>,
 
@@ -90,7 +120,11 @@ pub struct ResolveCopyrightClaim<'info> {
     )]
     pub moderator_stake: Account<'info, ModeratorStake>,
     
-    #[account(mut)]
+    #[account(
+        mut,
+        constraint = moderator_stake.can_resolve(ticket.ticket_type) @ ProtocolError::ModeratorNotEligible,
+        constraint = ticket.is_assigned_to(&moderator.key()) @ ProtocolError::ModeratorNotAssigned
+    )]
     pub ticket: Account<'info, ModTicket>,
 
     #[account(
@@ -124,10 +158,19 @@ pub fn create_ticket(
     target_id: String, 
     ticket_type: TicketType,
     reason: String,
+    evidence_hash: [u8; 32],
     claim_indices: Vec<u16>,
+    claim_shares_bps: Vec<u16>,
 ) -> Result<()> {
     require!(target_id.len() <= crate::state::MAX_ID_LEN, ProtocolError::StringTooLong);
     require!(reason.len() <= crate::state::MAX_REASON_LEN, ProtocolError::StringTooLong);
+    require!(claim_indices.len() <= MAX_CLAIM_INDICES, ProtocolError::InvalidAccount);
+    require!(
+        claim_shares_bps.len() == claim_indices.len(),
+        ProtocolError::InvalidFeeConfig
+    );
+    // Endpoint reports must name the endpoint (report_service_endpoint)
+    require!(ticket_type != TicketType::ServiceEndpointReport, ProtocolError::InvalidAccount);
     
     // ⚠️ SECURITY: For CopyrightClaim tickets, verify the claim deadline hasn't passed
     // This prevents creating tickets after the deadline, but once created, tickets remain
@@ -142,9 +185,18 @@ pub fn create_ticket(
             ProtocolError::Unauthorized
         );
         
-        // Validate indices against collection limits
-        for &idx in &claim_indices {
+        // Validate indices against collection limits and reject duplicates
+        for (i, &idx) in claim_indices.iter().enumerate() {
             require!(idx < collection.total_videos, ProtocolError::InvalidAccount);
+            require!(!claim_indices[..i].contains(&idx), ProtocolError::InvalidAccount);
+        }
+
+        // Each share must be a non-zero fraction of a single video's allocation
+        for &share_bps in &claim_shares_bps {
+            require!(
+                share_bps > 0 && share_bps <= FULL_SHARE_BPS,
+                ProtocolError::InvalidFeeConfig
+            );
         }
     }
     
@@ -160,7 +212,308 @@ pub fn create_ticket(
     ticket.resolver = None;
     ticket.created_at = clock.unix_timestamp;
     ticket.claim_indices = claim_indices; // Store indices
+    ticket.claim_shares_bps = claim_shares_bps;
+    ticket.collection = ctx.accounts.collection.as_ref()
+        .map(|c| c.key())
+        .unwrap_or_default();
+    ticket.evidence_hash = evidence_hash;
+    ticket.counter_notice_hash = [0u8; 32];
+    ticket.counter_notice_at = 0;
+    ticket.refund_per_purchase = 0;
+    ticket.refund_claims_remaining = 0;
+    ticket.refund_purchase_cutoff = 0;
+    ticket.reported_operator = Pubkey::default();
+    ticket.reported_service = None;
+    ticket.version = ModTicket::VERSION;
+    ticket.bump = ctx.bumps.ticket;
+
+    assign_new_ticket(
+        ticket,
+        &ctx.accounts.moderator_registry,
+        ctx.accounts.global_state.moderator_stake_minimum,
+        &ctx.accounts.slot_hashes,
+        clock,
+    )
+}
+
+#[derive(Accounts)]
+#[instruction(target_id: String)]
+pub struct ReportServiceEndpoint<'info> {
+    #[account(mut)]
+    pub reporter: Signer<'info>,
+
+    #[account(
+        init,
+        payer = reporter,
+        space = ModTicket::BASE_SIZE,
+        seeds = [b"ticket", target_id.as_bytes()],
+        bump
+    )]
+    pub ticket: Account<'info, ModTicket>,
+
+    #[account(
+        seeds = [SEED_SERVICE_REGISTRY],
+        bump = service_registry.bump
+    )]
+    pub service_registry: Account<'info, ServiceRegistry>,
+
+    #[account(
+        seeds = [SEED_GLOBAL_STATE],
+        bump = global_state.bump,
+        constraint = !global_state.is_paused(PAUSE_MODERATION) @ ProtocolError::ProtocolPaused
+    )]
+    pub global_state: Account<'info, GlobalState>,
+
+    #[account(
+        seeds = [SEED_MODERATOR_REGISTRY],
+        bump = moderator_registry.bump
+    )]
+    pub moderator_registry: Account<'info, ModeratorRegistry>,
+
+    /// CHECK: SlotHashes sysvar (randomness seed for moderator assignment), read as raw bytes
+    #[account(address = slot_hashes::ID)]
+    pub slot_hashes: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+    pub clock: Sysvar<'info, Clock>,
+}
+
+/// Open a ServiceEndpointReport ticket against a listed (or unbonding) endpoint.
+/// If the assigned moderators uphold it, anyone may call delist_service_endpoint
+/// to forfeit the operator's bond.
+pub fn report_service_endpoint(
+    ctx: Context<ReportServiceEndpoint>,
+    target_id: String,
+    operator: Pubkey,
+    service_type: ServiceType,
+    reason: String,
+    evidence_hash: [u8; 32],
+) -> Result<()> {
+    require!(target_id.len() <= crate::state::MAX_ID_LEN, ProtocolError::StringTooLong);
+    require!(reason.len() <= crate::state::MAX_REASON_LEN, ProtocolError::StringTooLong);
+    require!(
+        ctx.accounts.service_registry.position(&operator, service_type).is_some(),
+        ProtocolError::ServiceEndpointNotFound
+    );
+
+    let ticket = &mut ctx.accounts.ticket;
+    let clock = &ctx.accounts.clock;
+
+    ticket.reporter = ctx.accounts.reporter.key();
+    ticket.target_id = target_id;
+    ticket.ticket_type = TicketType::ServiceEndpointReport;
+    ticket.reason = reason;
+    ticket.resolved = false;
+    ticket.verdict = false;
+    ticket.resolver = None;
+    ticket.created_at = clock.unix_timestamp;
+    ticket.claim_indices = Vec::new();
+    ticket.claim_shares_bps = Vec::new();
+    ticket.collection = Pubkey::default();
+    ticket.evidence_hash = evidence_hash;
+    ticket.counter_notice_hash = [0u8; 32];
+    ticket.counter_notice_at = 0;
+    ticket.refund_per_purchase = 0;
+    ticket.refund_claims_remaining = 0;
+    ticket.refund_purchase_cutoff = 0;
+    ticket.reported_operator = operator;
+    ticket.reported_service = Some(service_type);
+    ticket.version = ModTicket::VERSION;
     ticket.bump = ctx.bumps.ticket;
+
+    msg!(
+        "ServiceEndpointReported: Ticket={} Operator={} Type={:?}",
+        ticket.key(),
+        operator,
+        service_type
+    );
+
+    assign_new_ticket(
+        ticket,
+        &ctx.accounts.moderator_registry,
+        ctx.accounts.global_state.moderator_stake_minimum,
+        &ctx.accounts.slot_hashes,
+        clock,
+    )
+}
+
+/// Assign resolvers at random so moderators cannot race to claim specific tickets
+fn assign_new_ticket(
+    ticket: &mut Account<ModTicket>,
+    moderator_registry: &ModeratorRegistry,
+    stake_minimum: u64,
+    slot_hashes: &AccountInfo,
+    clock: &Clock,
+) -> Result<()> {
+    let seed = assignment_seed(
+        &read_recent_slot_hash(slot_hashes)?,
+        &ticket.key(),
+        clock.slot,
+    );
+    ticket.assigned_moderators = select_moderators(
+        moderator_registry,
+        ticket.ticket_type,
+        stake_minimum,
+        seed,
+        &[],
+    )?;
+    ticket.assigned_at = clock.unix_timestamp;
+
+    msg!(
+        "TicketAssigned: Ticket={} Moderators={:?}",
+        ticket.key(),
+        ticket.assigned_moderators
+    );
+    Ok(())
+}
+
+// ============================================================================
+// Moderator Assignment
+// ============================================================================
+
+/// Returns the most recent slot hash from the SlotHashes sysvar.
+/// The sysvar is too large to deserialize on-chain, so the first entry is read directly:
+/// 8 (vec length) + 8 (slot) + 32 (hash)
+fn read_recent_slot_hash(slot_hashes: &AccountInfo) -> Result<[u8; 32]> {
+    let data = slot_hashes.try_borrow_data()?;
+    require!(data.len() >= 48, ProtocolError::InvalidAccount);
+    let mut hash = [0u8; 32];
+    hash.copy_from_slice(&data[16..48]);
+    Ok(hash)
+}
+
+/// Folds the slot hash, ticket address and current slot into a 64-bit PRNG seed
+fn assignment_seed(slot_hash: &[u8; 32], ticket: &Pubkey, slot: u64) -> u64 {
+    let ticket_bytes = ticket.to_bytes();
+    let mut seed = slot;
+    for (chunk, ticket_chunk) in slot_hash.chunks_exact(8).zip(ticket_bytes.chunks_exact(8)) {
+        let a = u64::from_le_bytes(chunk.try_into().unwrap());
+        let b = u64::from_le_bytes(ticket_chunk.try_into().unwrap());
+        seed = splitmix64(seed ^ a ^ b.rotate_left(32));
+    }
+    seed
+}
+
+/// SplitMix64 step: cheap, well-distributed mixing of the seed for each draw
+fn splitmix64(state: u64) -> u64 {
+    let mut z = state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+    z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+    z ^ (z >> 31)
+}
+
+/// Stake-weighted sampling without replacement of up to MODERATORS_PER_TICKET active moderators
+/// qualified for `ticket_type`. Moderators in `exclude` are skipped unless no one else is eligible.
+/// Fails with NoEligibleModerators rather than leaving the ticket open to any moderator.
+fn select_moderators(
+    registry: &ModeratorRegistry,
+    ticket_type: TicketType,
+    stake_minimum: u64,
+    seed: u64,
+    exclude: &[Pubkey],
+) -> Result<Vec<Pubkey>> {
+    let required = ticket_type.required_specialization();
+    let eligible: Vec<&ModeratorEntry> = registry.moderators.iter()
+        .filter(|e| e.is_active && e.specializations & required != 0 && e.stake_amount >= stake_minimum)
+        .collect();
+    require!(!eligible.is_empty(), ProtocolError::NoEligibleModerators);
+    let mut candidates: Vec<&ModeratorEntry> = eligible.iter()
+        .copied()
+        .filter(|e| !exclude.contains(&e.moderator))
+        .collect();
+    if candidates.is_empty() {
+        candidates = eligible;
+    }
+
+    let mut selected = Vec::with_capacity(MODERATORS_PER_TICKET);
+    let mut state = seed;
+    for _ in 0..MODERATORS_PER_TICKET {
+        let total_weight: u128 = candidates.iter().map(|e| e.stake_amount as u128).sum();
+        if total_weight == 0 {
+            break;
+        }
+        state = splitmix64(state);
+        let mut target = state as u128 % total_weight;
+        let mut pick = candidates.len() - 1;
+        for (i, entry) in candidates.iter().enumerate() {
+            let weight = entry.stake_amount as u128;
+            if target < weight {
+                pick = i;
+                break;
+            }
+            target -= weight;
+        }
+        selected.push(candidates.swap_remove(pick).moderator);
+    }
+    Ok(selected)
+}
+
+#[derive(Accounts)]
+pub struct ReassignTicket<'info> {
+    pub caller: Signer<'info>,
+
+    #[account(
+        mut,
+        constraint = !ticket.resolved @ ProtocolError::TicketAlreadyResolved
+    )]
+    pub ticket: Account<'info, ModTicket>,
+
+    #[account(
+        seeds = [SEED_GLOBAL_STATE],
+        bump = global_state.bump,
+        constraint = !global_state.is_paused(PAUSE_MODERATION) @ ProtocolError::ProtocolPaused
+    )]
+    pub global_state: Account<'info, GlobalState>,
+
+    #[account(
+        seeds = [SEED_MODERATOR_REGISTRY],
+        bump = moderator_registry.bump
+    )]
+    pub moderator_registry: Account<'info, ModeratorRegistry>,
+
+    /// CHECK: SlotHashes sysvar (randomness seed for moderator assignment), read as raw bytes
+    #[account(address = slot_hashes::ID)]
+    pub slot_hashes: UncheckedAccount<'info>,
+
+    pub clock: Sysvar<'info, Clock>,
+}
+
+/// Permissionless crank: draws a fresh set of moderators for a ticket whose assignees
+/// have not resolved it within MODERATOR_ASSIGNMENT_TIMEOUT_SECONDS.
+/// Previously assigned moderators are excluded when others are available.
+pub fn reassign_ticket(ctx: Context<ReassignTicket>) -> Result<()> {
+    let ticket = &mut ctx.accounts.ticket;
+    let clock = &ctx.accounts.clock;
+
+    require!(
+        clock.unix_timestamp >= ticket.assigned_at
+            .checked_add(MODERATOR_ASSIGNMENT_TIMEOUT_SECONDS)
+            .ok_or(ProtocolError::MathOverflow)?,
+        ProtocolError::AssignmentNotExpired
+    );
+
+    let seed = assignment_seed(
+        &read_recent_slot_hash(&ctx.accounts.slot_hashes)?,
+        &ticket.key(),
+        clock.slot,
+    );
+    let previous = std::mem::take(&mut ticket.assigned_moderators);
+    ticket.assigned_moderators = select_moderators(
+        &ctx.accounts.moderator_registry,
+        ticket.ticket_type,
+        ctx.accounts.global_state.moderator_stake_minimum,
+        seed,
+        &previous,
+    )?;
+    ticket.assigned_at = clock.unix_timestamp;
+
+    msg!(
+        "TicketReassigned: Ticket={} Caller={} Moderators={:?}",
+        ticket.key(),
+        ctx.accounts.caller.key(),
+        ticket.assigned_moderators
+    );
+
     Ok(())
 }
 
@@ -178,6 +531,7 @@ pub fn resolve_ticket(ctx: Context<ResolveTicket>, verdict: bool) -> Result<()>
     // Handle ContentReport: blacklist collection if approved
     if ticket.ticket_type == TicketType::ContentReport && verdict {
         if let Some(collection) = &mut ctx.accounts.collection {
+            require!(collection.key() == ticket.collection, ProtocolError::Unauthorized);
             collection.is_blacklisted = true;
             msg!("ContentReportApproved: Collection {} blacklisted", collection.collection_id);
         } else {
@@ -194,6 +548,8 @@ pub fn resolve_ticket(ctx: Context<ResolveTicket>, verdict: bool) -> Result<()>
 
 /// Resolves a copyright claim by transferring the claim vault tokens to the claimant.
 /// This is called when a moderator approves a CopyrightClaim ticket.
+/// If the collection owner submitted a counter-notice, resolution is blocked until
+/// COUNTER_NOTICE_RESPONSE_SECONDS have passed since the counter-notice.
 /// 
 /// ⚠️ SECURITY: Automatically reads the full balance from claim_vault to prevent
 /// accidental or malicious partial transfers that would leave dust in the vault.
@@ -211,6 +567,24 @@ pub fn resolve_copyright_claim(ctx: Context<ResolveCopyrightClaim>, verdict: boo
         return err!(ProtocolError::TicketAlreadyResolved);
     }
 
+    // The claim must be resolved against the collection it was filed against
+    require!(
+        ticket.collection == collection.key(),
+        ProtocolError::Unauthorized
+    );
+
+    // If the owner filed a counter-notice, the claimant gets a response window
+    // before moderators may rule on the claim
+    if ticket.counter_notice_at != 0 {
+        let window_end = ticket.counter_notice_at
+            .checked_add(COUNTER_NOTICE_RESPONSE_SECONDS)
+            .ok_or(ProtocolError::MathOverflow)?;
+        require!(
+            ctx.accounts.clock.unix_timestamp >= window_end,
+            ProtocolError::CounterNoticeWindowActive
+        );
+    }
+
     // ⚠️ SECURITY: Deadline check removed from resolution.
     // The deadline is now enforced at ticket creation time (in create_ticket).
     // Once a ticket is created before the deadline, it remains resolvable even if
@@ -232,38 +606,44 @@ pub fn resolve_copyright_claim(ctx: Context<ResolveCopyrightClaim>, verdict: boo
         // 1. Verify Claim Indices
         require!(!ticket.claim_indices.is_empty(), ProtocolError::InvalidFeeConfig);
         
-        // 2. Check Bitmap for double-claims
-        for &video_idx in &ticket.claim_indices {
-            let byte_idx = (video_idx / 8) as usize;
-            let bit_idx = (video_idx % 8) as u8;
-            
+        // 2. Check the claimed-share table: each video can pay out at most 100% in total,
+        //    split across any number of rights holders
+        require!(
+            ticket.claim_shares_bps.len() == ticket.claim_indices.len(),
+            ProtocolError::InvalidFeeConfig
+        );
+        for (&video_idx, &share_bps) in ticket.claim_indices.iter().zip(ticket.claim_shares_bps.iter()) {
+            let idx = video_idx as usize;
+
             // Check bounds
-            require!(byte_idx < collection.claimed_bitmap.len(), ProtocolError::InvalidAccount);
-            
-            // Check if bit is already set
-            let is_claimed = (collection.claimed_bitmap[byte_idx] >> bit_idx) & 1;
-            require!(is_claimed == 0, ProtocolError::Unauthorized); // "Already Claimed" error
+            require!(idx < collection.claimed_share_bps.len(), ProtocolError::InvalidAccount);
+
+            let new_claimed = collection.claimed_share_bps[idx]
+                .checked_add(share_bps)
+                .ok_or(ProtocolError::MathOverflow)?;
+            require!(new_claimed <= FULL_SHARE_BPS, ProtocolError::Unauthorized); // "Already Claimed" error
         }
 
         // 3. Calculate Proportional Amount
-        // Share = (Initial_Vault / Total_Videos) * Claimed_Count
+        // Share = (Initial_Vault / Total_Videos) * Sum(Share_Bps) / 10000
         // Use initial amount to maintain stable value per video
-        let count_claimed = ticket.claim_indices.len() as u64;
         let per_video_share = collection.claim_vault_initial_amount
             .checked_div(collection.total_videos as u64)
             .ok_or(ProtocolError::MathOverflow)?;
-            
-        let payout_amount = per_video_share
-            .checked_mul(count_claimed)
+        let total_share_bps: u64 = ticket.claim_shares_bps.iter().map(|&bps| bps as u64).sum();
+
+        let payout_amount = (per_video_share as u128)
+            .checked_mul(total_share_bps as u128)
+            .ok_or(ProtocolError::MathOverflow)?
+            .checked_div(FULL_SHARE_BPS as u128)
             .ok_or(ProtocolError::MathOverflow)?;
+        let payout_amount = u64::try_from(payout_amount).map_err(|_| ProtocolError::MathOverflow)?;
 
         require!(payout_amount > 0, ProtocolError::InsufficientFunds);
 
-        // 4. Update Bitmap (Mark as claimed)
-        for &video_idx in &ticket.claim_indices {
-            let byte_idx = (video_idx / 8) as usize;
-            let bit_idx = (video_idx % 8) as u8;
-            collection.claimed_bitmap[byte_idx] |= 1 << bit_idx;
+        // 4. Update claimed shares
+        for (&video_idx, &share_bps) in ticket.claim_indices.iter().zip(ticket.claim_shares_bps.iter()) {
+            collection.claimed_share_bps[video_idx as usize] += share_bps;
         }
 
         // 5. Transfer Calculated Amount
@@ -292,11 +672,12 @@ pub fn resolve_copyright_claim(ctx: Context<ResolveCopyrightClaim>, verdict: boo
         anchor_spl::token_interface::transfer_checked(cpi_ctx, payout_amount, ctx.accounts.collection_mint.decimals)?;
         
         msg!(
-            "CopyrightClaimPaid: Collection={} Claimant={} Amount={} Indices={:?}",
+            "CopyrightClaimPaid: Collection={} Claimant={} Amount={} Indices={:?} SharesBps={:?}",
             collection_id,
             ticket.reporter,
             payout_amount,
-            ticket.claim_indices
+            ticket.claim_indices,
+            ticket.claim_shares_bps
         );
     } else {
         msg!(
@@ -309,6 +690,66 @@ pub fn resolve_copyright_claim(ctx: Context<ResolveCopyrightClaim>, verdict: boo
     Ok(())
 }
 
+#[derive(Accounts)]
+pub struct SubmitCounterNotice<'info> {
+    pub owner: Signer<'info>,
+
+    #[account(
+        seeds = [SEED_GLOBAL_STATE],
+        bump = global_state.bump,
+        constraint = !global_state.is_paused(PAUSE_MODERATION) @ ProtocolError::ProtocolPaused
+    )]
+    pub global_state: Account<'info, GlobalState>,
+
+    #[account(
+        seeds = [b"collection", collection.owner.as_ref(), collection.collection_id.as_bytes()],
+        bump = collection.bump,
+        constraint = collection.owner == owner.key() @ ProtocolError::Unauthorized
+    )]
+    pub collection: Account<'info, CollectionState>,
+
+    #[account(
+        mut,
+        constraint = ticket.ticket_type == TicketType::CopyrightClaim @ ProtocolError::Unauthorized,
+        constraint = ticket.collection == collection.key() @ ProtocolError::Unauthorized
+    )]
+    pub ticket: Account<'info, ModTicket>,
+
+    pub clock: Sysvar<'info, Clock>,
+}
+
+/// Lets the collection owner contest a pending CopyrightClaim.
+/// counter_notice_hash: SHA-256 hash of the owner's off-chain evidence CID (same scheme as evidence_hash).
+/// Once submitted, the claim cannot be resolved until the claimant's response window has passed.
+pub fn submit_counter_notice(
+    ctx: Context<SubmitCounterNotice>,
+    counter_notice_hash: [u8; 32],
+) -> Result<()> {
+    let ticket = &mut ctx.accounts.ticket;
+
+    if ticket.resolved {
+        return err!(ProtocolError::TicketAlreadyResolved);
+    }
+    require!(
+        ticket.counter_notice_at == 0,
+        ProtocolError::CounterNoticeAlreadySubmitted
+    );
+
+    let now = ctx.accounts.clock.unix_timestamp;
+    ticket.counter_notice_hash = counter_notice_hash;
+    ticket.counter_notice_at = now;
+
+    msg!(
+        "CounterNoticeSubmitted: Ticket={} Collection={} Owner={} ResolvableAt={}",
+        ticket.target_id,
+        ctx.accounts.collection.collection_id,
+        ctx.accounts.owner.key(),
+        now + COUNTER_NOTICE_RESPONSE_SECONDS
+    );
+
+    Ok(())
+}
+
 #[derive(Accounts)]
 pub struct ResolveCidCensorship<'info> {
     #[account(mut)]
@@ -316,7 +757,8 @@ pub struct ResolveCidCensorship<'info> {
 
     #[account(
         seeds = [SEED_GLOBAL_STATE],
-        bump = global_state.bump
+        bump = global_state.bump,
+        constraint = !global_state.is_paused(PAUSE_MODERATION) @ ProtocolError::ProtocolPaused
     )]
     pub global_state: Account<'info, GlobalState>,
 
@@ -328,7 +770,11 @@ pub struct ResolveCidCensorship<'info> {
     )]
     pub moderator_stake: Account<'info, ModeratorStake>,
     
-    #[account(mut)]
+    #[account(
+        mut,
+        constraint = moderator_stake.can_resolve(ticket.ticket_type) @ ProtocolError::ModeratorNotEligible,
+        constraint = ticket.is_assigned_to(&moderator.key()) @ ProtocolError::ModeratorNotAssigned
+    )]
     pub ticket: Account<'info, ModTicket>,
 
     #[account(
@@ -344,6 +790,9 @@ pub struct ResolveCidCensorship<'info> {
 /// Resolves a CID censorship ticket by censoring a specific CID.
 /// This instruction updates the on-chain censored_bitmap and emits blockchain logs/notes for the indexer to pick up.
 /// The indexer will use these logs to flag the CID as censored in its database.
+///
+/// When approved, the censored video's share of the creator bond is reserved on the ticket
+/// and purchasers can claim it via claim_censorship_refund.
 pub fn resolve_cid_censorship(
     ctx: Context<ResolveCidCensorship>,
     verdict: bool,
@@ -364,6 +813,12 @@ pub fn resolve_cid_censorship(
         return err!(ProtocolError::TicketAlreadyResolved);
     }
 
+    // If the ticket was filed against a collection, it must be resolved against that collection
+    require!(
+        ticket.collection == Pubkey::default() || ticket.collection == collection.key(),
+        ProtocolError::Unauthorized
+    );
+
     // Validate the index bounds
     require!(video_index < collection.total_videos, ProtocolError::InvalidAccount);
 
@@ -386,6 +841,48 @@ pub fn resolve_cid_censorship(
 
     // Update the bitmap based on verdict
     if verdict {
+        let already_censored = (collection.censored_bitmap[byte_idx] >> bit_idx) & 1 == 1;
+        let already_reserved = (collection.refund_reserved_bitmap[byte_idx] >> bit_idx) & 1 == 1;
+
+        // Reserve the censored video's pro-rata share of the creator bond for purchasers.
+        // Share = Creator_Bond / Uncensored_Videos, split evenly across every purchase so far.
+        // Each video reserves at most once, even if it is un-censored and censored again.
+        if !already_censored && !already_reserved && collection.total_purchases > 0 {
+            let censored_count: u32 = collection.censored_bitmap.iter().map(|b| b.count_ones()).sum();
+            let uncensored_videos = (collection.total_videos as u64)
+                .checked_sub(censored_count as u64)
+                .ok_or(ProtocolError::MathOverflow)?;
+            let video_share = collection.creator_bond
+                .checked_div(uncensored_videos)
+                .ok_or(ProtocolError::MathOverflow)?;
+            let refund_per_purchase = video_share / collection.total_purchases;
+
+            if refund_per_purchase > 0 {
+                let reserved = refund_per_purchase
+                    .checked_mul(collection.total_purchases)
+                    .ok_or(ProtocolError::MathOverflow)?;
+                collection.creator_bond = collection.creator_bond
+                    .checked_sub(reserved)
+                    .ok_or(ProtocolError::MathOverflow)?;
+
+                collection.refund_reserved_bitmap[byte_idx] |= 1 << bit_idx;
+
+                ticket.collection = collection.key();
+                ticket.refund_per_purchase = refund_per_purchase;
+                ticket.refund_claims_remaining = collection.total_purchases;
+                ticket.refund_purchase_cutoff = collection.total_purchases;
+
+                msg!(
+                    "CensorshipRefundReserved: Collection={} VideoIndex={} PerPurchase={} Purchases={} Reserved={}",
+                    collection_id,
+                    video_index,
+                    refund_per_purchase,
+                    collection.total_purchases,
+                    reserved
+                );
+            }
+        }
+
         // Set the bit (Censor)
         collection.censored_bitmap[byte_idx] |= 1 << bit_idx;
         msg!("Video index {} marked as censored in on-chain bitmap", video_index);
@@ -414,3 +911,145 @@ pub fn resolve_cid_censorship(
 
     Ok(())
 }
+
+#[derive(Accounts)]
+#[instruction(access_nft_mint: Pubkey)]
+pub struct ClaimCensorshipRefund<'info> {
+    #[account(mut)]
+    pub claimant: Signer<'info>,
+
+    #[account(
+        seeds = [SEED_GLOBAL_STATE],
+        bump = global_state.bump,
+        constraint = !global_state.is_paused(PAUSE_UNSTAKING) @ ProtocolError::ProtocolPaused
+    )]
+    pub global_state: Account<'info, GlobalState>,
+
+    #[account(
+        seeds = [b"collection", collection.owner.as_ref(), collection.collection_id.as_bytes()],
+        bump = collection.bump
+    )]
+    pub collection: Account<'info, CollectionState>,
+
+    /// Approved CidCensorship ticket that reserved the refund budget
+    #[account(
+        mut,
+        constraint = ticket.ticket_type == TicketType::CidCensorship @ ProtocolError::Unauthorized,
+        constraint = ticket.resolved && ticket.verdict @ ProtocolError::NoRefundAvailable,
+        constraint = ticket.collection == collection.key() @ ProtocolError::Unauthorized
+    )]
+    pub ticket: Account<'info, ModTicket>,
+
+    /// One receipt per (ticket, Access NFT) - prevents double claims
+    #[account(
+        init,
+        payer = claimant,
+        space = CensorshipRefundReceipt::MAX_SIZE,
+        seeds = [SEED_CENSORSHIP_REFUND, ticket.key().as_ref(), access_nft_mint.as_ref()],
+        bump
+    )]
+    pub refund_receipt: Account<'info, CensorshipRefundReceipt>,
+
+    /// Proof of purchase: written by purchase_access for the claimant's Access NFT
+    #[account(
+        seeds = [SEED_PURCHASE_RECORD, access_nft_mint.as_ref()],
+        bump = purchase_record.bump,
+        constraint = purchase_record.purchaser == claimant.key() @ ProtocolError::Unauthorized,
+        constraint = purchase_record.collection == collection.key() @ ProtocolError::Unauthorized
+    )]
+    pub purchase_record: Account<'info, PurchaseRecord>,
+
+    /// Creator bond vault (source of refunds)
+    #[account(
+        mut,
+        seeds = [SEED_CREATOR_BOND, collection.key().as_ref()],
+        bump
+    )]
+    pub bond_vault: InterfaceAccount<'info, TokenAccount>,
+
+    /// Claimant's collection token account (destination)
+    #[account(
+        mut,
+        constraint = claimant_token_account.owner == claimant.key() @ ProtocolError::Unauthorized,
+        constraint = claimant_token_account.mint == collection_mint.key() @ ProtocolError::Unauthorized
+    )]
+    pub claimant_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(
+        constraint = collection_mint.key() == collection.mint @ ProtocolError::Unauthorized
+    )]
+    pub collection_mint: InterfaceAccount<'info, Mint>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>,
+    pub clock: Sysvar<'info, Clock>,
+}
+
+/// Pays a purchaser their share of the refund reserved by an approved CidCensorship ticket.
+/// Proof of purchase is the PurchaseRecord written for the claimant's Access NFT. Only
+/// purchases made before the censorship (the ones the reserved budget was sized for) qualify,
+/// so later buyers cannot drain the budget ahead of the affected purchasers. Each Access NFT
+/// can claim once per ticket.
+pub fn claim_censorship_refund(
+    ctx: Context<ClaimCensorshipRefund>,
+    access_nft_mint: Pubkey,
+) -> Result<()> {
+    let claimant_key = ctx.accounts.claimant.key();
+
+    let ticket = &mut ctx.accounts.ticket;
+    require!(
+        ticket.refund_per_purchase > 0 && ticket.refund_claims_remaining > 0,
+        ProtocolError::NoRefundAvailable
+    );
+    require!(
+        ctx.accounts.purchase_record.purchase_index < ticket.refund_purchase_cutoff,
+        ProtocolError::NoRefundAvailable
+    );
+    let refund_amount = ticket.refund_per_purchase;
+    ticket.refund_claims_remaining = ticket.refund_claims_remaining
+        .checked_sub(1)
+        .ok_or(ProtocolError::MathOverflow)?;
+
+    // Transfer from the bond vault using the Collection PDA authority
+    let collection = &ctx.accounts.collection;
+    let collection_seeds = [
+        b"collection".as_ref(),
+        collection.owner.as_ref(),
+        collection.collection_id.as_bytes(),
+        &[collection.bump],
+    ];
+    let signer_seeds = &[&collection_seeds[..]];
+
+    let transfer_ix = TransferChecked {
+        from: ctx.accounts.bond_vault.to_account_info(),
+        mint: ctx.accounts.collection_mint.to_account_info(),
+        to: ctx.accounts.claimant_token_account.to_account_info(),
+        authority: ctx.accounts.collection.to_account_info(),
+    };
+    let cpi_ctx = CpiContext::new_with_signer(
+        ctx.accounts.token_program.to_account_info(),
+        transfer_ix,
+        signer_seeds,
+    );
+    anchor_spl::token_interface::transfer_checked(cpi_ctx, refund_amount, ctx.accounts.collection_mint.decimals)?;
+
+    let receipt = &mut ctx.accounts.refund_receipt;
+    receipt.ticket = ticket.key();
+    receipt.claimant = claimant_key;
+    receipt.access_nft_mint = access_nft_mint;
+    receipt.amount = refund_amount;
+    receipt.claimed_at = ctx.accounts.clock.unix_timestamp;
+    receipt.version = CensorshipRefundReceipt::VERSION;
+    receipt.bump = ctx.bumps.refund_receipt;
+
+    msg!(
+        "CensorshipRefundClaimed: Collection={} Ticket={} Claimant={} Amount={} ClaimsRemaining={}",
+        collection.collection_id,
+        ticket.target_id,
+        claimant_key,
+        refund_amount,
+        ticket.refund_claims_remaining
+    );
+
+    Ok(())
+}
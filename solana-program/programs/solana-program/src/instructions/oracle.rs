@@ -0,0 +1,133 @@
+// solana-program/programs/solana-program/src/instructions/oracle.rs
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::Mint;
+use crate::state::*;
+use crate::errors::ProtocolError;
+use crate::constants::*;
+use crate::instructions::orca::{
+    read_pubkey, whirlpool_sqrt_price, ORCA_WHIRLPOOL_PROGRAM_ID,
+    WHIRLPOOL_TOKEN_MINT_A_OFFSET, WHIRLPOOL_TOKEN_MINT_B_OFFSET,
+};
+
+// ============================================================================
+// Pool Price Oracle
+// ============================================================================
+//
+// Most collection tokens never get a Pyth or Switchboard feed; their only market is
+// their own Whirlpool. A PriceObservation per Whirlpool keeps a ring of cumulative
+// sqrt prices, advanced by a permissionless crank at most once per
+// PRICE_OBSERVATION_INTERVAL_SECONDS, each sample clamped to PRICE_OBSERVATION_MAX_STEP_BPS
+// of the last. purchase_access prices collections without an
+// oracle_feed from the TWAPs of the collection pool and GlobalState.stablecoin_pool.
+// The first sample anchors every later clamp, so only the admin may create an observation.
+
+#[derive(Accounts)]
+pub struct InitializePriceObservation<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    #[account(
+        seeds = [SEED_GLOBAL_STATE],
+        bump = global_state.bump,
+        constraint = global_state.admin == admin.key() @ ProtocolError::Unauthorized
+    )]
+    pub global_state: Account<'info, GlobalState>,
+
+    /// CHECK: Any Whirlpool; mints are checked against its data below
+    #[account(owner = ORCA_WHIRLPOOL_PROGRAM_ID @ ProtocolError::InvalidAccount)]
+    pub whirlpool: UncheckedAccount<'info>,
+
+    pub token_mint_a: InterfaceAccount<'info, Mint>,
+    pub token_mint_b: InterfaceAccount<'info, Mint>,
+
+    #[account(
+        init,
+        payer = admin,
+        space = PriceObservation::MAX_SIZE,
+        seeds = [SEED_PRICE_OBSERVATION, whirlpool.key().as_ref()],
+        bump
+    )]
+    pub price_observation: Account<'info, PriceObservation>,
+
+    pub system_program: Program<'info, System>,
+    pub clock: Sysvar<'info, Clock>,
+}
+
+/// Admin only: seed a pool's observation from its current sqrt_price. A permissionless first
+/// sample could be taken in the same transaction as a price-moving swap, and each later sample
+/// may only step PRICE_OBSERVATION_MAX_STEP_BPS away from it.
+pub fn initialize_price_observation(ctx: Context<InitializePriceObservation>) -> Result<()> {
+    let whirlpool = ctx.accounts.whirlpool.to_account_info();
+    require!(
+        read_pubkey(&whirlpool, WHIRLPOOL_TOKEN_MINT_A_OFFSET)? == ctx.accounts.token_mint_a.key()
+            && read_pubkey(&whirlpool, WHIRLPOOL_TOKEN_MINT_B_OFFSET)? == ctx.accounts.token_mint_b.key(),
+        ProtocolError::InvalidAccount
+    );
+    let sqrt_price = whirlpool_sqrt_price(&whirlpool)?;
+    let now = ctx.accounts.clock.unix_timestamp;
+
+    let observation = &mut ctx.accounts.price_observation;
+    observation.whirlpool = whirlpool.key();
+    observation.decimals_a = ctx.accounts.token_mint_a.decimals;
+    observation.decimals_b = ctx.accounts.token_mint_b.decimals;
+    observation.last_sqrt_price = sqrt_price;
+    observation.samples = [PriceSample::default(); PRICE_OBSERVATION_SLOTS];
+    observation.samples[0] = PriceSample {
+        timestamp: now,
+        slot: ctx.accounts.clock.slot,
+        sqrt_price_cumulative: 0,
+    };
+    observation.head = 0;
+    observation.count = 1;
+    observation.version = PriceObservation::VERSION;
+    observation.bump = ctx.bumps.price_observation;
+
+    msg!(
+        "PriceObservationInitialized: Whirlpool={} SqrtPrice={}",
+        observation.whirlpool,
+        sqrt_price
+    );
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct RecordPriceObservation<'info> {
+    #[account(
+        seeds = [SEED_GLOBAL_STATE],
+        bump = global_state.bump,
+        constraint = !global_state.is_paused(PAUSE_PURCHASES) @ ProtocolError::ProtocolPaused
+    )]
+    pub global_state: Account<'info, GlobalState>,
+
+    #[account(
+        mut,
+        seeds = [SEED_PRICE_OBSERVATION, whirlpool.key().as_ref()],
+        bump = price_observation.bump
+    )]
+    pub price_observation: Account<'info, PriceObservation>,
+
+    /// CHECK: Bound to price_observation by its seeds
+    #[account(owner = ORCA_WHIRLPOOL_PROGRAM_ID @ ProtocolError::InvalidAccount)]
+    pub whirlpool: UncheckedAccount<'info>,
+
+    pub clock: Sysvar<'info, Clock>,
+}
+
+/// Permissionless crank: accrue the previous price and sample the pool's current sqrt_price.
+/// Paused with purchases, the only consumer of the TWAP.
+pub fn record_price_observation(ctx: Context<RecordPriceObservation>) -> Result<()> {
+    let clock = &ctx.accounts.clock;
+    let sqrt_price = whirlpool_sqrt_price(&ctx.accounts.whirlpool.to_account_info())?;
+
+    let observation = &mut ctx.accounts.price_observation;
+    observation.record(clock.unix_timestamp, clock.slot, sqrt_price)?;
+
+    msg!(
+        "PriceObserved: Whirlpool={} SqrtPrice={} Recorded={} Samples={}",
+        observation.whirlpool,
+        sqrt_price,
+        observation.last_sqrt_price,
+        observation.count
+    );
+    Ok(())
+}
@@ -1,6 +1,6 @@
 use anchor_lang::prelude::*;
 use anchor_lang::solana_program::instruction::{AccountMeta, Instruction};
-use anchor_lang::solana_program::program::invoke_signed;
+use anchor_lang::solana_program::program::{invoke, invoke_signed};
 use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface, TransferChecked};
 use anchor_spl::associated_token::AssociatedToken;
 use crate::state::*;
@@ -12,6 +12,9 @@ use crate::constants::*;
 /// Address: whirLbMiicVdio4qvUfM5KAg6Ct8VwpYzGff3uctyCc
 pub const ORCA_WHIRLPOOL_PROGRAM_ID: Pubkey = pubkey!("whirLbMiicVdio4qvUfM5KAg6Ct8VwpYzGff3uctyCc");
 
+/// SPL Memo program (required by Whirlpool *_v2 instructions for Token-2022 transfers)
+pub const MEMO_PROGRAM_ID: Pubkey = pubkey!("MemoSq4gqABAXKb96qnH8TysNcWxMyWCqXgDLGmfcHr");
+
 // ============================================================================
 // ORCA INSTRUCTION DISCRIMINATORS
 // ============================================================================
@@ -25,6 +28,51 @@ const OPEN_POSITION_WITH_METADATA_DISCRIMINATOR: [u8; 8] = [242, 16, 12, 155, 61
 /// Discriminator for increase_liquidity_v2 instruction
 const INCREASE_LIQUIDITY_V2_DISCRIMINATOR: [u8; 8] = [133, 29, 89, 223, 69, 238, 176, 10];
 
+/// Discriminator for update_fees_and_rewards instruction
+const UPDATE_FEES_AND_REWARDS_DISCRIMINATOR: [u8; 8] = [154, 230, 250, 13, 236, 209, 75, 223];
+
+/// Discriminator for collect_fees_v2 instruction
+const COLLECT_FEES_V2_DISCRIMINATOR: [u8; 8] = [207, 117, 95, 191, 229, 180, 226, 15];
+
+/// Discriminator for collect_reward_v2 instruction
+const COLLECT_REWARD_V2_DISCRIMINATOR: [u8; 8] = [177, 107, 37, 180, 160, 19, 49, 209];
+
+/// Discriminator for decrease_liquidity_v2 instruction
+const DECREASE_LIQUIDITY_V2_DISCRIMINATOR: [u8; 8] = [58, 127, 188, 62, 79, 82, 196, 96];
+
+/// Discriminator for close_position instruction
+const CLOSE_POSITION_DISCRIMINATOR: [u8; 8] = [123, 134, 81, 0, 49, 68, 98, 98];
+
+/// Discriminator for swap_v2 instruction
+const SWAP_V2_DISCRIMINATOR: [u8; 8] = [43, 4, 237, 11, 26, 201, 30, 98];
+
+/// Whirlpool sqrt price bounds (Q64.64); used as the swap price limit so only
+/// other_amount_threshold bounds slippage
+const MIN_SQRT_PRICE_X64: u128 = 4_295_048_016;
+const MAX_SQRT_PRICE_X64: u128 = 79_226_673_515_401_279_992_447_579_055;
+
+/// Byte offset of `whirlpools_config: Pubkey` in a Whirlpool account (after the discriminator)
+pub(crate) const WHIRLPOOL_CONFIG_OFFSET: usize = 8;
+
+/// Byte offset of `sqrt_price: u128` in a Whirlpool account (8 discriminator
+/// + 32 whirlpools_config + 1 bump + 2 tick_spacing + 2 fee_tier_index_seed + 2 fee_rate
+/// + 2 protocol_fee_rate + 16 liquidity)
+const WHIRLPOOL_SQRT_PRICE_OFFSET: usize = 65;
+
+/// Byte offsets of `token_mint_a` / `token_mint_b` in a Whirlpool account (after 16 sqrt_price,
+/// 4 tick_current_index and 8 + 8 protocol_fee_owed; mint_b follows 32 token_vault_a and
+/// 16 fee_growth_global_a)
+pub(crate) const WHIRLPOOL_TOKEN_MINT_A_OFFSET: usize = 101;
+pub(crate) const WHIRLPOOL_TOKEN_MINT_B_OFFSET: usize = 181;
+
+/// Byte offsets of `whirlpool` / `position_mint` in a Whirlpool Position account
+pub(crate) const POSITION_WHIRLPOOL_OFFSET: usize = 8;
+pub(crate) const POSITION_MINT_OFFSET: usize = 40;
+
+/// Byte offset of `liquidity: u128` in a Whirlpool Position account
+/// (8 discriminator + 32 whirlpool + 32 position_mint)
+const POSITION_LIQUIDITY_OFFSET: usize = 72;
+
 // ============================================================================
 // HELPER STRUCTS FOR SERIALIZATION
 // ============================================================================
@@ -36,6 +84,22 @@ pub struct IncreaseLiquidityV2Params {
     pub token_max_b: u64,
 }
 
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct DecreaseLiquidityV2Params {
+    pub liquidity_amount: u128,
+    pub token_min_a: u64,
+    pub token_min_b: u64,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct SwapV2Params {
+    pub amount: u64,
+    pub other_amount_threshold: u64,
+    pub sqrt_price_limit: u128,
+    pub amount_specified_is_input: bool,
+    pub a_to_b: bool,
+}
+
 #[derive(AnchorSerialize, AnchorDeserialize)]
 pub struct OpenPositionParams {
     pub tick_lower_index: i32,
@@ -52,12 +116,20 @@ pub struct InitializeOrcaPool<'info> {
     #[account(mut)]
     pub creator: Signer<'info>,
 
+    #[account(
+        seeds = [SEED_GLOBAL_STATE],
+        bump = global_state.bump,
+        constraint = !global_state.is_paused(PAUSE_MINTING) @ ProtocolError::ProtocolPaused
+    )]
+    pub global_state: Account<'info, GlobalState>,
+
     /// Collection state account
     #[account(
         mut,
         seeds = [b"collection", collection.owner.as_ref(), collection.collection_id.as_bytes()],
         bump = collection.bump,
-        constraint = collection.owner == creator.key() @ ProtocolError::Unauthorized
+        constraint = collection.owner == creator.key() @ ProtocolError::Unauthorized,
+        constraint = collection.launch_stage == LaunchStage::TokensMinted @ ProtocolError::InvalidLaunchStage
     )]
     pub collection: Account<'info, CollectionState>,
 
@@ -68,13 +140,34 @@ pub struct InitializeOrcaPool<'info> {
     pub collection_mint: InterfaceAccount<'info, Mint>,
 
     /// CAPGM token mint (token B - the quote currency)
+    /// Whirlpools order their mints by key, so the collection mint must sort first
+    #[account(
+        constraint = capgm_mint.key() == global_state.capgm_mint @ ProtocolError::InvalidPoolConfig,
+        constraint = collection_mint.key() < capgm_mint.key() @ ProtocolError::InvalidPoolConfig
+    )]
     pub capgm_mint: InterfaceAccount<'info, Mint>,
 
-    /// Whirlpool config account
-    /// CHECK: Validated by Orca program
-    #[account(owner = ORCA_WHIRLPOOL_PROGRAM_ID)]
+    /// Whirlpool config account (must be the allowlisted GlobalState.whirlpools_config)
+    /// CHECK: Validated against global_state and by Orca program
+    #[account(
+        owner = ORCA_WHIRLPOOL_PROGRAM_ID,
+        constraint = whirlpool_config.key() == global_state.whirlpools_config @ ProtocolError::InvalidPoolConfig
+    )]
     pub whirlpool_config: UncheckedAccount<'info>,
 
+    /// Stablecoin/CAPGM Whirlpool, read to value CAPGM for the initial price band
+    /// CHECK: Validated against global_state; only sqrt_price is read
+    #[account(
+        owner = ORCA_WHIRLPOOL_PROGRAM_ID,
+        constraint = stablecoin_pool.key() == global_state.stablecoin_pool @ ProtocolError::InvalidPoolConfig
+    )]
+    pub stablecoin_pool: UncheckedAccount<'info>,
+
+    #[account(
+        constraint = stablecoin_mint.key() == global_state.stablecoin_mint @ ProtocolError::InvalidPoolConfig
+    )]
+    pub stablecoin_mint: InterfaceAccount<'info, Mint>,
+
     /// Token badge A
     /// CHECK: Validated by Orca program
     pub token_badge_a: UncheckedAccount<'info>,
@@ -129,6 +222,15 @@ pub fn initialize_orca_pool(
         ProtocolError::InvalidFeeConfig
     );
 
+    check_launch_price(
+        &ctx.accounts.collection,
+        &ctx.accounts.collection_mint,
+        &ctx.accounts.capgm_mint,
+        &ctx.accounts.stablecoin_mint,
+        &ctx.accounts.stablecoin_pool,
+        initial_sqrt_price,
+    )?;
+
     // Build instruction data
     let mut data = Vec::with_capacity(8 + 2 + 16);
     data.extend_from_slice(&INITIALIZE_POOL_V2_DISCRIMINATOR);
@@ -178,19 +280,65 @@ pub fn initialize_orca_pool(
         &[],
     )?;
 
+    ctx.accounts.collection.launch_stage = LaunchStage::PoolInitialized;
+
     msg!("Orca Whirlpool initialized successfully!");
     Ok(())
 }
 
+/// Sanity band: at `sqrt_price`, one access must cost between MIN_LAUNCH_TOKENS_PER_ACCESS and
+/// MAX_LAUNCH_TOKENS_PER_ACCESS whole collection tokens. Checked by initialize_orca_pool and,
+/// for pools initialized outside the protocol, by resume_launch.
+pub(crate) fn check_launch_price<'info>(
+    collection: &CollectionState,
+    collection_mint: &InterfaceAccount<'info, Mint>,
+    capgm_mint: &InterfaceAccount<'info, Mint>,
+    stablecoin_mint: &InterfaceAccount<'info, Mint>,
+    stablecoin_pool: &AccountInfo<'info>,
+    sqrt_price: u128,
+) -> Result<()> {
+    let stablecoin_is_a = stablecoin_mint.key() < capgm_mint.key();
+    let tokens_per_access = access_cost_in_tokens(
+        collection.access_threshold_usd,
+        stablecoin_mint.decimals,
+        sqrt_price,
+        whirlpool_sqrt_price(stablecoin_pool)?,
+        stablecoin_is_a,
+    )
+    .map_err(|_| ProtocolError::InvalidPoolConfig)?;
+
+    let whole_token = 10u128.pow(collection_mint.decimals as u32);
+    msg!("Launch price: {} collection tokens per access", tokens_per_access / whole_token);
+    require!(
+        (MIN_LAUNCH_TOKENS_PER_ACCESS as u128 * whole_token..=MAX_LAUNCH_TOKENS_PER_ACCESS as u128 * whole_token)
+            .contains(&tokens_per_access),
+        ProtocolError::InvalidPoolConfig
+    );
+    Ok(())
+}
+
 #[derive(Accounts)]
 pub struct OpenOrcaPosition<'info> {
     #[account(mut)]
     pub creator: Signer<'info>,
 
+    /// Opening the launch position is pool setup; re-opening one is rebalancing
     #[account(
+        seeds = [SEED_GLOBAL_STATE],
+        bump = global_state.bump,
+        constraint = !global_state.is_paused(PAUSE_MINTING | PAUSE_LIQUIDITY) @ ProtocolError::ProtocolPaused
+    )]
+    pub global_state: Account<'info, GlobalState>,
+
+    /// The owner opens the first position and re-opens one after close_orca_position
+    #[account(
+        mut,
         seeds = [b"collection", collection.owner.as_ref(), collection.collection_id.as_bytes()],
         bump = collection.bump,
-        constraint = collection.owner == creator.key() @ ProtocolError::Unauthorized
+        constraint = collection.owner == creator.key() @ ProtocolError::Unauthorized,
+        constraint = matches!(collection.launch_stage, LaunchStage::PoolInitialized | LaunchStage::Launched) @ ProtocolError::InvalidLaunchStage,
+        // One live position at a time; close_orca_position clears position_mint
+        constraint = collection.position_mint == Pubkey::default() @ ProtocolError::InvalidAccount
     )]
     pub collection: Account<'info, CollectionState>,
 
@@ -299,6 +447,12 @@ pub fn open_orca_position(
         &[],
     )?;
 
+    let collection = &mut ctx.accounts.collection;
+    collection.position_mint = ctx.accounts.position_mint.key();
+    if collection.launch_stage == LaunchStage::PoolInitialized {
+        collection.launch_stage = LaunchStage::PositionOpened;
+    }
+
     msg!("Position opened successfully!");
     Ok(())
 }
@@ -308,11 +462,19 @@ pub struct DepositLiquidityToOrca<'info> {
     #[account(mut)]
     pub creator: Signer<'info>,
 
+    #[account(
+        seeds = [SEED_GLOBAL_STATE],
+        bump = global_state.bump,
+        constraint = !global_state.is_paused(PAUSE_MINTING) @ ProtocolError::ProtocolPaused
+    )]
+    pub global_state: Account<'info, GlobalState>,
+
     #[account(
         mut,
         seeds = [b"collection", collection.owner.as_ref(), collection.collection_id.as_bytes()],
         bump = collection.bump,
-        constraint = collection.owner == creator.key() @ ProtocolError::Unauthorized
+        constraint = collection.owner == creator.key() @ ProtocolError::Unauthorized,
+        constraint = collection.launch_stage == LaunchStage::PositionOpened @ ProtocolError::InvalidLaunchStage
     )]
     pub collection: Account<'info, CollectionState>,
 
@@ -335,7 +497,10 @@ pub struct DepositLiquidityToOrca<'info> {
     )]
     pub position_token_account: InterfaceAccount<'info, TokenAccount>,
     
-    /// CHECK: Validated by constraint on position_token_account
+    /// CHECK: Must be the collection's live position mint
+    #[account(
+        constraint = position_mint.key() == collection.position_mint @ ProtocolError::InvalidAccount
+    )]
     pub position_mint: UncheckedAccount<'info>,
 
     #[account(
@@ -350,14 +515,6 @@ pub struct DepositLiquidityToOrca<'info> {
     )]
     pub collection_reserve_a: InterfaceAccount<'info, TokenAccount>,
 
-    /// Creator's token account for token A (Collection Tokens) - receives refund of unused tokens
-    #[account(
-        mut,
-        constraint = creator_token_a.mint == token_mint_a.key() @ ProtocolError::Unauthorized,
-        constraint = creator_token_a.owner == creator.key() @ ProtocolError::Unauthorized
-    )]
-    pub creator_token_a: InterfaceAccount<'info, TokenAccount>,
-
     pub token_mint_b: InterfaceAccount<'info, Mint>,
 
     #[account(
@@ -400,6 +557,7 @@ pub struct DepositLiquidityToOrca<'info> {
     pub token_program: Interface<'info, TokenInterface>,
     pub associated_token_program: Program<'info, AssociatedToken>,
     pub system_program: Program<'info, System>,
+    pub clock: Sysvar<'info, Clock>,
 }
 
 pub fn deposit_liquidity_to_orca(
@@ -443,8 +601,8 @@ pub fn deposit_liquidity_to_orca(
     // This represents the total amount available for Orca to use
     let balance_after_transfer_b = ctx.accounts.collection_reserve_b.amount;
     
-    // ⚠️ SECURITY: Track balance of collection_reserve_a before Orca call
-    // This is needed to calculate unused token_max_a for refund
+    // Track balance of collection_reserve_a before Orca call
+    // This is needed to record the launch token deposit
     let balance_before_orca_a = ctx.accounts.collection_reserve_a.amount;
 
     // STEP 2: Execute Orca CPI
@@ -568,57 +726,1360 @@ pub fn deposit_liquidity_to_orca(
         msg!("No refund needed: all {} token B were used", token_max_b);
     }
 
-    // STEP 4: Refund unused token A (Collection Tokens) back to creator
-    // ⚠️ SECURITY: This prevents unused Collection Tokens from being locked in collection_reserve_a
-    // Reload the account to get updated balance after Orca call
+    // STEP 4: Unused token A (Collection Tokens) stays in collection_reserve_a
+    // The liquidity share belongs to the collection, not the creator; it remains in custody
+    // for increase_orca_liquidity
     ctx.accounts.collection_reserve_a.reload()?;
     let balance_after_orca_a = ctx.accounts.collection_reserve_a.amount;
-    
-    // Calculate how much was actually used by Orca
-    // This is the difference between balance before Orca call and balance after Orca call
     let actual_used_a = balance_before_orca_a
         .checked_sub(balance_after_orca_a)
         .ok_or(ProtocolError::MathOverflow)?;
-    
-    // Calculate refund amount: min(token_max_a - actual_used_a, balance_after_orca_a)
-    // This refunds any excess that wasn't needed due to pool price/tick math
-    // We use min() to handle cases where collection_reserve_a had fewer tokens than token_max_a
-    let unused_from_max = token_max_a
-        .checked_sub(actual_used_a)
+    msg!(
+        "Token A deposited: {} (max: {}, left in reserve: {})",
+        actual_used_a,
+        token_max_a,
+        balance_after_orca_a
+    );
+
+    // Record the launch deposits for the LP lock and vesting (see check_liquidity_floor)
+    let collection = &mut ctx.accounts.collection;
+    collection.launch_token_deposit = actual_used_a;
+    collection.creator_capgm_deposit = actual_used_b;
+    collection.liquidity_locked_at = ctx.accounts.clock.unix_timestamp;
+
+    // The initial liquidity is in place; the collection is open for purchases
+    collection.launch_stage = LaunchStage::Launched;
+
+    msg!("=== Flash Deposit Complete! ===");
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct CollectOrcaFees<'info> {
+    /// Anyone may crank fee collection
+    pub cranker: Signer<'info>,
+
+    #[account(
+        seeds = [SEED_GLOBAL_STATE],
+        bump = global_state.bump,
+        constraint = !global_state.is_paused(PAUSE_LIQUIDITY) @ ProtocolError::ProtocolPaused
+    )]
+    pub global_state: Account<'info, GlobalState>,
+
+    #[account(
+        mut,
+        seeds = [b"collection", collection.owner.as_ref(), collection.collection_id.as_bytes()],
+        bump = collection.bump
+    )]
+    pub collection: Account<'info, CollectionState>,
+
+    /// CHECK: The collection's staking pool, which may not exist until someone stakes; read and
+    /// written in the handler
+    #[account(
+        mut,
+        seeds = [SEED_STAKING_POOL, collection.key().as_ref()],
+        bump
+    )]
+    pub staking_pool: UncheckedAccount<'info>,
+
+    /// Staking pool's collection token account (receives the staker share; required only when
+    /// tokens are staked)
+    #[account(
+        mut,
+        constraint = pool_token_account.owner == staking_pool.key() @ ProtocolError::Unauthorized,
+        constraint = pool_token_account.mint == token_mint_a.key() @ ProtocolError::Unauthorized
+    )]
+    pub pool_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    /// CHECK: Validated against collection
+    #[account(
+        mut,
+        constraint = whirlpool.key() == collection.pool_address @ ProtocolError::Unauthorized,
+        owner = ORCA_WHIRLPOOL_PROGRAM_ID
+    )]
+    pub whirlpool: UncheckedAccount<'info>,
+
+    /// CHECK: Validated by Orca program against position_token_account
+    #[account(mut, owner = ORCA_WHIRLPOOL_PROGRAM_ID)]
+    pub position: UncheckedAccount<'info>,
+
+    #[account(
+        constraint = position_token_account.owner == collection.key() @ ProtocolError::Unauthorized,
+        constraint = position_token_account.mint == collection.position_mint @ ProtocolError::InvalidAccount,
+        constraint = position_token_account.amount == 1 @ ProtocolError::Unauthorized
+    )]
+    pub position_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    /// Tick array for lower bound
+    /// CHECK: Managed by Orca program
+    #[account(owner = ORCA_WHIRLPOOL_PROGRAM_ID)]
+    pub tick_array_lower: UncheckedAccount<'info>,
+
+    /// Tick array for upper bound
+    /// CHECK: Managed by Orca program
+    #[account(owner = ORCA_WHIRLPOOL_PROGRAM_ID)]
+    pub tick_array_upper: UncheckedAccount<'info>,
+
+    #[account(
+        constraint = token_mint_a.key() == collection.mint @ ProtocolError::Unauthorized
+    )]
+    pub token_mint_a: InterfaceAccount<'info, Mint>,
+
+    #[account(
+        constraint = token_mint_b.key() == global_state.capgm_mint @ ProtocolError::InvalidAccount
+    )]
+    pub token_mint_b: InterfaceAccount<'info, Mint>,
+
+    #[account(
+        mut,
+        associated_token::mint = token_mint_a,
+        associated_token::authority = collection,
+        associated_token::token_program = token_program,
+    )]
+    pub collection_reserve_a: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        associated_token::mint = token_mint_b,
+        associated_token::authority = collection,
+        associated_token::token_program = token_program,
+    )]
+    pub collection_reserve_b: InterfaceAccount<'info, TokenAccount>,
+
+    /// CHECK: Managed by Orca program
+    #[account(mut)]
+    pub token_vault_a: UncheckedAccount<'info>,
+
+    /// CHECK: Managed by Orca program
+    #[account(mut)]
+    pub token_vault_b: UncheckedAccount<'info>,
+
+    /// CHECK: Orca Whirlpool program (validated by address constraint)
+    #[account(address = ORCA_WHIRLPOOL_PROGRAM_ID)]
+    pub whirlpool_program: UncheckedAccount<'info>,
+
+    /// CHECK: SPL Memo program (validated by address constraint)
+    #[account(address = MEMO_PROGRAM_ID)]
+    pub memo_program: UncheckedAccount<'info>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+/// Harvest trading fees (and optionally rewards) earned by the collection's Whirlpool position.
+/// Both sides are split by collection.lp_fee_staker_bps between stakers and the owner.
+/// The collection token share moves into the staking pool and its reward index; the CAPGM share
+/// stays in reserve B against staker_capgm_reward_balance and accrues to capgm_reward_per_token
+/// (paid by claim_staking_capgm_rewards). With nothing staked, or before the staking pool exists,
+/// the staker share goes to the owner.
+/// remaining_accounts: one [reward_mint, reward_vault] pair per entry in `reward_indices`;
+/// only rewards paid in the collection token or CAPGM can be collected.
+pub fn collect_orca_fees<'info>(
+    ctx: Context<'_, '_, 'info, 'info, CollectOrcaFees<'info>>,
+    reward_indices: Vec<u8>,
+) -> Result<()> {
+    require!(
+        ctx.remaining_accounts.len() == reward_indices.len() * 2,
+        ProtocolError::InvalidAccount
+    );
+
+    let balance_before_a = ctx.accounts.collection_reserve_a.amount;
+    let balance_before_b = ctx.accounts.collection_reserve_b.amount;
+
+    let collection = &ctx.accounts.collection;
+    let seeds = [
+        b"collection".as_ref(),
+        collection.owner.as_ref(),
+        collection.collection_id.as_bytes(),
+        &[collection.bump],
+    ];
+    let signer_seeds = &[&seeds[..]];
+
+    // STEP 1: Checkpoint the position's owed fees and rewards
+    // (Orca rejects this for empty positions, whose fees were checkpointed when liquidity was removed)
+    if position_liquidity(&ctx.accounts.position)? > 0 {
+        invoke_signed(
+            &Instruction {
+                program_id: ORCA_WHIRLPOOL_PROGRAM_ID,
+                accounts: vec![
+                    AccountMeta::new(ctx.accounts.whirlpool.key(), false),
+                    AccountMeta::new(ctx.accounts.position.key(), false),
+                    AccountMeta::new_readonly(ctx.accounts.tick_array_lower.key(), false),
+                    AccountMeta::new_readonly(ctx.accounts.tick_array_upper.key(), false),
+                ],
+                data: UPDATE_FEES_AND_REWARDS_DISCRIMINATOR.to_vec(),
+            },
+            &[
+                ctx.accounts.whirlpool.to_account_info(),
+                ctx.accounts.position.to_account_info(),
+                ctx.accounts.tick_array_lower.to_account_info(),
+                ctx.accounts.tick_array_upper.to_account_info(),
+            ],
+            &[],
+        )?;
+    }
+
+    // STEP 2: Collect trading fees into the collection reserves
+    let mut data = Vec::with_capacity(8 + 1);
+    data.extend_from_slice(&COLLECT_FEES_V2_DISCRIMINATOR);
+    data.push(0u8); // remaining_accounts_info: None
+
+    invoke_signed(
+        &Instruction {
+            program_id: ORCA_WHIRLPOOL_PROGRAM_ID,
+            accounts: vec![
+                AccountMeta::new_readonly(ctx.accounts.whirlpool.key(), false),
+                AccountMeta::new_readonly(collection.key(), true), // position_authority
+                AccountMeta::new(ctx.accounts.position.key(), false),
+                AccountMeta::new_readonly(ctx.accounts.position_token_account.key(), false),
+                AccountMeta::new_readonly(ctx.accounts.token_mint_a.key(), false),
+                AccountMeta::new_readonly(ctx.accounts.token_mint_b.key(), false),
+                AccountMeta::new(ctx.accounts.collection_reserve_a.key(), false),
+                AccountMeta::new(ctx.accounts.token_vault_a.key(), false),
+                AccountMeta::new(ctx.accounts.collection_reserve_b.key(), false),
+                AccountMeta::new(ctx.accounts.token_vault_b.key(), false),
+                AccountMeta::new_readonly(ctx.accounts.token_program.key(), false),
+                AccountMeta::new_readonly(ctx.accounts.token_program.key(), false),
+                AccountMeta::new_readonly(ctx.accounts.memo_program.key(), false),
+            ],
+            data,
+        },
+        &[
+            ctx.accounts.whirlpool.to_account_info(),
+            collection.to_account_info(),
+            ctx.accounts.position.to_account_info(),
+            ctx.accounts.position_token_account.to_account_info(),
+            ctx.accounts.token_mint_a.to_account_info(),
+            ctx.accounts.token_mint_b.to_account_info(),
+            ctx.accounts.collection_reserve_a.to_account_info(),
+            ctx.accounts.token_vault_a.to_account_info(),
+            ctx.accounts.collection_reserve_b.to_account_info(),
+            ctx.accounts.token_vault_b.to_account_info(),
+            ctx.accounts.token_program.to_account_info(),
+            ctx.accounts.memo_program.to_account_info(),
+        ],
+        signer_seeds,
+    )?;
+
+    // STEP 3: Collect any requested rewards that pay out in the collection token or CAPGM
+    for (reward_index, pair) in reward_indices.iter().zip(ctx.remaining_accounts.chunks(2)) {
+        let reward_mint = &pair[0];
+        let reward_vault = &pair[1];
+        let reward_owner_account = if reward_mint.key() == ctx.accounts.token_mint_a.key() {
+            ctx.accounts.collection_reserve_a.to_account_info()
+        } else if reward_mint.key() == ctx.accounts.token_mint_b.key() {
+            ctx.accounts.collection_reserve_b.to_account_info()
+        } else {
+            return err!(ProtocolError::InvalidAccount);
+        };
+
+        let mut data = Vec::with_capacity(8 + 1 + 1);
+        data.extend_from_slice(&COLLECT_REWARD_V2_DISCRIMINATOR);
+        data.push(*reward_index);
+        data.push(0u8); // remaining_accounts_info: None
+
+        invoke_signed(
+            &Instruction {
+                program_id: ORCA_WHIRLPOOL_PROGRAM_ID,
+                accounts: vec![
+                    AccountMeta::new_readonly(ctx.accounts.whirlpool.key(), false),
+                    AccountMeta::new_readonly(collection.key(), true), // position_authority
+                    AccountMeta::new(ctx.accounts.position.key(), false),
+                    AccountMeta::new_readonly(ctx.accounts.position_token_account.key(), false),
+                    AccountMeta::new(reward_owner_account.key(), false),
+                    AccountMeta::new_readonly(reward_mint.key(), false),
+                    AccountMeta::new(reward_vault.key(), false),
+                    AccountMeta::new_readonly(ctx.accounts.token_program.key(), false),
+                    AccountMeta::new_readonly(ctx.accounts.memo_program.key(), false),
+                ],
+                data,
+            },
+            &[
+                ctx.accounts.whirlpool.to_account_info(),
+                collection.to_account_info(),
+                ctx.accounts.position.to_account_info(),
+                ctx.accounts.position_token_account.to_account_info(),
+                reward_owner_account,
+                reward_mint.clone(),
+                reward_vault.clone(),
+                ctx.accounts.token_program.to_account_info(),
+                ctx.accounts.memo_program.to_account_info(),
+            ],
+            signer_seeds,
+        )?;
+    }
+
+    // STEP 4: Measure what was harvested
+    ctx.accounts.collection_reserve_a.reload()?;
+    ctx.accounts.collection_reserve_b.reload()?;
+    let harvested_a = ctx.accounts.collection_reserve_a.amount
+        .checked_sub(balance_before_a)
         .ok_or(ProtocolError::MathOverflow)?;
-    
-    // Refund is the minimum of: unused amount from token_max_a, and what's actually left in reserve
-    let refund_amount_a = unused_from_max.min(balance_after_orca_a);
-    
-    if refund_amount_a > 0 {
-        msg!("Refunding unused token A: {} (provided: {}, used: {}, remaining: {})", 
-             refund_amount_a, token_max_a, actual_used_a, balance_after_orca_a);
-        
-        // Transfer refund from collection_reserve_a back to creator
-        let refund_accounts_a = TransferChecked {
+    let harvested_b = ctx.accounts.collection_reserve_b.amount
+        .checked_sub(balance_before_b)
+        .ok_or(ProtocolError::MathOverflow)?;
+
+    // STEP 5: Route the staker share of collection token fees into the reward index
+    let staking_pool_info = ctx.accounts.staking_pool.to_account_info();
+    let mut staking_pool = if staking_pool_info.data_is_empty() {
+        None
+    } else {
+        Some(CollectionStakingPool::try_deserialize(&mut &staking_pool_info.data.borrow()[..])?)
+    };
+    let total_staked = staking_pool.as_ref().map_or(0, |pool| pool.total_staked);
+    let to_stakers = lp_fee_staker_share(harvested_a, collection.lp_fee_staker_bps, total_staked)?;
+    let to_owner = harvested_a
+        .checked_sub(to_stakers)
+        .ok_or(ProtocolError::MathOverflow)?;
+
+    if to_stakers > 0 {
+        let pool_token_account = ctx.accounts.pool_token_account.as_ref()
+            .ok_or(ProtocolError::InvalidAccount)?;
+        let transfer_to_pool = TransferChecked {
             from: ctx.accounts.collection_reserve_a.to_account_info(),
             mint: ctx.accounts.token_mint_a.to_account_info(),
-            to: ctx.accounts.creator_token_a.to_account_info(),
-            authority: ctx.accounts.collection.to_account_info(),
+            to: pool_token_account.to_account_info(),
+            authority: collection.to_account_info(),
         };
-        
-        let refund_ctx_a = CpiContext::new_with_signer(
+        let cpi_ctx = CpiContext::new_with_signer(
             ctx.accounts.token_program.to_account_info(),
-            refund_accounts_a,
+            transfer_to_pool,
             signer_seeds,
         );
-        
-        anchor_spl::token_interface::transfer_checked(
-            refund_ctx_a,
-            refund_amount_a,
-            ctx.accounts.token_mint_a.decimals
-        )?;
-        
-        msg!("Refund complete: {} token A returned to creator", refund_amount_a);
-    } else {
-        msg!("No refund needed: all {} token A were used (or none available)", token_max_a);
+        anchor_spl::token_interface::transfer_checked(cpi_ctx, to_stakers, ctx.accounts.token_mint_a.decimals)?;
+
+        // to_stakers is only non-zero when the pool exists and holds stake
+        let staking_pool = staking_pool.as_mut().ok_or(ProtocolError::MathOverflow)?;
+        let reward_increment = (to_stakers as u128)
+            .checked_mul(REWARD_PRECISION)
+            .ok_or(ProtocolError::MathOverflow)?
+            .checked_div(staking_pool.total_staked as u128)
+            .ok_or(ProtocolError::MathOverflow)?;
+        staking_pool.reward_per_token = staking_pool.reward_per_token
+            .checked_add(reward_increment)
+            .ok_or(ProtocolError::MathOverflow)?;
     }
 
-    msg!("=== Flash Deposit Complete! ===");
+    // STEP 6: Accrue the staker share of CAPGM fees (it stays in reserve B until claimed)
+    let capgm_to_stakers = lp_fee_staker_share(harvested_b, collection.lp_fee_staker_bps, total_staked)?;
+    let capgm_to_owner = harvested_b
+        .checked_sub(capgm_to_stakers)
+        .ok_or(ProtocolError::MathOverflow)?;
+
+    if capgm_to_stakers > 0 {
+        let staking_pool = staking_pool.as_mut().ok_or(ProtocolError::MathOverflow)?;
+        let reward_increment = (capgm_to_stakers as u128)
+            .checked_mul(REWARD_PRECISION)
+            .ok_or(ProtocolError::MathOverflow)?
+            .checked_div(staking_pool.total_staked as u128)
+            .ok_or(ProtocolError::MathOverflow)?;
+        staking_pool.capgm_reward_per_token = staking_pool.capgm_reward_per_token
+            .checked_add(reward_increment)
+            .ok_or(ProtocolError::MathOverflow)?;
+    }
+
+    if let Some(staking_pool) = staking_pool {
+        staking_pool.try_serialize(&mut &mut staking_pool_info.data.borrow_mut()[..])?;
+    }
+
+    // STEP 7: Credit the owner's balances (held in the collection reserves until claimed)
+    let collection_key = collection.key();
+    let collection = &mut ctx.accounts.collection;
+    collection.owner_reward_balance = collection.owner_reward_balance
+        .checked_add(to_owner)
+        .ok_or(ProtocolError::MathOverflow)?;
+    collection.owner_capgm_reward_balance = collection.owner_capgm_reward_balance
+        .checked_add(capgm_to_owner)
+        .ok_or(ProtocolError::MathOverflow)?;
+    collection.staker_capgm_reward_balance = collection.staker_capgm_reward_balance
+        .checked_add(capgm_to_stakers)
+        .ok_or(ProtocolError::MathOverflow)?;
+
+    msg!(
+        "OrcaFeesCollected: Collection={} HarvestedA={} HarvestedB={} ToStakersA={} ToStakersB={} ToOwnerA={} ToOwnerB={}",
+        collection_key,
+        harvested_a,
+        harvested_b,
+        to_stakers,
+        capgm_to_stakers,
+        to_owner,
+        capgm_to_owner
+    );
     Ok(())
-}
\ No newline at end of file
+}
+
+/// Staker share of `harvested` LP fees at `lp_fee_staker_bps`; nothing while the pool is empty
+fn lp_fee_staker_share(harvested: u64, lp_fee_staker_bps: u16, total_staked: u64) -> Result<u64> {
+    if total_staked == 0 {
+        return Ok(0);
+    }
+    let share = (harvested as u128)
+        .checked_mul(lp_fee_staker_bps as u128)
+        .ok_or(ProtocolError::MathOverflow)?
+        / FULL_SHARE_BPS as u128;
+    Ok(share as u64)
+}
+
+#[derive(Accounts)]
+pub struct ClaimOwnerRewards<'info> {
+    pub owner: Signer<'info>,
+
+    #[account(
+        seeds = [SEED_GLOBAL_STATE],
+        bump = global_state.bump,
+        constraint = !global_state.is_paused(PAUSE_UNSTAKING) @ ProtocolError::ProtocolPaused
+    )]
+    pub global_state: Account<'info, GlobalState>,
+
+    #[account(
+        mut,
+        seeds = [b"collection", collection.owner.as_ref(), collection.collection_id.as_bytes()],
+        bump = collection.bump,
+        constraint = collection.owner == owner.key() @ ProtocolError::Unauthorized
+    )]
+    pub collection: Account<'info, CollectionState>,
+
+    #[account(
+        constraint = token_mint_a.key() == collection.mint @ ProtocolError::Unauthorized
+    )]
+    pub token_mint_a: InterfaceAccount<'info, Mint>,
+
+    #[account(
+        constraint = token_mint_b.key() == global_state.capgm_mint @ ProtocolError::InvalidAccount
+    )]
+    pub token_mint_b: InterfaceAccount<'info, Mint>,
+
+    #[account(
+        mut,
+        associated_token::mint = token_mint_a,
+        associated_token::authority = collection,
+        associated_token::token_program = token_program,
+    )]
+    pub collection_reserve_a: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        associated_token::mint = token_mint_b,
+        associated_token::authority = collection,
+        associated_token::token_program = token_program,
+    )]
+    pub collection_reserve_b: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        token::mint = token_mint_a,
+        token::authority = owner,
+    )]
+    pub owner_token_a: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        token::mint = token_mint_b,
+        token::authority = owner,
+    )]
+    pub owner_token_b: InterfaceAccount<'info, TokenAccount>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+/// Withdraw the LP fees credited to the owner by collect_orca_fees
+pub fn claim_owner_rewards(ctx: Context<ClaimOwnerRewards>) -> Result<()> {
+    let collection = &ctx.accounts.collection;
+    let amount_a = collection.owner_reward_balance;
+    let amount_b = collection.owner_capgm_reward_balance;
+    require!(amount_a > 0 || amount_b > 0, ProtocolError::InsufficientFunds);
+
+    let seeds = [
+        b"collection".as_ref(),
+        collection.owner.as_ref(),
+        collection.collection_id.as_bytes(),
+        &[collection.bump],
+    ];
+    let signer_seeds = &[&seeds[..]];
+
+    if amount_a > 0 {
+        let transfer_a = TransferChecked {
+            from: ctx.accounts.collection_reserve_a.to_account_info(),
+            mint: ctx.accounts.token_mint_a.to_account_info(),
+            to: ctx.accounts.owner_token_a.to_account_info(),
+            authority: collection.to_account_info(),
+        };
+        let cpi_ctx = CpiContext::new_with_signer(ctx.accounts.token_program.to_account_info(), transfer_a, signer_seeds);
+        anchor_spl::token_interface::transfer_checked(cpi_ctx, amount_a, ctx.accounts.token_mint_a.decimals)?;
+    }
+
+    if amount_b > 0 {
+        let transfer_b = TransferChecked {
+            from: ctx.accounts.collection_reserve_b.to_account_info(),
+            mint: ctx.accounts.token_mint_b.to_account_info(),
+            to: ctx.accounts.owner_token_b.to_account_info(),
+            authority: collection.to_account_info(),
+        };
+        let cpi_ctx = CpiContext::new_with_signer(ctx.accounts.token_program.to_account_info(), transfer_b, signer_seeds);
+        anchor_spl::token_interface::transfer_checked(cpi_ctx, amount_b, ctx.accounts.token_mint_b.decimals)?;
+    }
+
+    let collection = &mut ctx.accounts.collection;
+    collection.owner_reward_balance = 0;
+    collection.owner_capgm_reward_balance = 0;
+
+    msg!(
+        "OwnerRewardsClaimed: Collection={} AmountA={} AmountB={}",
+        collection.key(),
+        amount_a,
+        amount_b
+    );
+    Ok(())
+}
+
+// ============================================================================
+// LIQUIDITY MANAGEMENT
+// ============================================================================
+//
+// Rebalancing moves the collection's liquidity to a new tick range without it ever leaving
+// collection custody: decrease_orca_liquidity -> collect_orca_fees -> close_orca_position ->
+// open_orca_position -> increase_orca_liquidity. Only the collection owner may rebalance, and
+// withdrawals are rate limited. Withdrawn tokens land in the collection reserves, and
+// re-deposits can only draw on reserve balances not owed to the owner or stakers.
+
+#[derive(Accounts)]
+pub struct ManageOrcaLiquidity<'info> {
+    /// Collection owner
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [SEED_GLOBAL_STATE],
+        bump = global_state.bump,
+        constraint = !global_state.is_paused(PAUSE_LIQUIDITY) @ ProtocolError::ProtocolPaused
+    )]
+    pub global_state: Account<'info, GlobalState>,
+
+    #[account(
+        mut,
+        seeds = [b"collection", collection.owner.as_ref(), collection.collection_id.as_bytes()],
+        bump = collection.bump,
+        constraint = collection.owner == authority.key() @ ProtocolError::Unauthorized,
+        constraint = collection.launch_stage == LaunchStage::Launched @ ProtocolError::InvalidLaunchStage
+    )]
+    pub collection: Account<'info, CollectionState>,
+
+    /// CHECK: Validated against collection
+    #[account(
+        mut,
+        constraint = whirlpool.key() == collection.pool_address @ ProtocolError::Unauthorized,
+        owner = ORCA_WHIRLPOOL_PROGRAM_ID
+    )]
+    pub whirlpool: UncheckedAccount<'info>,
+
+    /// CHECK: Validated by Orca program against position_token_account
+    #[account(mut, owner = ORCA_WHIRLPOOL_PROGRAM_ID)]
+    pub position: UncheckedAccount<'info>,
+
+    #[account(
+        constraint = position_token_account.owner == collection.key() @ ProtocolError::Unauthorized,
+        constraint = position_token_account.mint == collection.position_mint @ ProtocolError::InvalidAccount,
+        constraint = position_token_account.amount == 1 @ ProtocolError::Unauthorized
+    )]
+    pub position_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    /// Tick array for lower bound
+    /// CHECK: Managed by Orca program
+    #[account(mut, owner = ORCA_WHIRLPOOL_PROGRAM_ID)]
+    pub tick_array_lower: UncheckedAccount<'info>,
+
+    /// Tick array for upper bound
+    /// CHECK: Managed by Orca program
+    #[account(mut, owner = ORCA_WHIRLPOOL_PROGRAM_ID)]
+    pub tick_array_upper: UncheckedAccount<'info>,
+
+    #[account(
+        constraint = token_mint_a.key() == collection.mint @ ProtocolError::Unauthorized
+    )]
+    pub token_mint_a: InterfaceAccount<'info, Mint>,
+
+    #[account(
+        constraint = token_mint_b.key() == global_state.capgm_mint @ ProtocolError::InvalidAccount
+    )]
+    pub token_mint_b: InterfaceAccount<'info, Mint>,
+
+    #[account(
+        mut,
+        associated_token::mint = token_mint_a,
+        associated_token::authority = collection,
+        associated_token::token_program = token_program,
+    )]
+    pub collection_reserve_a: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        associated_token::mint = token_mint_b,
+        associated_token::authority = collection,
+        associated_token::token_program = token_program,
+    )]
+    pub collection_reserve_b: InterfaceAccount<'info, TokenAccount>,
+
+    /// CHECK: Managed by Orca program
+    #[account(mut)]
+    pub token_vault_a: UncheckedAccount<'info>,
+
+    /// CHECK: Managed by Orca program
+    #[account(mut)]
+    pub token_vault_b: UncheckedAccount<'info>,
+
+    /// CHECK: Orca Whirlpool program (validated by address constraint)
+    #[account(address = ORCA_WHIRLPOOL_PROGRAM_ID)]
+    pub whirlpool_program: UncheckedAccount<'info>,
+
+    /// CHECK: SPL Memo program (validated by address constraint)
+    #[account(address = MEMO_PROGRAM_ID)]
+    pub memo_program: UncheckedAccount<'info>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+    pub clock: Sysvar<'info, Clock>,
+}
+
+/// Withdraw liquidity from the collection's position into the collection reserves.
+/// token_min_a / token_min_b bound slippage; at most one withdrawal per
+/// LIQUIDITY_REBALANCE_COOLDOWN_SECONDS so liquidity cannot be kept out of the pool.
+/// Nothing may be withdrawn during the LP lock. Withdrawn tokens stay in the collection
+/// reserves for re-deposit; only claim_vested_liquidity pays liquidity out.
+pub fn decrease_orca_liquidity(
+    ctx: Context<ManageOrcaLiquidity>,
+    liquidity_amount: u128,
+    token_min_a: u64,
+    token_min_b: u64,
+) -> Result<()> {
+    require!(liquidity_amount > 0, ProtocolError::InvalidFeeConfig);
+
+    let now = ctx.accounts.clock.unix_timestamp;
+    check_lp_lock(&ctx.accounts.collection, now)?;
+    check_rebalance_cooldown(ctx.accounts.collection.last_rebalance_at, now)?;
+
+    let params = DecreaseLiquidityV2Params {
+        liquidity_amount,
+        token_min_a,
+        token_min_b,
+    };
+    let mut data = Vec::with_capacity(8 + 32 + 1);
+    data.extend_from_slice(&DECREASE_LIQUIDITY_V2_DISCRIMINATOR);
+    data.extend_from_slice(&params.try_to_vec()?);
+    data.push(0u8); // remaining_accounts_info: None
+
+    modify_liquidity_cpi(ctx.accounts, data)?;
+
+    ctx.accounts.collection.last_rebalance_at = now;
+
+    msg!(
+        "OrcaLiquidityDecreased: Collection={} Authority={} Liquidity={} MinA={} MinB={}",
+        ctx.accounts.collection.key(),
+        ctx.accounts.authority.key(),
+        liquidity_amount,
+        token_min_a,
+        token_min_b
+    );
+    Ok(())
+}
+
+/// No liquidity leaves the position until LP_LOCK_PERIOD_SECONDS after launch
+fn check_lp_lock(collection: &CollectionState, now: i64) -> Result<()> {
+    let lock_ends_at = collection.liquidity_locked_at
+        .checked_add(LP_LOCK_PERIOD_SECONDS)
+        .ok_or(ProtocolError::MathOverflow)?;
+    require!(now >= lock_ends_at, ProtocolError::LiquidityLocked);
+    Ok(())
+}
+
+/// Anti-rug floor in token amounts: CAPGM paid out of custody by claim_vested_liquidity may
+/// never exceed the unlocked share of the creator's launch deposit. Liquidity moved between
+/// the position and the collection reserves stays in custody, so rebalancing is not limited.
+fn check_liquidity_floor(collection: &CollectionState) -> Result<()> {
+    require!(
+        collection.creator_capgm_withdrawn <= unlocked_share(collection.creator_capgm_deposit)?,
+        ProtocolError::LiquidityLocked
+    );
+    Ok(())
+}
+
+/// Share of a launch deposit above LP_PERMANENT_LOCK_BPS
+fn unlocked_share(deposit: u64) -> Result<u64> {
+    let unlocked = (deposit as u128)
+        .checked_mul(10000 - LP_PERMANENT_LOCK_BPS as u128)
+        .ok_or(ProtocolError::MathOverflow)?
+        / 10000;
+    u64::try_from(unlocked).map_err(|_| ProtocolError::MathOverflow.into())
+}
+
+/// Reserve balances not owed as LP fees to the owner or stakers
+fn idle_reserves(
+    collection: &CollectionState,
+    collection_reserve_a: &TokenAccount,
+    collection_reserve_b: &TokenAccount,
+) -> Result<(u64, u64)> {
+    let idle_a = collection_reserve_a.amount
+        .checked_sub(collection.owner_reward_balance)
+        .ok_or(ProtocolError::MathOverflow)?;
+    let idle_b = collection_reserve_b.amount
+        .checked_sub(collection.owner_capgm_reward_balance)
+        .and_then(|amount| amount.checked_sub(collection.staker_capgm_reward_balance))
+        .ok_or(ProtocolError::MathOverflow)?;
+    Ok((idle_a, idle_b))
+}
+
+/// Withdrawals are rate limited to one per LIQUIDITY_REBALANCE_COOLDOWN_SECONDS
+fn check_rebalance_cooldown(last_rebalance_at: i64, now: i64) -> Result<()> {
+    let cooldown_ends_at = last_rebalance_at
+        .checked_add(LIQUIDITY_REBALANCE_COOLDOWN_SECONDS)
+        .ok_or(ProtocolError::MathOverflow)?;
+    require!(now >= cooldown_ends_at, ProtocolError::TimelockNotElapsed);
+    Ok(())
+}
+
+/// Re-deposit reserve tokens into the collection's (re-opened) position.
+/// token_max_a / token_max_b bound slippage and may not exceed the reserves
+/// left after the unclaimed owner and staker LP fee balances.
+pub fn increase_orca_liquidity(
+    ctx: Context<ManageOrcaLiquidity>,
+    liquidity_amount: u128,
+    token_max_a: u64,
+    token_max_b: u64,
+) -> Result<()> {
+    require!(liquidity_amount > 0, ProtocolError::InvalidFeeConfig);
+
+    let (available_a, available_b) = idle_reserves(
+        &ctx.accounts.collection,
+        &ctx.accounts.collection_reserve_a,
+        &ctx.accounts.collection_reserve_b,
+    )?;
+    require!(
+        token_max_a <= available_a && token_max_b <= available_b,
+        ProtocolError::InsufficientFunds
+    );
+
+    let params = IncreaseLiquidityV2Params {
+        liquidity_amount,
+        token_max_a,
+        token_max_b,
+    };
+    let mut data = Vec::with_capacity(8 + 32 + 1);
+    data.extend_from_slice(&INCREASE_LIQUIDITY_V2_DISCRIMINATOR);
+    data.extend_from_slice(&params.try_to_vec()?);
+    data.push(0u8); // remaining_accounts_info: None
+
+    modify_liquidity_cpi(ctx.accounts, data)?;
+
+    msg!(
+        "OrcaLiquidityIncreased: Collection={} Authority={} Liquidity={} MaxA={} MaxB={}",
+        ctx.accounts.collection.key(),
+        ctx.accounts.authority.key(),
+        liquidity_amount,
+        token_max_a,
+        token_max_b
+    );
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct ClaimVestedLiquidity<'info> {
+    /// Position and reserve accounts; liquidity.authority must be the collection owner
+    pub liquidity: ManageOrcaLiquidity<'info>,
+
+    /// Owner's CAPGM account (receives the vested CAPGM)
+    #[account(
+        mut,
+        constraint = owner_capgm_account.owner == liquidity.collection.owner @ ProtocolError::Unauthorized,
+        constraint = owner_capgm_account.mint == liquidity.token_mint_b.key() @ ProtocolError::Unauthorized
+    )]
+    pub owner_capgm_account: InterfaceAccount<'info, TokenAccount>,
+}
+
+/// Withdraw launch liquidity once the LP lock has elapsed and pay its CAPGM side to the
+/// creator, up to the vested part of creator_capgm_deposit. Any CAPGM beyond that, and the
+/// collection tokens withdrawn alongside, stay in the reserves for re-deposit. The payout is
+/// a withdrawal (PAUSE_UNSTAKING) on top of the liquidity pause, and is bounded by
+/// check_liquidity_floor.
+pub fn claim_vested_liquidity(
+    ctx: Context<ClaimVestedLiquidity>,
+    liquidity_amount: u128,
+    token_min_a: u64,
+    token_min_b: u64,
+) -> Result<()> {
+    require!(liquidity_amount > 0, ProtocolError::InvalidFeeConfig);
+
+    let liquidity = &ctx.accounts.liquidity;
+    require!(
+        !liquidity.global_state.is_paused(PAUSE_UNSTAKING),
+        ProtocolError::ProtocolPaused
+    );
+    let collection = &liquidity.collection;
+
+    let vested = vested_creator_capgm(collection, liquidity.clock.unix_timestamp)?;
+    let claimable = vested.saturating_sub(collection.creator_capgm_withdrawn);
+    require!(claimable > 0, ProtocolError::LiquidityLocked);
+
+    let params = DecreaseLiquidityV2Params {
+        liquidity_amount,
+        token_min_a,
+        token_min_b,
+    };
+    let mut data = Vec::with_capacity(8 + 32 + 1);
+    data.extend_from_slice(&DECREASE_LIQUIDITY_V2_DISCRIMINATOR);
+    data.extend_from_slice(&params.try_to_vec()?);
+    data.push(0u8); // remaining_accounts_info: None
+
+    let reserve_b_before = liquidity.collection_reserve_b.amount;
+    modify_liquidity_cpi(liquidity, data)?;
+
+    let liquidity = &mut ctx.accounts.liquidity;
+    liquidity.collection_reserve_b.reload()?;
+    let capgm_out = liquidity.collection_reserve_b.amount
+        .checked_sub(reserve_b_before)
+        .ok_or(ProtocolError::MathOverflow)?;
+    let payout = capgm_out.min(claimable);
+
+    if payout > 0 {
+        let collection = &liquidity.collection;
+        let seeds = [
+            b"collection".as_ref(),
+            collection.owner.as_ref(),
+            collection.collection_id.as_bytes(),
+            &[collection.bump],
+        ];
+        let signer_seeds = &[&seeds[..]];
+        let transfer_accounts = TransferChecked {
+            from: liquidity.collection_reserve_b.to_account_info(),
+            mint: liquidity.token_mint_b.to_account_info(),
+            to: ctx.accounts.owner_capgm_account.to_account_info(),
+            authority: collection.to_account_info(),
+        };
+        let cpi_ctx = CpiContext::new_with_signer(
+            liquidity.token_program.to_account_info(),
+            transfer_accounts,
+            signer_seeds,
+        );
+        anchor_spl::token_interface::transfer_checked(cpi_ctx, payout, liquidity.token_mint_b.decimals)?;
+    }
+
+    let collection = &mut liquidity.collection;
+    collection.creator_capgm_withdrawn = collection.creator_capgm_withdrawn
+        .checked_add(payout)
+        .ok_or(ProtocolError::MathOverflow)?;
+    check_liquidity_floor(collection)?;
+
+    msg!(
+        "VestedLiquidityClaimed: Collection={} Liquidity={} CapgmOut={} Paid={} TotalWithdrawn={}",
+        collection.key(),
+        liquidity_amount,
+        capgm_out,
+        payout,
+        collection.creator_capgm_withdrawn
+    );
+    Ok(())
+}
+
+/// CAPGM from the creator's launch deposit vested at `now`: nothing during the lock period,
+/// then the share above LP_PERMANENT_LOCK_BPS unlocks linearly over LP_VESTING_DURATION_SECONDS
+fn vested_creator_capgm(collection: &CollectionState, now: i64) -> Result<u64> {
+    let vesting_starts_at = collection.liquidity_locked_at
+        .checked_add(LP_LOCK_PERIOD_SECONDS)
+        .ok_or(ProtocolError::MathOverflow)?;
+    let elapsed = now.saturating_sub(vesting_starts_at).clamp(0, LP_VESTING_DURATION_SECONDS);
+    let vested = (unlocked_share(collection.creator_capgm_deposit)? as u128)
+        .checked_mul(elapsed as u128)
+        .ok_or(ProtocolError::MathOverflow)?
+        / LP_VESTING_DURATION_SECONDS as u128;
+    u64::try_from(vested).map_err(|_| ProtocolError::MathOverflow.into())
+}
+
+/// increase_liquidity_v2 and decrease_liquidity_v2 share one account layout;
+/// the collection PDA signs as position authority
+fn modify_liquidity_cpi(accounts: &ManageOrcaLiquidity, data: Vec<u8>) -> Result<()> {
+    let collection = &accounts.collection;
+    let seeds = [
+        b"collection".as_ref(),
+        collection.owner.as_ref(),
+        collection.collection_id.as_bytes(),
+        &[collection.bump],
+    ];
+    let signer_seeds = &[&seeds[..]];
+
+    let instruction = Instruction {
+        program_id: ORCA_WHIRLPOOL_PROGRAM_ID,
+        accounts: vec![
+            AccountMeta::new(accounts.whirlpool.key(), false),
+            AccountMeta::new_readonly(accounts.token_program.key(), false),
+            AccountMeta::new_readonly(accounts.token_program.key(), false),
+            AccountMeta::new_readonly(accounts.memo_program.key(), false),
+            AccountMeta::new_readonly(collection.key(), true), // position_authority
+            AccountMeta::new(accounts.position.key(), false),
+            AccountMeta::new_readonly(accounts.position_token_account.key(), false),
+            AccountMeta::new_readonly(accounts.token_mint_a.key(), false),
+            AccountMeta::new_readonly(accounts.token_mint_b.key(), false),
+            AccountMeta::new(accounts.collection_reserve_a.key(), false),
+            AccountMeta::new(accounts.collection_reserve_b.key(), false),
+            AccountMeta::new(accounts.token_vault_a.key(), false),
+            AccountMeta::new(accounts.token_vault_b.key(), false),
+            AccountMeta::new(accounts.tick_array_lower.key(), false),
+            AccountMeta::new(accounts.tick_array_upper.key(), false),
+        ],
+        data,
+    };
+
+    invoke_signed(
+        &instruction,
+        &[
+            accounts.whirlpool.to_account_info(),
+            accounts.token_program.to_account_info(),
+            accounts.memo_program.to_account_info(),
+            collection.to_account_info(),
+            accounts.position.to_account_info(),
+            accounts.position_token_account.to_account_info(),
+            accounts.token_mint_a.to_account_info(),
+            accounts.token_mint_b.to_account_info(),
+            accounts.collection_reserve_a.to_account_info(),
+            accounts.collection_reserve_b.to_account_info(),
+            accounts.token_vault_a.to_account_info(),
+            accounts.token_vault_b.to_account_info(),
+            accounts.tick_array_lower.to_account_info(),
+            accounts.tick_array_upper.to_account_info(),
+        ],
+        signer_seeds,
+    )?;
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct CloseOrcaPosition<'info> {
+    /// Collection owner; receives the position's rent
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [SEED_GLOBAL_STATE],
+        bump = global_state.bump,
+        constraint = !global_state.is_paused(PAUSE_LIQUIDITY) @ ProtocolError::ProtocolPaused
+    )]
+    pub global_state: Account<'info, GlobalState>,
+
+    #[account(
+        mut,
+        seeds = [b"collection", collection.owner.as_ref(), collection.collection_id.as_bytes()],
+        bump = collection.bump,
+        constraint = collection.owner == authority.key() @ ProtocolError::Unauthorized,
+        constraint = collection.launch_stage == LaunchStage::Launched @ ProtocolError::InvalidLaunchStage
+    )]
+    pub collection: Account<'info, CollectionState>,
+
+    /// CHECK: Validated by Orca program against position_token_account
+    #[account(mut, owner = ORCA_WHIRLPOOL_PROGRAM_ID)]
+    pub position: UncheckedAccount<'info>,
+
+    /// CHECK: Must be the collection's live position
+    #[account(
+        mut,
+        constraint = position_mint.key() == collection.position_mint @ ProtocolError::InvalidAccount
+    )]
+    pub position_mint: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        constraint = position_token_account.owner == collection.key() @ ProtocolError::Unauthorized,
+        constraint = position_token_account.mint == position_mint.key() @ ProtocolError::Unauthorized
+    )]
+    pub position_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(
+        constraint = token_mint_a.key() == collection.mint @ ProtocolError::Unauthorized
+    )]
+    pub token_mint_a: InterfaceAccount<'info, Mint>,
+
+    #[account(
+        constraint = token_mint_b.key() == global_state.capgm_mint @ ProtocolError::InvalidAccount
+    )]
+    pub token_mint_b: InterfaceAccount<'info, Mint>,
+
+    #[account(
+        associated_token::mint = token_mint_a,
+        associated_token::authority = collection,
+        associated_token::token_program = token_program,
+    )]
+    pub collection_reserve_a: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(
+        associated_token::mint = token_mint_b,
+        associated_token::authority = collection,
+        associated_token::token_program = token_program,
+    )]
+    pub collection_reserve_b: InterfaceAccount<'info, TokenAccount>,
+
+    /// CHECK: Orca Whirlpool program (validated by address constraint)
+    #[account(address = ORCA_WHIRLPOOL_PROGRAM_ID)]
+    pub whirlpool_program: UncheckedAccount<'info>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+    pub clock: Sysvar<'info, Clock>,
+}
+
+/// Close an emptied position so liquidity can be re-opened at new ticks.
+/// All liquidity must be withdrawn and fees collected (collect_orca_fees) first; the LP lock
+/// applies as for decrease_orca_liquidity.
+pub fn close_orca_position(ctx: Context<CloseOrcaPosition>) -> Result<()> {
+    require!(
+        position_liquidity(&ctx.accounts.position)? == 0,
+        ProtocolError::InvalidAccount
+    );
+    check_lp_lock(&ctx.accounts.collection, ctx.accounts.clock.unix_timestamp)?;
+
+    let collection = &ctx.accounts.collection;
+    let seeds = [
+        b"collection".as_ref(),
+        collection.owner.as_ref(),
+        collection.collection_id.as_bytes(),
+        &[collection.bump],
+    ];
+    let signer_seeds = &[&seeds[..]];
+
+    let instruction = Instruction {
+        program_id: ORCA_WHIRLPOOL_PROGRAM_ID,
+        accounts: vec![
+            AccountMeta::new_readonly(collection.key(), true), // position_authority
+            AccountMeta::new(ctx.accounts.authority.key(), false), // receiver
+            AccountMeta::new(ctx.accounts.position.key(), false),
+            AccountMeta::new(ctx.accounts.position_mint.key(), false),
+            AccountMeta::new(ctx.accounts.position_token_account.key(), false),
+            AccountMeta::new_readonly(ctx.accounts.token_program.key(), false),
+        ],
+        data: CLOSE_POSITION_DISCRIMINATOR.to_vec(),
+    };
+
+    invoke_signed(
+        &instruction,
+        &[
+            collection.to_account_info(),
+            ctx.accounts.authority.to_account_info(),
+            ctx.accounts.position.to_account_info(),
+            ctx.accounts.position_mint.to_account_info(),
+            ctx.accounts.position_token_account.to_account_info(),
+            ctx.accounts.token_program.to_account_info(),
+        ],
+        signer_seeds,
+    )?;
+
+    let collection = &mut ctx.accounts.collection;
+    collection.position_mint = Pubkey::default();
+
+    msg!(
+        "OrcaPositionClosed: Collection={} Position={} Authority={}",
+        collection.key(),
+        ctx.accounts.position.key(),
+        ctx.accounts.authority.key()
+    );
+    Ok(())
+}
+
+/// Read the current liquidity of a Whirlpool position
+fn position_liquidity(position: &AccountInfo) -> Result<u128> {
+    read_u128(position, POSITION_LIQUIDITY_OFFSET)
+}
+
+/// Read the current sqrt price (Q64.64) of a Whirlpool
+pub(crate) fn whirlpool_sqrt_price(whirlpool: &AccountInfo) -> Result<u128> {
+    read_u128(whirlpool, WHIRLPOOL_SQRT_PRICE_OFFSET)
+}
+
+pub(crate) fn read_pubkey(account: &AccountInfo, offset: usize) -> Result<Pubkey> {
+    let data = account.try_borrow_data()?;
+    let bytes: [u8; 32] = data
+        .get(offset..offset + 32)
+        .ok_or(ProtocolError::InvalidAccount)?
+        .try_into()
+        .map_err(|_| ProtocolError::InvalidAccount)?;
+    Ok(Pubkey::from(bytes))
+}
+
+fn read_u128(account: &AccountInfo, offset: usize) -> Result<u128> {
+    let data = account.try_borrow_data()?;
+    let bytes = data
+        .get(offset..offset + 16)
+        .ok_or(ProtocolError::InvalidAccount)?;
+    Ok(u128::from_le_bytes(bytes.try_into().map_err(|_| ProtocolError::InvalidAccount)?))
+}
+
+/// 1.0 in Q64.64 fixed point (Whirlpool sqrt prices are Q64.64)
+const Q64: u128 = 1 << 64;
+
+/// Collection token base units one access (`access_threshold_usd` cents) costs, given the
+/// collection pool's sqrt price (collection token A / CAPGM B) and the stablecoin/CAPGM pool's.
+/// The stablecoin is taken to be worth one USD. Each price is applied as two divisions (or
+/// multiplications) by its sqrt price, so no precision is lost squaring it; results round down.
+pub(crate) fn access_cost_in_tokens(
+    access_threshold_usd: u64,
+    stablecoin_decimals: u8,
+    token_sqrt_price_x64: u128,
+    stablecoin_sqrt_price_x64: u128,
+    stablecoin_is_a: bool,
+) -> Result<u128> {
+    let stablecoin_amount = (access_threshold_usd as u128)
+        .checked_mul(10u128.checked_pow(stablecoin_decimals as u32).ok_or(ProtocolError::MathOverflow)?)
+        .ok_or(ProtocolError::MathOverflow)?
+        / 100; // cents -> stablecoin base units
+    let capgm_amount = if stablecoin_is_a {
+        // sqrt_price^2 is CAPGM per stablecoin
+        let half = mul_div(stablecoin_amount, stablecoin_sqrt_price_x64, Q64)?;
+        mul_div(half, stablecoin_sqrt_price_x64, Q64)?
+    } else {
+        // sqrt_price^2 is stablecoin per CAPGM
+        let half = mul_div(stablecoin_amount, Q64, stablecoin_sqrt_price_x64)?;
+        mul_div(half, Q64, stablecoin_sqrt_price_x64)?
+    };
+    // sqrt_price^2 is CAPGM per collection token
+    let half = mul_div(capgm_amount, Q64, token_sqrt_price_x64)?;
+    mul_div(half, Q64, token_sqrt_price_x64)
+}
+
+/// floor(a * b / denominator) with a 256-bit intermediate product; fails on a zero
+/// denominator or a result that does not fit in u128
+pub(crate) fn mul_div(a: u128, b: u128, denominator: u128) -> Result<u128> {
+    require!(denominator > 0, ProtocolError::MathOverflow);
+
+    // Schoolbook product of the 64-bit halves
+    const MASK: u128 = u64::MAX as u128;
+    let (a_hi, a_lo) = (a >> 64, a & MASK);
+    let (b_hi, b_lo) = (b >> 64, b & MASK);
+    let lo_lo = a_lo * b_lo;
+    let hi_lo = a_hi * b_lo;
+    let lo_hi = a_lo * b_hi;
+    let mid = (lo_lo >> 64) + (hi_lo & MASK) + (lo_hi & MASK);
+    let product_lo = (lo_lo & MASK) | (mid << 64);
+    let product_hi = a_hi * b_hi + (hi_lo >> 64) + (lo_hi >> 64) + (mid >> 64);
+
+    // The quotient fits in u128 only if the high half is below the denominator
+    require!(product_hi < denominator, ProtocolError::MathOverflow);
+    if product_hi == 0 {
+        return Ok(product_lo / denominator);
+    }
+
+    // Restoring long division of the 256-bit product, one bit at a time
+    let mut remainder = product_hi;
+    let mut quotient = 0u128;
+    for bit in (0..128).rev() {
+        let overflow = remainder >> 127 == 1;
+        remainder = (remainder << 1) | ((product_lo >> bit) & 1);
+        quotient <<= 1;
+        if overflow || remainder >= denominator {
+            remainder = remainder.wrapping_sub(denominator);
+            quotient |= 1;
+        }
+    }
+    Ok(quotient)
+}
+
+/// Accounts for one Whirlpool swap_v2 hop
+pub(crate) struct WhirlpoolSwapAccounts<'info> {
+    pub token_program: AccountInfo<'info>,
+    pub memo_program: AccountInfo<'info>,
+    pub token_authority: AccountInfo<'info>,
+    pub whirlpool: AccountInfo<'info>,
+    pub token_mint_a: AccountInfo<'info>,
+    pub token_mint_b: AccountInfo<'info>,
+    pub token_owner_account_a: AccountInfo<'info>,
+    pub token_vault_a: AccountInfo<'info>,
+    pub token_owner_account_b: AccountInfo<'info>,
+    pub token_vault_b: AccountInfo<'info>,
+    pub tick_array_0: AccountInfo<'info>,
+    pub tick_array_1: AccountInfo<'info>,
+    pub tick_array_2: AccountInfo<'info>,
+    pub oracle: AccountInfo<'info>,
+}
+
+/// Exact-input swap_v2 of `amount_in`, failing inside Orca if less than
+/// `min_amount_out` comes back. token_authority must already be a signer of the
+/// outer transaction.
+pub(crate) fn whirlpool_swap_cpi(
+    accounts: &WhirlpoolSwapAccounts,
+    amount_in: u64,
+    min_amount_out: u64,
+    a_to_b: bool,
+) -> Result<()> {
+    let data = swap_v2_data(amount_in, min_amount_out, a_to_b)?;
+
+    let instruction = Instruction {
+        program_id: ORCA_WHIRLPOOL_PROGRAM_ID,
+        accounts: vec![
+            AccountMeta::new_readonly(accounts.token_program.key(), false),
+            AccountMeta::new_readonly(accounts.token_program.key(), false),
+            AccountMeta::new_readonly(accounts.memo_program.key(), false),
+            AccountMeta::new_readonly(accounts.token_authority.key(), true),
+            AccountMeta::new(accounts.whirlpool.key(), false),
+            AccountMeta::new_readonly(accounts.token_mint_a.key(), false),
+            AccountMeta::new_readonly(accounts.token_mint_b.key(), false),
+            AccountMeta::new(accounts.token_owner_account_a.key(), false),
+            AccountMeta::new(accounts.token_vault_a.key(), false),
+            AccountMeta::new(accounts.token_owner_account_b.key(), false),
+            AccountMeta::new(accounts.token_vault_b.key(), false),
+            AccountMeta::new(accounts.tick_array_0.key(), false),
+            AccountMeta::new(accounts.tick_array_1.key(), false),
+            AccountMeta::new(accounts.tick_array_2.key(), false),
+            AccountMeta::new(accounts.oracle.key(), false),
+        ],
+        data,
+    };
+
+    invoke(
+        &instruction,
+        &[
+            accounts.token_program.clone(),
+            accounts.memo_program.clone(),
+            accounts.token_authority.clone(),
+            accounts.whirlpool.clone(),
+            accounts.token_mint_a.clone(),
+            accounts.token_mint_b.clone(),
+            accounts.token_owner_account_a.clone(),
+            accounts.token_vault_a.clone(),
+            accounts.token_owner_account_b.clone(),
+            accounts.token_vault_b.clone(),
+            accounts.tick_array_0.clone(),
+            accounts.tick_array_1.clone(),
+            accounts.tick_array_2.clone(),
+            accounts.oracle.clone(),
+        ],
+    )?;
+    Ok(())
+}
+
+/// swap_v2 instruction data for an exact-input swap with no price limit
+fn swap_v2_data(amount_in: u64, min_amount_out: u64, a_to_b: bool) -> Result<Vec<u8>> {
+    let params = SwapV2Params {
+        amount: amount_in,
+        other_amount_threshold: min_amount_out,
+        sqrt_price_limit: if a_to_b { MIN_SQRT_PRICE_X64 } else { MAX_SQRT_PRICE_X64 },
+        amount_specified_is_input: true,
+        a_to_b,
+    };
+    let mut data = Vec::with_capacity(8 + 34 + 1);
+    data.extend_from_slice(&SWAP_V2_DISCRIMINATOR);
+    data.extend_from_slice(&params.try_to_vec()?);
+    data.push(0u8); // remaining_accounts_info: None
+    Ok(data)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn lp_fee_staker_share_splits_by_bps() {
+        assert_eq!(lp_fee_staker_share(1_000, 5000, 1).unwrap(), 500);
+        assert_eq!(lp_fee_staker_share(999, 2000, 1).unwrap(), 199);
+        assert_eq!(lp_fee_staker_share(u64::MAX, 8000, 1).unwrap(), 14_757_395_258_967_641_292);
+    }
+
+    #[test]
+    fn lp_fee_staker_share_is_zero_with_nothing_staked() {
+        assert_eq!(lp_fee_staker_share(1_000, 5000, 0).unwrap(), 0);
+    }
+
+    #[test]
+    fn swap_v2_data_encodes_an_exact_input_swap() {
+        let data = swap_v2_data(1_000, 990, false).unwrap();
+        assert_eq!(data.len(), 8 + 8 + 8 + 16 + 1 + 1 + 1);
+        assert_eq!(data[..8], SWAP_V2_DISCRIMINATOR);
+        assert_eq!(data[8..16], 1_000u64.to_le_bytes());
+        assert_eq!(data[16..24], 990u64.to_le_bytes());
+        // B -> A swaps push the price up, so the limit is the maximum
+        assert_eq!(data[24..40], MAX_SQRT_PRICE_X64.to_le_bytes());
+        assert_eq!(data[40..], [1, 0, 0]);
+
+        let data = swap_v2_data(1_000, 990, true).unwrap();
+        assert_eq!(data[24..40], MIN_SQRT_PRICE_X64.to_le_bytes());
+        assert_eq!(data[40..], [1, 1, 0]);
+    }
+
+    #[test]
+    fn mul_div_uses_a_wide_intermediate() {
+        assert_eq!(mul_div(6, 7, 4).unwrap(), 10);
+        assert_eq!(mul_div(u128::MAX, u128::MAX, u128::MAX).unwrap(), u128::MAX);
+        assert_eq!(mul_div(u128::MAX, Q64, Q64 * 2).unwrap(), u128::MAX / 2);
+        assert_eq!(mul_div(Q64 * 3 + 5, Q64 * 7, Q64).unwrap(), (Q64 * 3 + 5) * 7);
+        assert!(mul_div(u128::MAX, 2, 1).is_err());
+        assert!(mul_div(1, 1, 0).is_err());
+    }
+
+    #[test]
+    fn access_cost_in_tokens_follows_both_pools() {
+        // $5.00 with a 6-decimal stablecoin at 1:1 to CAPGM and CAPGM 1:1 to the collection token
+        assert_eq!(access_cost_in_tokens(500, 6, Q64, Q64, false).unwrap(), 5_000_000);
+        // One collection token costs 4 CAPGM (sqrt price 2.0), so a quarter as many are needed
+        assert_eq!(access_cost_in_tokens(500, 6, 2 * Q64, Q64, false).unwrap(), 1_250_000);
+        // Stablecoin sorted first: sqrt price 2.0 means 4 CAPGM per stablecoin unit
+        assert_eq!(access_cost_in_tokens(500, 6, Q64, 2 * Q64, true).unwrap(), 20_000_000);
+        // Stablecoin sorted second: sqrt price 2.0 means CAPGM is worth 4 stablecoin units
+        assert_eq!(access_cost_in_tokens(500, 6, Q64, 2 * Q64, false).unwrap(), 1_250_000);
+    }
+
+    #[test]
+    fn access_cost_in_tokens_rejects_zero_prices() {
+        assert!(access_cost_in_tokens(500, 6, 0, Q64, false).is_err());
+        assert!(access_cost_in_tokens(500, 6, Q64, 0, false).is_err());
+    }
+
+    #[test]
+    fn rebalance_cooldown_rejects_early_withdrawals() {
+        let last = 1_700_000_000;
+        assert_eq!(
+            check_rebalance_cooldown(last, last + LIQUIDITY_REBALANCE_COOLDOWN_SECONDS - 1).unwrap_err(),
+            ProtocolError::TimelockNotElapsed.into()
+        );
+        assert!(check_rebalance_cooldown(last, last + LIQUIDITY_REBALANCE_COOLDOWN_SECONDS).is_ok());
+        // A position that was never rebalanced may withdraw immediately
+        assert!(check_rebalance_cooldown(0, last).is_ok());
+    }
+
+    fn launched_collection() -> CollectionState {
+        CollectionState {
+            liquidity_locked_at: 1_700_000_000,
+            launch_token_deposit: 1_000_000,
+            creator_capgm_deposit: 2_000_000,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn lp_lock_rejects_withdrawals_before_the_period_ends() {
+        let collection = launched_collection();
+        let lock_ends_at = collection.liquidity_locked_at + LP_LOCK_PERIOD_SECONDS;
+        assert_eq!(
+            check_lp_lock(&collection, lock_ends_at - 1).unwrap_err(),
+            ProtocolError::LiquidityLocked.into()
+        );
+        assert!(check_lp_lock(&collection, lock_ends_at).is_ok());
+    }
+
+    #[test]
+    fn liquidity_floor_caps_payouts_at_the_unlocked_share() {
+        let mut collection = launched_collection();
+        assert!(check_liquidity_floor(&collection).is_ok());
+        collection.creator_capgm_withdrawn = 1_000_000;
+        assert!(check_liquidity_floor(&collection).is_ok());
+        collection.creator_capgm_withdrawn = 1_000_001;
+        assert_eq!(
+            check_liquidity_floor(&collection).unwrap_err(),
+            ProtocolError::LiquidityLocked.into()
+        );
+    }
+
+    #[test]
+    fn vested_creator_capgm_unlocks_linearly_after_the_lock() {
+        let collection = launched_collection();
+        let vesting_starts_at = collection.liquidity_locked_at + LP_LOCK_PERIOD_SECONDS;
+        assert_eq!(vested_creator_capgm(&collection, collection.liquidity_locked_at).unwrap(), 0);
+        assert_eq!(vested_creator_capgm(&collection, vesting_starts_at).unwrap(), 0);
+        assert_eq!(
+            vested_creator_capgm(&collection, vesting_starts_at + LP_VESTING_DURATION_SECONDS / 2).unwrap(),
+            500_000
+        );
+        // Only the share above LP_PERMANENT_LOCK_BPS ever vests
+        assert_eq!(
+            vested_creator_capgm(&collection, vesting_starts_at + 2 * LP_VESTING_DURATION_SECONDS).unwrap(),
+            1_000_000
+        );
+    }
+}
@@ -16,7 +16,7 @@ pub struct RegisterHost<'info> {
     #[account(
         init,
         payer = pinner,
-        space = 8 + 32 + 32 + 1, // 8 (discriminator) + 32 (collection) + 32 (pinner) + 1 (is_active)
+        space = PinnerState::MAX_SIZE,
         seeds = [b"host_bond", pinner.key().as_ref(), collection.key().as_ref()],
         bump
     )]
@@ -32,6 +32,7 @@ pub fn register_collection_host(ctx: Context<RegisterHost>) -> Result<()> {
     pinner_state.collection = collection.key();
     pinner_state.pinner = ctx.accounts.pinner.key();
     pinner_state.is_active = true;
+    pinner_state.version = PinnerState::VERSION;
 
     // Note: Pinners no longer receive rewards via a separate claiming mechanism.
     // Pinners are paid directly when purchasers release escrow funds to peers
@@ -0,0 +1,375 @@
+// solana-program/programs/solana-program/src/instructions/services.rs
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{TokenInterface, TransferChecked, Mint, TokenAccount};
+use anchor_spl::associated_token::AssociatedToken;
+use crate::state::*;
+use crate::errors::ProtocolError;
+use crate::constants::*;
+
+// ============================================================================
+// Service Registry
+// ============================================================================
+//
+// Operators list indexer and node registry endpoints by posting a CAPGM bond.
+// Clients read the ServiceRegistry PDA and pick among the listed endpoints, falling
+// back to GlobalState.indexer_api_url / node_registry_url as defaults. Misbehaving
+// endpoints are reported through a ServiceEndpointReport ticket; once the assigned
+// moderators uphold it the endpoint is delisted and its bond forfeited to the treasury.
+// Operators leave by unbonding, which keeps the bond slashable for a notice period.
+
+#[derive(Accounts)]
+pub struct RegisterServiceEndpoint<'info> {
+    #[account(mut)]
+    pub operator: Signer<'info>,
+
+    #[account(
+        seeds = [SEED_GLOBAL_STATE],
+        bump = global_state.bump,
+        constraint = !global_state.is_paused(PAUSE_STAKING) @ ProtocolError::ProtocolPaused
+    )]
+    pub global_state: Account<'info, GlobalState>,
+
+    #[account(
+        init_if_needed,
+        payer = operator,
+        space = ServiceRegistry::MAX_SIZE,
+        seeds = [SEED_SERVICE_REGISTRY],
+        bump
+    )]
+    pub service_registry: Account<'info, ServiceRegistry>,
+
+    #[account(
+        mut,
+        token::mint = capgm_mint,
+        token::authority = operator,
+    )]
+    pub operator_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    /// Bond vault (registry-owned ATA) holding all endpoint bonds
+    #[account(
+        init_if_needed,
+        payer = operator,
+        associated_token::mint = capgm_mint,
+        associated_token::authority = service_registry,
+        associated_token::token_program = token_program,
+    )]
+    pub bond_vault: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(
+        constraint = capgm_mint.key() == global_state.capgm_mint @ ProtocolError::InvalidAccount
+    )]
+    pub capgm_mint: InterfaceAccount<'info, Mint>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+    pub clock: Sysvar<'info, Clock>,
+}
+
+/// List an endpoint, or update the URL / top up the bond of an existing listing.
+/// A new listing must post at least MIN_SERVICE_ENDPOINT_BOND; bond_amount may be 0
+/// when only changing the URL.
+pub fn register_service_endpoint(
+    ctx: Context<RegisterServiceEndpoint>,
+    service_type: ServiceType,
+    url: String,
+    bond_amount: u64,
+) -> Result<()> {
+    require!(!url.is_empty() && url.len() <= MAX_URL_LEN, ProtocolError::StringTooLong);
+
+    let operator = ctx.accounts.operator.key();
+    let registry = &mut ctx.accounts.service_registry;
+    registry.version = ServiceRegistry::VERSION;
+    registry.bump = ctx.bumps.service_registry;
+
+    let total_bond = match registry.position(&operator, service_type) {
+        Some(index) => {
+            // Re-registering an unbonding endpoint lists it again
+            let endpoint = &mut registry.endpoints[index];
+            endpoint.url = url;
+            endpoint.unbonding_since = 0;
+            endpoint.bond = endpoint.bond
+                .checked_add(bond_amount)
+                .ok_or(ProtocolError::MathOverflow)?;
+            endpoint.bond
+        }
+        None => {
+            require!(bond_amount >= MIN_SERVICE_ENDPOINT_BOND, ProtocolError::InsufficientFunds);
+            require!(
+                registry.endpoints.len() < MAX_SERVICE_ENDPOINTS,
+                ProtocolError::ServiceRegistryFull
+            );
+            registry.endpoints.push(ServiceEndpoint {
+                operator,
+                service_type,
+                url,
+                bond: bond_amount,
+                registered_at: ctx.accounts.clock.unix_timestamp,
+                unbonding_since: 0,
+            });
+            bond_amount
+        }
+    };
+
+    if bond_amount > 0 {
+        let transfer_ix = TransferChecked {
+            from: ctx.accounts.operator_token_account.to_account_info(),
+            mint: ctx.accounts.capgm_mint.to_account_info(),
+            to: ctx.accounts.bond_vault.to_account_info(),
+            authority: ctx.accounts.operator.to_account_info(),
+        };
+        let cpi_ctx = CpiContext::new(ctx.accounts.token_program.to_account_info(), transfer_ix);
+        anchor_spl::token_interface::transfer_checked(cpi_ctx, bond_amount, ctx.accounts.capgm_mint.decimals)?;
+    }
+
+    msg!(
+        "ServiceEndpointRegistered: Operator={} Type={:?} Bond={}",
+        operator,
+        service_type,
+        total_bond
+    );
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct DeregisterServiceEndpoint<'info> {
+    pub operator: Signer<'info>,
+
+    #[account(
+        seeds = [SEED_GLOBAL_STATE],
+        bump = global_state.bump,
+        constraint = !global_state.is_paused(PAUSE_UNSTAKING) @ ProtocolError::ProtocolPaused
+    )]
+    pub global_state: Account<'info, GlobalState>,
+
+    #[account(
+        mut,
+        seeds = [SEED_SERVICE_REGISTRY],
+        bump = service_registry.bump
+    )]
+    pub service_registry: Account<'info, ServiceRegistry>,
+
+    pub clock: Sysvar<'info, Clock>,
+}
+
+/// Start unbonding your own listing. The endpoint stays in the registry (clients skip
+/// unbonding endpoints) and its bond stays slashable until withdraw_service_bond.
+pub fn deregister_service_endpoint(
+    ctx: Context<DeregisterServiceEndpoint>,
+    service_type: ServiceType,
+) -> Result<()> {
+    let operator = ctx.accounts.operator.key();
+    let registry = &mut ctx.accounts.service_registry;
+    let index = registry
+        .position(&operator, service_type)
+        .ok_or(ProtocolError::ServiceEndpointNotFound)?;
+    let endpoint = &mut registry.endpoints[index];
+    require!(endpoint.unbonding_since == 0, ProtocolError::InvalidAccount);
+
+    let now = ctx.accounts.clock.unix_timestamp;
+    endpoint.unbonding_since = now;
+
+    msg!(
+        "ServiceEndpointUnbonding: Operator={} Type={:?} Bond={} WithdrawableAt={}",
+        operator,
+        service_type,
+        endpoint.bond,
+        now + SERVICE_UNBONDING_PERIOD_SECONDS
+    );
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct WithdrawServiceBond<'info> {
+    pub operator: Signer<'info>,
+
+    #[account(
+        seeds = [SEED_GLOBAL_STATE],
+        bump = global_state.bump,
+        constraint = !global_state.is_paused(PAUSE_UNSTAKING) @ ProtocolError::ProtocolPaused
+    )]
+    pub global_state: Account<'info, GlobalState>,
+
+    #[account(
+        mut,
+        seeds = [SEED_SERVICE_REGISTRY],
+        bump = service_registry.bump
+    )]
+    pub service_registry: Account<'info, ServiceRegistry>,
+
+    #[account(
+        mut,
+        token::mint = capgm_mint,
+        token::authority = operator,
+    )]
+    pub operator_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        associated_token::mint = capgm_mint,
+        associated_token::authority = service_registry,
+        associated_token::token_program = token_program,
+    )]
+    pub bond_vault: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(
+        constraint = capgm_mint.key() == global_state.capgm_mint @ ProtocolError::InvalidAccount
+    )]
+    pub capgm_mint: InterfaceAccount<'info, Mint>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+    pub clock: Sysvar<'info, Clock>,
+}
+
+/// Remove an unbonded listing and withdraw its bond once SERVICE_UNBONDING_PERIOD_SECONDS
+/// have passed since deregister_service_endpoint
+pub fn withdraw_service_bond(
+    ctx: Context<WithdrawServiceBond>,
+    service_type: ServiceType,
+) -> Result<()> {
+    let operator = ctx.accounts.operator.key();
+    let registry = &mut ctx.accounts.service_registry;
+    let index = registry
+        .position(&operator, service_type)
+        .ok_or(ProtocolError::ServiceEndpointNotFound)?;
+    let unbonding_since = registry.endpoints[index].unbonding_since;
+    require!(unbonding_since != 0, ProtocolError::InvalidAccount);
+    require!(
+        ctx.accounts.clock.unix_timestamp >= unbonding_since + SERVICE_UNBONDING_PERIOD_SECONDS,
+        ProtocolError::TimelockNotElapsed
+    );
+    let bond = registry.endpoints.remove(index).bond;
+
+    transfer_bond(
+        &ctx.accounts.service_registry,
+        &ctx.accounts.bond_vault,
+        &ctx.accounts.operator_token_account,
+        &ctx.accounts.capgm_mint,
+        &ctx.accounts.token_program,
+        bond,
+    )?;
+
+    msg!(
+        "ServiceEndpointDeregistered: Operator={} Type={:?} BondReturned={}",
+        operator,
+        service_type,
+        bond
+    );
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(operator: Pubkey, service_type: ServiceType)]
+pub struct DelistServiceEndpoint<'info> {
+    pub executor: Signer<'info>,
+
+    #[account(
+        seeds = [SEED_GLOBAL_STATE],
+        bump = global_state.bump,
+        constraint = !global_state.is_paused(PAUSE_MODERATION) @ ProtocolError::ProtocolPaused
+    )]
+    pub global_state: Account<'info, GlobalState>,
+
+    /// Upheld ServiceEndpointReport naming this endpoint
+    #[account(
+        constraint = ticket.ticket_type == TicketType::ServiceEndpointReport @ ProtocolError::InvalidAccount,
+        constraint = ticket.reported_operator == operator @ ProtocolError::InvalidAccount,
+        constraint = ticket.reported_service == Some(service_type) @ ProtocolError::InvalidAccount,
+        constraint = ticket.resolved && ticket.verdict @ ProtocolError::TicketNotUpheld
+    )]
+    pub ticket: Account<'info, ModTicket>,
+
+    #[account(
+        mut,
+        seeds = [SEED_SERVICE_REGISTRY],
+        bump = service_registry.bump
+    )]
+    pub service_registry: Account<'info, ServiceRegistry>,
+
+    #[account(
+        mut,
+        associated_token::mint = capgm_mint,
+        associated_token::authority = service_registry,
+        associated_token::token_program = token_program,
+    )]
+    pub bond_vault: InterfaceAccount<'info, TokenAccount>,
+
+    /// Treasury token account (destination for the forfeited bond)
+    #[account(
+        mut,
+        constraint = treasury_token_account.owner == global_state.treasury @ ProtocolError::Unauthorized,
+        constraint = treasury_token_account.mint == capgm_mint.key() @ ProtocolError::Unauthorized
+    )]
+    pub treasury_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(
+        constraint = capgm_mint.key() == global_state.capgm_mint @ ProtocolError::InvalidAccount
+    )]
+    pub capgm_mint: InterfaceAccount<'info, Mint>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+/// Permissionless: delist an endpoint whose ServiceEndpointReport was upheld by the
+/// assigned moderators and forfeit the operator's bond to the treasury. Unbonding
+/// endpoints can still be delisted. The report must postdate the listing, so an old
+/// ticket cannot be reused against a later registration by the same operator.
+pub fn delist_service_endpoint(
+    ctx: Context<DelistServiceEndpoint>,
+    operator: Pubkey,
+    service_type: ServiceType,
+) -> Result<()> {
+    let registry = &mut ctx.accounts.service_registry;
+    let index = registry
+        .position(&operator, service_type)
+        .ok_or(ProtocolError::ServiceEndpointNotFound)?;
+    require!(
+        ctx.accounts.ticket.created_at >= registry.endpoints[index].registered_at,
+        ProtocolError::InvalidAccount
+    );
+    let bond = registry.endpoints.remove(index).bond;
+
+    transfer_bond(
+        &ctx.accounts.service_registry,
+        &ctx.accounts.bond_vault,
+        &ctx.accounts.treasury_token_account,
+        &ctx.accounts.capgm_mint,
+        &ctx.accounts.token_program,
+        bond,
+    )?;
+
+    msg!(
+        "ServiceEndpointDelisted: Operator={} Type={:?} Ticket={} BondForfeited={}",
+        operator,
+        service_type,
+        ctx.accounts.ticket.key(),
+        bond
+    );
+    Ok(())
+}
+
+/// Pay `amount` out of the bond vault, signed by the registry PDA
+fn transfer_bond<'info>(
+    registry: &Account<'info, ServiceRegistry>,
+    bond_vault: &InterfaceAccount<'info, TokenAccount>,
+    destination: &InterfaceAccount<'info, TokenAccount>,
+    capgm_mint: &InterfaceAccount<'info, Mint>,
+    token_program: &Interface<'info, TokenInterface>,
+    amount: u64,
+) -> Result<()> {
+    if amount == 0 {
+        return Ok(());
+    }
+
+    let registry_seeds = [SEED_SERVICE_REGISTRY, &[registry.bump]];
+    let signer_seeds = &[&registry_seeds[..]];
+
+    let transfer_ix = TransferChecked {
+        from: bond_vault.to_account_info(),
+        mint: capgm_mint.to_account_info(),
+        to: destination.to_account_info(),
+        authority: registry.to_account_info(),
+    };
+    let cpi_ctx = CpiContext::new_with_signer(token_program.to_account_info(), transfer_ix, signer_seeds);
+    anchor_spl::token_interface::transfer_checked(cpi_ctx, amount, capgm_mint.decimals)
+}
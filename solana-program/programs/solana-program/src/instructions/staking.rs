@@ -5,6 +5,7 @@ use anchor_spl::associated_token::AssociatedToken;
 use crate::state::*;
 use crate::errors::ProtocolError;
 use crate::constants::*;
+use crate::instructions::admin::authorize_admin;
 
 // ============================================================================
 // Moderator Staking (CAPGM Token)
@@ -17,7 +18,8 @@ pub struct StakeModerator<'info> {
 
     #[account(
         seeds = [SEED_GLOBAL_STATE],
-        bump = global_state.bump
+        bump = global_state.bump,
+        constraint = !global_state.is_paused(PAUSE_STAKING) @ ProtocolError::ProtocolPaused
     )]
     pub global_state: Account<'info, GlobalState>,
 
@@ -46,6 +48,15 @@ pub struct StakeModerator<'info> {
     )]
     pub moderator_stake: Account<'info, ModeratorStake>,
 
+    #[account(
+        init_if_needed,
+        payer = moderator,
+        space = ModeratorRegistry::MAX_SIZE,
+        seeds = [SEED_MODERATOR_REGISTRY],
+        bump
+    )]
+    pub moderator_registry: Account<'info, ModeratorRegistry>,
+
     pub token_program: Interface<'info, TokenInterface>,
     pub associated_token_program: Program<'info, AssociatedToken>,
     pub system_program: Program<'info, System>,
@@ -94,8 +105,23 @@ pub fn stake_moderator(
         .checked_add(stake_amount)
         .ok_or(ProtocolError::MathOverflow)?;
     moderator_stake.is_active = true;
+    moderator_stake.version = ModeratorStake::VERSION;
     moderator_stake.bump = ctx.bumps.moderator_stake;
 
+    // Register (or refresh) the moderator in the assignment pool
+    let moderator_registry = &mut ctx.accounts.moderator_registry;
+    moderator_registry.version = ModeratorRegistry::VERSION;
+    moderator_registry.bump = ctx.bumps.moderator_registry;
+    let evicted = moderator_registry.upsert(ModeratorEntry {
+        moderator: moderator_stake.moderator,
+        stake_amount: moderator_stake.stake_amount,
+        specializations: moderator_stake.specializations,
+        is_active: true,
+    })?;
+    if let Some(evicted) = evicted {
+        msg!("ModeratorEvicted: Moderator={} (lowest stake in a full registry)", evicted);
+    }
+
     msg!(
         "ModeratorStaked: Moderator={} Amount={} TotalStaked={}",
         ctx.accounts.moderator.key(),
@@ -108,16 +134,25 @@ pub fn stake_moderator(
 
 #[derive(Accounts)]
 pub struct SlashModerator<'info> {
+    /// Admin key, or the relayer of an AdminAuthorization when the admin is a PDA
+    #[account(mut)]
     pub super_moderator: Signer<'info>,
 
     #[account(
         mut,
         seeds = [SEED_GLOBAL_STATE],
-        bump = global_state.bump,
-        constraint = global_state.admin == super_moderator.key() @ ProtocolError::Unauthorized
+        bump = global_state.bump
     )]
     pub global_state: Account<'info, GlobalState>,
 
+    #[account(
+        mut,
+        close = super_moderator,
+        seeds = [SEED_ADMIN_AUTHORIZATION, admin_authorization.proposal.as_ref()],
+        bump = admin_authorization.bump
+    )]
+    pub admin_authorization: Option<Account<'info, AdminAuthorization>>,
+
     #[account(
         mut,
         seeds = [b"moderator_stake", moderator.key().as_ref()],
@@ -128,6 +163,13 @@ pub struct SlashModerator<'info> {
     /// CHECK: Moderator being slashed
     pub moderator: UncheckedAccount<'info>,
 
+    #[account(
+        mut,
+        seeds = [SEED_MODERATOR_REGISTRY],
+        bump = moderator_registry.bump
+    )]
+    pub moderator_registry: Account<'info, ModeratorRegistry>,
+
     /// Moderator staking vault (source of slashed tokens)
     #[account(
         mut,
@@ -136,9 +178,13 @@ pub struct SlashModerator<'info> {
     )]
     pub staking_vault: InterfaceAccount<'info, TokenAccount>,
 
-    /// CHECK: Treasury token account (destination for slashed tokens)
-    #[account(mut)]
-    pub treasury_token_account: UncheckedAccount<'info>,
+    /// Treasury token account (destination for slashed tokens)
+    #[account(
+        mut,
+        constraint = treasury_token_account.owner == global_state.treasury @ ProtocolError::Unauthorized,
+        constraint = treasury_token_account.mint == capgm_mint.key() @ ProtocolError::Unauthorized
+    )]
+    pub treasury_token_account: InterfaceAccount<'info, TokenAccount>,
 
     /// CAPGM token mint (for transfer_checked)
     pub capgm_mint: InterfaceAccount<'info, Mint>,
@@ -147,6 +193,13 @@ pub struct SlashModerator<'info> {
 }
 
 pub fn slash_moderator(ctx: Context<SlashModerator>) -> Result<()> {
+    authorize_admin(
+        &ctx.accounts.global_state,
+        &ctx.accounts.super_moderator.key(),
+        &ctx.accounts.admin_authorization,
+        &AdminAction::SlashModerator { moderator: ctx.accounts.moderator.key() },
+    )?;
+
     let moderator_stake = &mut ctx.accounts.moderator_stake;
     let global_state = &ctx.accounts.global_state;
     let capgm_mint = &ctx.accounts.capgm_mint;
@@ -196,6 +249,9 @@ pub fn slash_moderator(ctx: Context<SlashModerator>) -> Result<()> {
         .checked_add(1)
         .ok_or(ProtocolError::MathOverflow)?;
 
+    // Slashed moderators are no longer eligible for ticket assignment
+    ctx.accounts.moderator_registry.remove(&ctx.accounts.moderator.key());
+
     msg!(
         "ModeratorSlashed: Moderator={} Amount={}",
         ctx.accounts.moderator.key(),
@@ -205,6 +261,71 @@ pub fn slash_moderator(ctx: Context<SlashModerator>) -> Result<()> {
     Ok(())
 }
 
+#[derive(Accounts)]
+pub struct RegisterModeratorSpecializations<'info> {
+    pub moderator: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"moderator_stake", moderator.key().as_ref()],
+        bump = moderator_stake.bump,
+        constraint = moderator_stake.moderator == moderator.key() @ ProtocolError::Unauthorized
+    )]
+    pub moderator_stake: Account<'info, ModeratorStake>,
+
+    #[account(
+        mut,
+        seeds = [SEED_MODERATOR_REGISTRY],
+        bump = moderator_registry.bump
+    )]
+    pub moderator_registry: Account<'info, ModeratorRegistry>,
+}
+
+/// Register which ticket types a staked moderator is qualified to resolve.
+/// specializations: bitflags of SPECIALIZATION_* (e.g. copyright-only moderators set SPECIALIZATION_COPYRIGHT)
+/// jurisdictions: optional ISO 3166-1 alpha-2 country codes, used by clients to route tickets
+pub fn register_moderator_specializations(
+    ctx: Context<RegisterModeratorSpecializations>,
+    specializations: u8,
+    jurisdictions: Vec<[u8; 2]>,
+) -> Result<()> {
+    require!(
+        specializations != 0 && specializations & !SPECIALIZATION_ALL == 0,
+        ProtocolError::InvalidFeeConfig
+    );
+    require!(jurisdictions.len() <= MAX_JURISDICTIONS, ProtocolError::StringTooLong);
+    for code in &jurisdictions {
+        require!(
+            code.iter().all(|c| c.is_ascii_uppercase()),
+            ProtocolError::InvalidAccount
+        );
+    }
+
+    let moderator_stake = &mut ctx.accounts.moderator_stake;
+    moderator_stake.specializations = specializations;
+    moderator_stake.jurisdictions = jurisdictions;
+
+    if moderator_stake.is_active {
+        ctx.accounts.moderator_registry.upsert(ModeratorEntry {
+            moderator: moderator_stake.moderator,
+            stake_amount: moderator_stake.stake_amount,
+            specializations,
+            is_active: true,
+        })?;
+    } else {
+        ctx.accounts.moderator_registry.remove(&moderator_stake.moderator);
+    }
+
+    msg!(
+        "ModeratorRegistered: Moderator={} Specializations={:#05b} Jurisdictions={}",
+        ctx.accounts.moderator.key(),
+        specializations,
+        moderator_stake.jurisdictions.len()
+    );
+
+    Ok(())
+}
+
 // ============================================================================
 // Collection Token Staking (for earning rewards from access purchases)
 // ============================================================================
@@ -214,6 +335,13 @@ pub struct StakeCollectionTokens<'info> {
     #[account(mut)]
     pub staker: Signer<'info>,
 
+    #[account(
+        seeds = [SEED_GLOBAL_STATE],
+        bump = global_state.bump,
+        constraint = !global_state.is_paused(PAUSE_STAKING) @ ProtocolError::ProtocolPaused
+    )]
+    pub global_state: Account<'info, GlobalState>,
+
     #[account(
         seeds = [b"collection", collection.owner.as_ref(), collection.collection_id.as_bytes()],
         bump
@@ -273,6 +401,8 @@ pub fn stake_collection_tokens(
         staking_pool.collection = collection.key();
         staking_pool.total_staked = 0;
         staking_pool.reward_per_token = 0;
+        staking_pool.capgm_reward_per_token = 0;
+        staking_pool.version = CollectionStakingPool::VERSION;
         staking_pool.bump = ctx.bumps.staking_pool;
     }
 
@@ -282,6 +412,9 @@ pub fn stake_collection_tokens(
         staker_position.collection = collection.key();
         staker_position.amount_staked = 0;
         staker_position.reward_debt = 0;
+        staker_position.capgm_reward_debt = 0;
+        staker_position.capgm_rewards_owed = 0;
+        staker_position.version = StakerPosition::VERSION;
         staker_position.bump = ctx.bumps.staker_position;
     }
 
@@ -303,6 +436,8 @@ pub fn stake_collection_tokens(
         }
     }
 
+    settle_capgm_rewards(staking_pool, staker_position)?;
+
     // Transfer tokens from staker to pool (no fees on staking)
     let transfer_ix = TransferChecked {
         from: ctx.accounts.staker_token_account.to_account_info(),
@@ -326,6 +461,9 @@ pub fn stake_collection_tokens(
     staker_position.reward_debt = (staker_position.amount_staked as u128)
         .checked_mul(staking_pool.reward_per_token)
         .ok_or(ProtocolError::MathOverflow)?;
+    staker_position.capgm_reward_debt = (staker_position.amount_staked as u128)
+        .checked_mul(staking_pool.capgm_reward_per_token)
+        .ok_or(ProtocolError::MathOverflow)?;
 
     msg!(
         "CollectionTokensStaked: Staker={} Collection={} Amount={} TotalStaked={}",
@@ -343,6 +481,13 @@ pub struct ClaimStakingRewards<'info> {
     #[account(mut)]
     pub staker: Signer<'info>,
 
+    #[account(
+        seeds = [SEED_GLOBAL_STATE],
+        bump = global_state.bump,
+        constraint = !global_state.is_paused(PAUSE_UNSTAKING) @ ProtocolError::ProtocolPaused
+    )]
+    pub global_state: Account<'info, GlobalState>,
+
     #[account(
         seeds = [b"collection", collection.owner.as_ref(), collection.collection_id.as_bytes()],
         bump
@@ -450,6 +595,13 @@ pub struct UnstakeCollectionTokens<'info> {
     #[account(mut)]
     pub staker: Signer<'info>,
 
+    #[account(
+        seeds = [SEED_GLOBAL_STATE],
+        bump = global_state.bump,
+        constraint = !global_state.is_paused(PAUSE_UNSTAKING) @ ProtocolError::ProtocolPaused
+    )]
+    pub global_state: Account<'info, GlobalState>,
+
     #[account(
         seeds = [b"collection", collection.owner.as_ref(), collection.collection_id.as_bytes()],
         bump
@@ -507,6 +659,8 @@ pub fn unstake_collection_tokens(
         ProtocolError::InsufficientFunds
     );
 
+    settle_capgm_rewards(staking_pool, staker_position)?;
+
     // Claim any pending rewards first
     let pending = (staker_position.amount_staked as u128)
         .checked_mul(staking_pool.reward_per_token)
@@ -566,6 +720,130 @@ pub fn unstake_collection_tokens(
     staker_position.reward_debt = (staker_position.amount_staked as u128)
         .checked_mul(staking_pool.reward_per_token)
         .ok_or(ProtocolError::MathOverflow)?;
+    staker_position.capgm_reward_debt = (staker_position.amount_staked as u128)
+        .checked_mul(staking_pool.capgm_reward_per_token)
+        .ok_or(ProtocolError::MathOverflow)?;
+
+    Ok(())
+}
+
+/// Move CAPGM LP fee rewards accrued since the last checkpoint into capgm_rewards_owed.
+/// Callers reset capgm_reward_debt once amount_staked has its new value.
+fn settle_capgm_rewards(staking_pool: &CollectionStakingPool, staker_position: &mut StakerPosition) -> Result<()> {
+    let pending = (staker_position.amount_staked as u128)
+        .checked_mul(staking_pool.capgm_reward_per_token)
+        .ok_or(ProtocolError::MathOverflow)?
+        .checked_sub(staker_position.capgm_reward_debt)
+        .ok_or(ProtocolError::MathOverflow)?;
+    let pending_tokens = u64::try_from(pending / REWARD_PRECISION).map_err(|_| ProtocolError::MathOverflow)?;
+    staker_position.capgm_rewards_owed = staker_position.capgm_rewards_owed
+        .checked_add(pending_tokens)
+        .ok_or(ProtocolError::MathOverflow)?;
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct ClaimStakingCapgmRewards<'info> {
+    pub staker: Signer<'info>,
+
+    #[account(
+        seeds = [SEED_GLOBAL_STATE],
+        bump = global_state.bump,
+        constraint = !global_state.is_paused(PAUSE_UNSTAKING) @ ProtocolError::ProtocolPaused
+    )]
+    pub global_state: Account<'info, GlobalState>,
+
+    #[account(
+        mut,
+        seeds = [b"collection", collection.owner.as_ref(), collection.collection_id.as_bytes()],
+        bump = collection.bump
+    )]
+    pub collection: Account<'info, CollectionState>,
+
+    #[account(
+        seeds = [SEED_STAKING_POOL, collection.key().as_ref()],
+        bump = staking_pool.bump
+    )]
+    pub staking_pool: Account<'info, CollectionStakingPool>,
+
+    #[account(
+        mut,
+        seeds = [SEED_STAKER_POSITION, staker.key().as_ref(), collection.key().as_ref()],
+        bump = staker_position.bump,
+        constraint = staker_position.staker == staker.key() @ ProtocolError::Unauthorized
+    )]
+    pub staker_position: Account<'info, StakerPosition>,
+
+    #[account(
+        constraint = capgm_mint.key() == global_state.capgm_mint @ ProtocolError::InvalidAccount
+    )]
+    pub capgm_mint: InterfaceAccount<'info, Mint>,
+
+    /// Collection's CAPGM reserve (holds staker_capgm_reward_balance)
+    #[account(
+        mut,
+        associated_token::mint = capgm_mint,
+        associated_token::authority = collection,
+        associated_token::token_program = token_program,
+    )]
+    pub collection_reserve_b: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        constraint = staker_capgm_account.mint == capgm_mint.key() @ ProtocolError::InvalidAccount
+    )]
+    pub staker_capgm_account: InterfaceAccount<'info, TokenAccount>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+/// Claim the staker's share of CAPGM fees harvested by collect_orca_fees
+pub fn claim_staking_capgm_rewards(ctx: Context<ClaimStakingCapgmRewards>) -> Result<()> {
+    let staking_pool = &ctx.accounts.staking_pool;
+    let staker_position = &mut ctx.accounts.staker_position;
+
+    settle_capgm_rewards(staking_pool, staker_position)?;
+    staker_position.capgm_reward_debt = (staker_position.amount_staked as u128)
+        .checked_mul(staking_pool.capgm_reward_per_token)
+        .ok_or(ProtocolError::MathOverflow)?;
+
+    let amount = staker_position.capgm_rewards_owed;
+    require!(amount > 0, ProtocolError::InsufficientFunds);
+    staker_position.capgm_rewards_owed = 0;
+
+    let collection = &ctx.accounts.collection;
+    let seeds = [
+        b"collection".as_ref(),
+        collection.owner.as_ref(),
+        collection.collection_id.as_bytes(),
+        &[collection.bump],
+    ];
+    let signer_seeds = &[&seeds[..]];
+
+    let transfer_to_staker = TransferChecked {
+        from: ctx.accounts.collection_reserve_b.to_account_info(),
+        mint: ctx.accounts.capgm_mint.to_account_info(),
+        to: ctx.accounts.staker_capgm_account.to_account_info(),
+        authority: collection.to_account_info(),
+    };
+    let cpi_ctx = CpiContext::new_with_signer(
+        ctx.accounts.token_program.to_account_info(),
+        transfer_to_staker,
+        signer_seeds,
+    );
+    anchor_spl::token_interface::transfer_checked(cpi_ctx, amount, ctx.accounts.capgm_mint.decimals)?;
+
+    let collection = &mut ctx.accounts.collection;
+    collection.staker_capgm_reward_balance = collection.staker_capgm_reward_balance
+        .checked_sub(amount)
+        .ok_or(ProtocolError::MathOverflow)?;
+
+    msg!(
+        "CapgmRewardClaim: Staker={} Collection={} Amount={}",
+        ctx.accounts.staker.key(),
+        collection.collection_id,
+        amount
+    );
 
     Ok(())
 }
@@ -0,0 +1,345 @@
+// solana-program/programs/solana-program/src/instructions/treasury.rs
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{TokenInterface, TransferChecked, Mint, TokenAccount};
+use anchor_spl::associated_token::AssociatedToken;
+use crate::state::*;
+use crate::errors::ProtocolError;
+use crate::constants::*;
+use crate::instructions::admin::authorize_admin;
+
+// ============================================================================
+// Protocol Treasury
+// ============================================================================
+//
+// The treasury PDA owns one associated token account per mint. Once GlobalState.treasury
+// points at the PDA (via the timelocked update_global_state), purchase fees and slashed
+// moderator stakes accumulate in those accounts and can only leave through
+// disburse_from_treasury, against an admin-approved budget, with a TreasuryPayout record.
+// When the admin is the multisig or governance PDA, each of these instructions is called
+// with the AdminAuthorization issued by the executed proposal instead of an admin signature.
+
+#[derive(Accounts)]
+pub struct InitializeTreasury<'info> {
+    /// Admin key, or the relayer of an AdminAuthorization when the admin is a PDA
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    #[account(
+        seeds = [SEED_GLOBAL_STATE],
+        bump = global_state.bump
+    )]
+    pub global_state: Account<'info, GlobalState>,
+
+    #[account(
+        mut,
+        close = admin,
+        seeds = [SEED_ADMIN_AUTHORIZATION, admin_authorization.proposal.as_ref()],
+        bump = admin_authorization.bump
+    )]
+    pub admin_authorization: Option<Account<'info, AdminAuthorization>>,
+
+    #[account(
+        init,
+        payer = admin,
+        space = ProtocolTreasury::MAX_SIZE,
+        seeds = [SEED_TREASURY],
+        bump
+    )]
+    pub treasury: Account<'info, ProtocolTreasury>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn initialize_treasury(ctx: Context<InitializeTreasury>) -> Result<()> {
+    authorize_admin(
+        &ctx.accounts.global_state,
+        &ctx.accounts.admin.key(),
+        &ctx.accounts.admin_authorization,
+        &AdminAction::InitializeTreasury,
+    )?;
+
+    let treasury = &mut ctx.accounts.treasury;
+    treasury.payout_count = 0;
+    treasury.version = ProtocolTreasury::VERSION;
+    treasury.bump = ctx.bumps.treasury;
+
+    msg!("TreasuryInitialized: Treasury={} Admin={}", treasury.key(), ctx.accounts.global_state.admin);
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(name: String)]
+pub struct CreateTreasuryBudget<'info> {
+    /// Admin key, or the relayer of an AdminAuthorization when the admin is a PDA
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    #[account(
+        seeds = [SEED_GLOBAL_STATE],
+        bump = global_state.bump
+    )]
+    pub global_state: Account<'info, GlobalState>,
+
+    #[account(
+        mut,
+        close = admin,
+        seeds = [SEED_ADMIN_AUTHORIZATION, admin_authorization.proposal.as_ref()],
+        bump = admin_authorization.bump
+    )]
+    pub admin_authorization: Option<Account<'info, AdminAuthorization>>,
+
+    #[account(
+        init,
+        payer = admin,
+        space = TreasuryBudget::MAX_SIZE,
+        seeds = [SEED_TREASURY_BUDGET, mint.key().as_ref(), name.as_bytes()],
+        bump
+    )]
+    pub budget: Account<'info, TreasuryBudget>,
+
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    pub system_program: Program<'info, System>,
+    pub clock: Sysvar<'info, Clock>,
+}
+
+/// Approve a spending cap for one category of disbursements in a single mint
+pub fn create_treasury_budget(
+    ctx: Context<CreateTreasuryBudget>,
+    name: String,
+    category: DisbursementCategory,
+    allocated: u64,
+) -> Result<()> {
+    authorize_admin(
+        &ctx.accounts.global_state,
+        &ctx.accounts.admin.key(),
+        &ctx.accounts.admin_authorization,
+        &AdminAction::CreateTreasuryBudget {
+            mint: ctx.accounts.mint.key(),
+            name: name.clone(),
+            category,
+            allocated,
+        },
+    )?;
+    require!(name.len() <= MAX_NAME_LEN, ProtocolError::StringTooLong);
+    require!(allocated > 0, ProtocolError::InvalidFeeConfig);
+
+    let budget = &mut ctx.accounts.budget;
+    budget.mint = ctx.accounts.mint.key();
+    budget.name = name;
+    budget.category = category;
+    budget.allocated = allocated;
+    budget.spent = 0;
+    budget.approved_by = ctx.accounts.global_state.admin;
+    budget.created_at = ctx.accounts.clock.unix_timestamp;
+    budget.version = TreasuryBudget::VERSION;
+    budget.bump = ctx.bumps.budget;
+
+    msg!(
+        "TreasuryBudgetCreated: Budget={} Name={} Category={:?} Mint={} Allocated={}",
+        budget.key(),
+        budget.name,
+        category,
+        budget.mint,
+        allocated
+    );
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct UpdateTreasuryBudget<'info> {
+    /// Admin key, or the relayer of an AdminAuthorization when the admin is a PDA
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    #[account(
+        seeds = [SEED_GLOBAL_STATE],
+        bump = global_state.bump
+    )]
+    pub global_state: Account<'info, GlobalState>,
+
+    #[account(
+        mut,
+        close = admin,
+        seeds = [SEED_ADMIN_AUTHORIZATION, admin_authorization.proposal.as_ref()],
+        bump = admin_authorization.bump
+    )]
+    pub admin_authorization: Option<Account<'info, AdminAuthorization>>,
+
+    #[account(
+        mut,
+        seeds = [SEED_TREASURY_BUDGET, budget.mint.as_ref(), budget.name.as_bytes()],
+        bump = budget.bump
+    )]
+    pub budget: Account<'info, TreasuryBudget>,
+}
+
+/// Resize a budget. The cap can never drop below what has already been spent;
+/// setting allocated == spent closes the budget to further payouts.
+pub fn update_treasury_budget(ctx: Context<UpdateTreasuryBudget>, allocated: u64) -> Result<()> {
+    authorize_admin(
+        &ctx.accounts.global_state,
+        &ctx.accounts.admin.key(),
+        &ctx.accounts.admin_authorization,
+        &AdminAction::UpdateTreasuryBudget { budget: ctx.accounts.budget.key(), allocated },
+    )?;
+
+    let budget = &mut ctx.accounts.budget;
+    require!(allocated >= budget.spent, ProtocolError::InvalidFeeConfig);
+
+    let previous = budget.allocated;
+    budget.allocated = allocated;
+    budget.approved_by = ctx.accounts.global_state.admin;
+
+    msg!(
+        "TreasuryBudgetUpdated: Budget={} Allocated={} -> {} Spent={}",
+        budget.key(),
+        previous,
+        allocated,
+        budget.spent
+    );
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct DisburseFromTreasury<'info> {
+    /// Admin key, or the relayer of an AdminAuthorization when the admin is a PDA
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    #[account(
+        seeds = [SEED_GLOBAL_STATE],
+        bump = global_state.bump
+    )]
+    pub global_state: Account<'info, GlobalState>,
+
+    #[account(
+        mut,
+        close = admin,
+        seeds = [SEED_ADMIN_AUTHORIZATION, admin_authorization.proposal.as_ref()],
+        bump = admin_authorization.bump
+    )]
+    pub admin_authorization: Option<Account<'info, AdminAuthorization>>,
+
+    #[account(
+        mut,
+        seeds = [SEED_TREASURY],
+        bump = treasury.bump
+    )]
+    pub treasury: Account<'info, ProtocolTreasury>,
+
+    #[account(
+        mut,
+        seeds = [SEED_TREASURY_BUDGET, budget.mint.as_ref(), budget.name.as_bytes()],
+        bump = budget.bump,
+        constraint = budget.mint == mint.key() @ ProtocolError::InvalidAccount
+    )]
+    pub budget: Account<'info, TreasuryBudget>,
+
+    /// Treasury fee account for this mint (source of funds)
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = treasury,
+        associated_token::token_program = token_program,
+    )]
+    pub treasury_vault: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        constraint = recipient_token_account.mint == mint.key() @ ProtocolError::InvalidAccount
+    )]
+    pub recipient_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(
+        init,
+        payer = admin,
+        space = TreasuryPayout::MAX_SIZE,
+        seeds = [SEED_TREASURY_PAYOUT, &treasury.payout_count.to_le_bytes()],
+        bump
+    )]
+    pub payout: Account<'info, TreasuryPayout>,
+
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+    pub clock: Sysvar<'info, Clock>,
+}
+
+/// Pay out of the treasury against an approved budget.
+/// Every payout is recorded in its own TreasuryPayout account for transparency.
+pub fn disburse_from_treasury(
+    ctx: Context<DisburseFromTreasury>,
+    amount: u64,
+    memo: String,
+) -> Result<()> {
+    authorize_admin(
+        &ctx.accounts.global_state,
+        &ctx.accounts.admin.key(),
+        &ctx.accounts.admin_authorization,
+        &AdminAction::DisburseFromTreasury {
+            budget: ctx.accounts.budget.key(),
+            recipient_token_account: ctx.accounts.recipient_token_account.key(),
+            amount,
+            memo: memo.clone(),
+        },
+    )?;
+    require!(amount > 0, ProtocolError::InsufficientFunds);
+    require!(memo.len() <= MAX_REASON_LEN, ProtocolError::StringTooLong);
+
+    let budget = &mut ctx.accounts.budget;
+    let new_spent = budget.spent
+        .checked_add(amount)
+        .ok_or(ProtocolError::MathOverflow)?;
+    require!(new_spent <= budget.allocated, ProtocolError::BudgetExceeded);
+
+    // Transfer from the treasury fee account using the treasury PDA as authority
+    let treasury_seeds = [SEED_TREASURY, &[ctx.accounts.treasury.bump]];
+    let signer_seeds = &[&treasury_seeds[..]];
+
+    let transfer_ix = TransferChecked {
+        from: ctx.accounts.treasury_vault.to_account_info(),
+        mint: ctx.accounts.mint.to_account_info(),
+        to: ctx.accounts.recipient_token_account.to_account_info(),
+        authority: ctx.accounts.treasury.to_account_info(),
+    };
+    let cpi_ctx = CpiContext::new_with_signer(
+        ctx.accounts.token_program.to_account_info(),
+        transfer_ix,
+        signer_seeds,
+    );
+    anchor_spl::token_interface::transfer_checked(cpi_ctx, amount, ctx.accounts.mint.decimals)?;
+
+    budget.spent = new_spent;
+
+    let treasury = &mut ctx.accounts.treasury;
+    let payout = &mut ctx.accounts.payout;
+    payout.payout_id = treasury.payout_count;
+    payout.budget = budget.key();
+    payout.mint = ctx.accounts.mint.key();
+    payout.recipient = ctx.accounts.recipient_token_account.owner;
+    payout.amount = amount;
+    payout.category = budget.category;
+    payout.memo = memo;
+    payout.approved_by = ctx.accounts.global_state.admin;
+    payout.paid_at = ctx.accounts.clock.unix_timestamp;
+    payout.version = TreasuryPayout::VERSION;
+    payout.bump = ctx.bumps.payout;
+
+    treasury.payout_count = treasury.payout_count
+        .checked_add(1)
+        .ok_or(ProtocolError::MathOverflow)?;
+
+    msg!(
+        "TreasuryDisbursed: Payout={} Id={} Budget={} Recipient={} Amount={} Remaining={}",
+        payout.key(),
+        payout.payout_id,
+        payout.budget,
+        payout.recipient,
+        amount,
+        budget.allocated - budget.spent
+    );
+    Ok(())
+}
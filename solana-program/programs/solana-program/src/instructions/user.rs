@@ -1,14 +1,28 @@
 use anchor_lang::prelude::*;
-use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface, MintTo, Burn, burn};
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface, MintTo, Burn, burn, TransferChecked};
 use anchor_spl::associated_token::AssociatedToken;
 use crate::state::*;
 use crate::errors::ProtocolError;
 use crate::constants::*;
 use spl_token_2022::extension::ExtensionType;
 use spl_token_2022::instruction::initialize_mint;
+use anchor_lang::system_program::{transfer, Transfer};
+use anchor_spl::token_2022_extensions::{
+    token_metadata_initialize, token_metadata_update_field, TokenMetadataInitialize, TokenMetadataUpdateField,
+};
+use anchor_spl::token_2022_extensions::spl_pod::optional_keys::OptionalNonZeroPubkey;
+use anchor_spl::token_2022_extensions::spl_token_metadata_interface::state::{Field, TokenMetadata};
+
+#[event]
+pub struct CollectionUpdated {
+    pub collection: Pubkey,
+    pub update: CollectionUpdate,
+    pub manifest_version: u32,
+    pub timestamp: i64,
+}
 
 #[derive(Accounts)]
-#[instruction(collection_id: String, name: String, cid_hash: [u8; 32], access_threshold_usd: u64, total_videos: u16, performer_share_percent: Option<u8>)]
+#[instruction(collection_id: String, name: String, cid_hash: [u8; 32], access_threshold_usd: u64, total_videos: u16, token_config: CollectionTokenConfig)]
 pub struct CreateCollection<'info> {
     #[account(mut)]
     pub owner: Signer<'info>,
@@ -16,9 +30,8 @@ pub struct CreateCollection<'info> {
     #[account(
         init,
         payer = owner,
-        // Calculate space dynamically based on bitmap size
-        // Base size + (total_videos / 8 + 1) * 2 for claimed/censored bitmaps
-        space = CollectionState::BASE_SIZE + ((total_videos as usize + 7) / 8) * 2,
+        // Calculate space dynamically based on video count
+        space = CollectionState::space(total_videos),
         seeds = [b"collection", owner.key().as_ref(), collection_id.as_bytes()],
         bump
     )]
@@ -50,7 +63,8 @@ pub struct CreateCollection<'info> {
     /// Global state account containing the protocol treasury address
     #[account(
         seeds = [crate::constants::SEED_GLOBAL_STATE],
-        bump = global_state.bump
+        bump = global_state.bump,
+        constraint = !global_state.is_paused(PAUSE_MINTING) @ ProtocolError::ProtocolPaused
     )]
     pub global_state: Account<'info, GlobalState>,
 
@@ -69,9 +83,14 @@ pub fn create_collection(
     cid_hash: [u8; 32],
     access_threshold_usd: u64,
     total_videos: u16,
-    performer_share_percent: Option<u8>,
+    token_config: CollectionTokenConfig,
 ) -> Result<()> {
     require!(collection_id.len() <= MAX_ID_LEN, ProtocolError::StringTooLong);
+    // The token symbol is derived from these characters (see collection_symbol)
+    require!(
+        collection_id.chars().any(|c| c.is_ascii_alphanumeric()),
+        ProtocolError::InvalidCollectionId
+    );
     require!(name.len() <= MAX_NAME_LEN, ProtocolError::StringTooLong);
     require!(total_videos > 0, ProtocolError::InvalidFeeConfig);
     
@@ -81,10 +100,34 @@ pub fn create_collection(
         ProtocolError::Unauthorized
     );
 
-    // Validate config: Creator share is fixed at 10%, Claim Share + Creator Share must be <= 100%
-    // We reserve at least 1% for liquidity for technical sanity
-    let claim_share = performer_share_percent.unwrap_or(10);
-    require!(claim_share <= 89, ProtocolError::InvalidFeeConfig); // Ensures 10+89 <= 99, leaving >=1% for liquidity
+    // Whirlpools order their mints by key and the collection token must be token A
+    // (see InitializeOrcaPool); a mint that sorts after CAPGM could never be launched,
+    // so reject it here and let the creator pick another collection_id
+    require!(
+        ctx.accounts.mint.key() < ctx.accounts.global_state.capgm_mint,
+        ProtocolError::InvalidPoolConfig
+    );
+
+    // Validate config: Claim Share + the largest allowed Creator Share must be <= 99%,
+    // so at least 1% is always left for liquidity whatever creator share applies at mint time
+    let claim_share_bps = token_config.claim_share_bps.unwrap_or(DEFAULT_CLAIM_SHARE_BPS);
+    require!(
+        claim_share_bps as u32 + MAX_CREATOR_SHARE_BPS as u32 <= 9900,
+        ProtocolError::InvalidFeeConfig
+    );
+    let decimals = token_config.decimals.unwrap_or(DEFAULT_COLLECTION_DECIMALS);
+    require!(decimals <= MAX_COLLECTION_DECIMALS, ProtocolError::InvalidSupply);
+
+    // Creator allocation vests: cliff within a bounded linear unlock
+    let vesting_cliff = token_config.creator_vesting_cliff_seconds
+        .unwrap_or(DEFAULT_CREATOR_VESTING_CLIFF_SECONDS);
+    let vesting_duration = token_config.creator_vesting_duration_seconds
+        .unwrap_or(DEFAULT_CREATOR_VESTING_DURATION_SECONDS);
+    require!(
+        (MIN_CREATOR_VESTING_DURATION_SECONDS..=MAX_CREATOR_VESTING_DURATION_SECONDS).contains(&vesting_duration)
+            && (0..=vesting_duration).contains(&vesting_cliff),
+        ProtocolError::InvalidFeeConfig
+    );
 
     let clock = &ctx.accounts.clock;
     let collection = &mut ctx.accounts.collection;
@@ -102,10 +145,9 @@ pub fn create_collection(
     collection.total_trust_score = 0;
     collection.is_blacklisted = false;
     collection.name = name;
-    collection.content_cid = String::from(""); // Deprecated field, kept for backward compatibility
     collection.access_threshold_usd = access_threshold_usd;
     collection.oracle_feed = ctx.accounts.oracle_feed.key();
-    collection.claim_share_percent = claim_share;
+    collection.claim_share_bps = claim_share_bps;
     
     // Initialize reward trackers
     collection.owner_reward_balance = 0;
@@ -115,24 +157,69 @@ pub fn create_collection(
     // Initialize proportional copyright claim fields
     collection.total_videos = total_videos;
     collection.claim_vault_initial_amount = 0; // Will be set during minting
-    // Initialize bitmaps with 0s (size = ceil(total_videos / 8))
+    // Initialize claimed shares (one entry per video) and censored bitmap (size = ceil(total_videos / 8)) with 0s
     let bitmap_size = (total_videos as usize + 7) / 8;
-    collection.claimed_bitmap = vec![0; bitmap_size];
+    collection.claimed_share_bps = vec![0; total_videos as usize];
     collection.censored_bitmap = vec![0; bitmap_size];
+
+    // Initialize censorship refund trackers
+    collection.total_purchases = 0;
+    collection.creator_bond = 0;
+    collection.refund_reserved_bitmap = vec![0; bitmap_size];
+
+    // Fee schedule follows GlobalState until the owner sets overrides
+    collection.staker_split_override_bps = None;
+    collection.creator_share_override_bps = None;
+    collection.creator_royalty_bps = 0;
+    collection.manifest_version = 0;
+    collection.previous_cid_hashes = Vec::new();
+
+    // Orca LP fees are split between stakers and the owner
+    collection.lp_fee_staker_bps = DEFAULT_LP_FEE_STAKER_BPS;
+    collection.owner_capgm_reward_balance = 0;
+    collection.staker_capgm_reward_balance = 0;
+    collection.last_rebalance_at = 0;
+    collection.position_mint = Pubkey::default();
+    collection.launch_token_deposit = 0;
+    collection.creator_capgm_deposit = 0;
+    collection.creator_capgm_withdrawn = 0;
+    collection.liquidity_locked_at = 0;
+    collection.creator_vesting_cliff_seconds = vesting_cliff;
+    collection.creator_vesting_duration_seconds = vesting_duration;
+    collection.creator_vesting_start = 0; // Set during minting
+    collection.creator_vesting_total = 0;
+    collection.creator_vesting_claimed = 0;
+    collection.launch_stage = LaunchStage::Created;
     
+    collection.version = CollectionState::VERSION;
     collection.bump = ctx.bumps.collection;
 
-    // --- MANUAL MINT CREATION (NO TRANSFER FEE EXTENSION) ---
+    // --- MANUAL MINT CREATION (METADATA EXTENSIONS, NO TRANSFER FEE EXTENSION) ---
     // NOTE: Transfer fees are now manually collected only on purchases/sales,
     // not on staking or normal transfers. This allows fees to be selective.
 
-    // 1. Calculate space required for Mint (standard Token-2022, no extensions)
+    // Token metadata lives on the mint itself; the collection PDA is its update authority
+    let collection_key = ctx.accounts.collection.key();
+    let metadata = TokenMetadata {
+        update_authority: OptionalNonZeroPubkey(collection_key),
+        mint: ctx.accounts.mint.key(),
+        name: ctx.accounts.collection.name.clone(),
+        symbol: collection_symbol(&collection_id),
+        uri: collection_metadata_uri(&ctx.accounts.global_state.indexer_api_url, &collection_id),
+        additional_metadata: Vec::new(),
+    };
+
+    // 1. Calculate space required for Mint (the MetadataPointer is the only fixed-size extension)
     let space = ExtensionType::try_calculate_account_len::<spl_token_2022::state::Mint>(
-        &[], // No extensions
+        &[ExtensionType::MetadataPointer],
     ).map_err(|_| ProtocolError::MathOverflow)?;
 
-    // 2. Calculate Rent
-    let rent_lamports = ctx.accounts.rent.minimum_balance(space);
+    // 2. Calculate Rent. The account is created at `space` and Token-2022 reallocs it when
+    // the variable-length TokenMetadata is written, so fund rent for both up front.
+    let metadata_len = metadata.tlv_size_of().map_err(|_| ProtocolError::MathOverflow)?;
+    let rent_lamports = ctx.accounts.rent.minimum_balance(
+        space.checked_add(metadata_len).ok_or(ProtocolError::MathOverflow)?
+    );
     let space_u64 = u64::try_from(space).map_err(|_| ProtocolError::MathOverflow)?;
 
     // 3. Prepare Seeds for Signing (Mint is a PDA of Collection)
@@ -160,14 +247,25 @@ pub fn create_collection(
         signer,
     )?;
 
-    // 5. Initialize the Mint (Standard Token-2022, no transfer fee extension)
+    // 5. Point the mint's metadata at itself (must precede InitializeMint)
+    anchor_lang::solana_program::program::invoke(
+        &spl_token_2022::extension::metadata_pointer::instruction::initialize(
+            ctx.accounts.token_program.key,
+            ctx.accounts.mint.key,
+            Some(collection_key), // Pointer Authority
+            Some(ctx.accounts.mint.key()),
+        )?,
+        &[ctx.accounts.mint.to_account_info()],
+    )?;
+
+    // 6. Initialize the Mint (Token-2022, no transfer fee extension)
     anchor_lang::solana_program::program::invoke_signed(
         &initialize_mint(
             ctx.accounts.token_program.key,
             ctx.accounts.mint.key,
             &ctx.accounts.collection.key(), // Mint Authority
             Some(&ctx.accounts.collection.key()), // Freeze Authority
-            6, // Decimals
+            decimals,
         )?,
         &[
             ctx.accounts.mint.to_account_info(),
@@ -177,18 +275,63 @@ pub fn create_collection(
         signer,
     )?;
 
+    // 7. Write the TokenMetadata, signed by the collection as mint authority
+    let collection_bump = [ctx.bumps.collection];
+    let collection_seeds = [
+        b"collection".as_ref(),
+        owner_key.as_ref(),
+        collection_id.as_bytes(),
+        &collection_bump,
+    ];
+    token_metadata_initialize(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            TokenMetadataInitialize {
+                program_id: ctx.accounts.token_program.to_account_info(),
+                metadata: ctx.accounts.mint.to_account_info(),
+                update_authority: ctx.accounts.collection.to_account_info(),
+                mint_authority: ctx.accounts.collection.to_account_info(),
+                mint: ctx.accounts.mint.to_account_info(),
+            },
+            &[&collection_seeds[..]],
+        ),
+        metadata.name,
+        metadata.symbol.clone(),
+        metadata.uri,
+    )?;
+
     // --- MANUAL MINT CREATION END ---
 
     msg!(
-        "CollectionCreated: ID={} Owner={} Share={}%",
+        "CollectionCreated: ID={} Owner={} Symbol={} ClaimShare={}bps Decimals={}",
         collection_id,
         owner_key,
-        claim_share
+        metadata.symbol,
+        claim_share_bps,
+        decimals
     );
 
     Ok(())
 }
 
+/// Token symbol derived from the collection ID: its ASCII alphanumerics, uppercased and
+/// truncated to MAX_SYMBOL_LEN ("cooking-101" -> "COOKING101"). create_collection rejects
+/// IDs without any, so the symbol is never empty.
+pub(crate) fn collection_symbol(collection_id: &str) -> String {
+    collection_id
+        .chars()
+        .filter(char::is_ascii_alphanumeric)
+        .take(MAX_SYMBOL_LEN)
+        .collect::<String>()
+        .to_ascii_uppercase()
+}
+
+/// Metadata URI for a collection mint. The manifest CID itself stays private (only cid_hash is
+/// on-chain), so wallets are pointed at the indexer's public view of the manifest instead.
+pub(crate) fn collection_metadata_uri(indexer_api_url: &str, collection_id: &str) -> String {
+    format!("{}/collections/{}", indexer_api_url.trim_end_matches('/'), collection_id)
+}
+
 #[derive(Accounts)]
 #[instruction(ipns_key: String)]
 pub struct InitializeUserAccount<'info> {
@@ -220,6 +363,7 @@ pub fn initialize_user_account(
     user_account.authority = ctx.accounts.authority.key();
     user_account.ipns_key = ipns_key;
     user_account.is_online = false;
+    user_account.version = UserAccount::VERSION;
     user_account.bump = ctx.bumps.user_account;
 
     Ok(())
@@ -239,6 +383,13 @@ pub struct MintCollectionTokens<'info> {
     )]
     pub collection: Account<'info, CollectionState>,
 
+    #[account(
+        seeds = [SEED_GLOBAL_STATE],
+        bump = global_state.bump,
+        constraint = !global_state.is_paused(PAUSE_MINTING) @ ProtocolError::ProtocolPaused
+    )]
+    pub global_state: Account<'info, GlobalState>,
+
     /// The collection token mint (PDA derived from collection)
     #[account(
         mut,
@@ -247,13 +398,18 @@ pub struct MintCollectionTokens<'info> {
     )]
     pub mint: InterfaceAccount<'info, Mint>,
 
-    /// Creator's token account to receive 10% of minted tokens
+    /// Creator vesting vault (PDA token account owned by the Collection PDA) receiving the
+    /// creator share of minted tokens (default 10%); released by claim_creator_vesting
     #[account(
-        mut,
-        constraint = creator_token_account.owner == creator.key() @ ProtocolError::Unauthorized,
-        constraint = creator_token_account.mint == mint.key() @ ProtocolError::Unauthorized
+        init_if_needed,
+        payer = creator,
+        seeds = [SEED_CREATOR_VESTING, collection.key().as_ref()],
+        bump,
+        token::mint = mint,
+        token::authority = collection,
+        token::token_program = token_program,
     )]
-    pub creator_token_account: InterfaceAccount<'info, TokenAccount>,
+    pub creator_vesting_vault: InterfaceAccount<'info, TokenAccount>,
 
     /// CHECK: Claim vault token account (PDA) to receive configured share (default 10%)
     /// Validated by constraint against collection.claim_vault
@@ -288,10 +444,11 @@ pub struct MintCollectionTokens<'info> {
     pub token_program: Interface<'info, TokenInterface>,
     pub associated_token_program: Program<'info, AssociatedToken>,
     pub system_program: Program<'info, System>,
+    pub clock: Sysvar<'info, Clock>,
 }
 
 /// Mint collection tokens with automatic 3-way distribution:
-/// - 10% to Creator (Fixed)
+/// - Creator share to the creator vesting vault (GlobalState.creator_share_bps or collection override, default 10%)
 /// - X% to Claim Vault (Configurable, default 10%)
 /// - Remaining% to Liquidity Reserve (staging area for Orca pool)
 /// 
@@ -326,8 +483,9 @@ pub fn mint_collection_tokens(
     let collection_bump = ctx.accounts.collection.bump;
     let collection_mint = ctx.accounts.collection.mint;
     let tokens_minted = ctx.accounts.collection.tokens_minted;
-    let claim_share_percent = ctx.accounts.collection.claim_share_percent as u64;
+    let claim_vault_bps = ctx.accounts.collection.claim_share_bps as u64;
     let mint = &ctx.accounts.mint;
+    let supply_before = mint.supply;
 
     // ⚠️ SECURITY: Enforce one-time minting per collection
     // According to the design doc, collection tokens should only be minted once ever per collection
@@ -335,6 +493,10 @@ pub fn mint_collection_tokens(
         !tokens_minted,
         ProtocolError::Unauthorized // Tokens already minted for this collection
     );
+    require!(
+        ctx.accounts.collection.launch_stage == LaunchStage::Created,
+        ProtocolError::InvalidLaunchStage
+    );
 
     // Verify the mint matches the collection's mint
     require!(
@@ -342,38 +504,38 @@ pub fn mint_collection_tokens(
         ProtocolError::Unauthorized
     );
 
-    // Distribution logic:
-    // Creator: 10% (Fixed)
-    // Claim Vault: claim_share_percent (Configurable)
-    // Liquidity Reserve: 100% - 10% - claim_share_percent
+    // Supply must fall within the GlobalState bounds (whole tokens at the mint's decimals)
+    let global_state = &ctx.accounts.global_state;
+    let unit = 10u64
+        .checked_pow(mint.decimals as u32)
+        .ok_or(ProtocolError::InvalidSupply)?;
+    let min_amount = global_state.min_collection_supply
+        .checked_mul(unit)
+        .ok_or(ProtocolError::InvalidSupply)?;
+    let max_amount = global_state.max_collection_supply
+        .checked_mul(unit)
+        .ok_or(ProtocolError::InvalidSupply)?;
+    require!((min_amount..=max_amount).contains(&amount), ProtocolError::InvalidSupply);
+
+    // Distribution logic (basis points):
+    // Creator: creator_share_bps (collection override, else GlobalState; default 10%)
+    // Claim Vault: claim_share_bps (Configurable)
+    // Liquidity Reserve: remainder
     
-    let creator_percent = 10u64;
-    let reserve_percent = 100u64
-        .checked_sub(creator_percent)
+    let creator_bps = ctx.accounts.collection.creator_share_override_bps
+        .unwrap_or(global_state.creator_share_bps) as u64;
+    let reserve_bps = 10000u64
+        .checked_sub(creator_bps)
         .ok_or(ProtocolError::InvalidFeeConfig)?
-        .checked_sub(claim_share_percent)
+        .checked_sub(claim_vault_bps)
         .ok_or(ProtocolError::InvalidFeeConfig)?;
     
-    require!(reserve_percent > 0, ProtocolError::InvalidFeeConfig);
+    require!(reserve_bps > 0, ProtocolError::InvalidFeeConfig);
 
     // Calculate amounts
-    let reserve_amount = amount
-        .checked_mul(reserve_percent)
-        .ok_or(ProtocolError::MathOverflow)?
-        .checked_div(100)
-        .ok_or(ProtocolError::MathOverflow)?;
-
-    let creator_amount = amount
-        .checked_mul(creator_percent)
-        .ok_or(ProtocolError::MathOverflow)?
-        .checked_div(100)
-        .ok_or(ProtocolError::MathOverflow)?;
-
-    let claim_vault_amount = amount
-        .checked_mul(claim_share_percent)
-        .ok_or(ProtocolError::MathOverflow)?
-        .checked_div(100)
-        .ok_or(ProtocolError::MathOverflow)?;
+    let reserve_amount = bps_of(amount, reserve_bps)?;
+    let creator_amount = bps_of(amount, creator_bps)?;
+    let claim_vault_amount = bps_of(amount, claim_vault_bps)?;
 
     // Verify the split is correct (accounting for rounding)
     let total_distributed = reserve_amount
@@ -396,10 +558,10 @@ pub fn mint_collection_tokens(
     ];
     let signer = &[&seeds[..]];
 
-    // 1. Mint 10% to creator's token account
+    // 1. Mint creator share to the creator vesting vault
     let creator_cpi_accounts = MintTo {
         mint: ctx.accounts.mint.to_account_info(),
-        to: ctx.accounts.creator_token_account.to_account_info(),
+        to: ctx.accounts.creator_vesting_vault.to_account_info(),
         authority: collection_account_info.clone(),
     };
     let creator_cpi_ctx = CpiContext::new_with_signer(
@@ -438,25 +600,36 @@ pub fn mint_collection_tokens(
     );
     anchor_spl::token_interface::mint_to(reserve_cpi_ctx, final_reserve_amount)?;
 
+    // Invariant: the mint's on-chain supply grew by exactly the amount distributed
+    ctx.accounts.mint.reload()?;
+    require!(
+        Some(ctx.accounts.mint.supply) == supply_before.checked_add(amount),
+        ProtocolError::SupplyMismatch
+    );
+
     // Mark tokens as minted (one-time operation - cannot mint again)
     // SNAPSHOT THE INITIAL AMOUNT for proportional claim calculations
     let collection = &mut ctx.accounts.collection;
     collection.claim_vault_initial_amount = claim_vault_amount;
+    collection.creator_vesting_start = ctx.accounts.clock.unix_timestamp;
+    collection.creator_vesting_total = creator_amount;
     collection.tokens_minted = true;
+    collection.launch_stage = LaunchStage::TokensMinted;
 
     msg!(
         "CollectionTokensMinted: Collection={} Mint={} TotalAmount={}",
         collection_id,
-        mint.key(),
+        collection_mint,
         amount
     );
     msg!(
-        "Distribution: Creator={}(10%) ClaimVault={}({}%) LiquidityReserve={}({}%)",
+        "Distribution: CreatorVesting={}({}bps) ClaimVault={}({}bps) LiquidityReserve={}({}bps)",
         creator_amount,
+        creator_bps,
         claim_vault_amount,
-        claim_share_percent,
+        claim_vault_bps,
         final_reserve_amount,
-        reserve_percent
+        reserve_bps
     );
     msg!(
         "NEXT STEPS: 1) initialize_orca_pool() to create Whirlpool. 2) deposit_liquidity_to_orca() to move tokens from reserve → Orca."
@@ -468,6 +641,102 @@ pub fn mint_collection_tokens(
     Ok(())
 }
 
+/// `bps` basis points of `amount`, rounded down (u128 intermediate so large supplies cannot overflow)
+fn bps_of(amount: u64, bps: u64) -> Result<u64> {
+    let share = (amount as u128)
+        .checked_mul(bps as u128)
+        .ok_or(ProtocolError::MathOverflow)?
+        / 10000;
+    u64::try_from(share).map_err(|_| ProtocolError::MathOverflow.into())
+}
+
+#[derive(Accounts)]
+pub struct ClaimCreatorVesting<'info> {
+    pub owner: Signer<'info>,
+
+    #[account(
+        seeds = [SEED_GLOBAL_STATE],
+        bump = global_state.bump,
+        constraint = !global_state.is_paused(PAUSE_UNSTAKING) @ ProtocolError::ProtocolPaused
+    )]
+    pub global_state: Account<'info, GlobalState>,
+
+    #[account(
+        mut,
+        seeds = [b"collection", collection.owner.as_ref(), collection.collection_id.as_bytes()],
+        bump = collection.bump,
+        constraint = collection.owner == owner.key() @ ProtocolError::Unauthorized
+    )]
+    pub collection: Account<'info, CollectionState>,
+
+    #[account(
+        mut,
+        seeds = [SEED_CREATOR_VESTING, collection.key().as_ref()],
+        bump
+    )]
+    pub creator_vesting_vault: InterfaceAccount<'info, TokenAccount>,
+
+    /// Owner's collection token account (receives the vested tokens)
+    #[account(
+        mut,
+        constraint = owner_token_account.owner == owner.key() @ ProtocolError::Unauthorized,
+        constraint = owner_token_account.mint == collection_mint.key() @ ProtocolError::Unauthorized
+    )]
+    pub owner_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(
+        constraint = collection_mint.key() == collection.mint @ ProtocolError::Unauthorized
+    )]
+    pub collection_mint: InterfaceAccount<'info, Mint>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+    pub clock: Sysvar<'info, Clock>,
+}
+
+/// Release the vested part of the creator allocation from the creator vesting vault.
+/// Nothing unlocks before the cliff; afterwards the allocation unlocks linearly until
+/// creator_vesting_duration_seconds after minting.
+pub fn claim_creator_vesting(ctx: Context<ClaimCreatorVesting>) -> Result<()> {
+    let collection = &ctx.accounts.collection;
+    let vested = collection.creator_vested_amount(ctx.accounts.clock.unix_timestamp)?;
+    let claimable = vested.saturating_sub(collection.creator_vesting_claimed);
+    require!(claimable > 0, ProtocolError::NothingVested);
+
+    let seeds = [
+        b"collection".as_ref(),
+        collection.owner.as_ref(),
+        collection.collection_id.as_bytes(),
+        &[collection.bump],
+    ];
+    let signer_seeds = &[&seeds[..]];
+    let transfer_ix = TransferChecked {
+        from: ctx.accounts.creator_vesting_vault.to_account_info(),
+        mint: ctx.accounts.collection_mint.to_account_info(),
+        to: ctx.accounts.owner_token_account.to_account_info(),
+        authority: collection.to_account_info(),
+    };
+    let cpi_ctx = CpiContext::new_with_signer(
+        ctx.accounts.token_program.to_account_info(),
+        transfer_ix,
+        signer_seeds,
+    );
+    anchor_spl::token_interface::transfer_checked(cpi_ctx, claimable, ctx.accounts.collection_mint.decimals)?;
+
+    let collection = &mut ctx.accounts.collection;
+    collection.creator_vesting_claimed = collection.creator_vesting_claimed
+        .checked_add(claimable)
+        .ok_or(ProtocolError::MathOverflow)?;
+
+    msg!(
+        "CreatorVestingClaimed: Collection={} Amount={} Claimed={} Total={}",
+        collection.collection_id,
+        claimable,
+        collection.creator_vesting_claimed,
+        collection.creator_vesting_total
+    );
+    Ok(())
+}
+
 #[derive(Accounts)]
 pub struct BurnUnclaimedTokens<'info> {
     #[account(mut)]
@@ -562,4 +831,438 @@ pub fn burn_unclaimed_tokens(ctx: Context<BurnUnclaimedTokens>) -> Result<()> {
     );
 
     Ok(())
-}
\ No newline at end of file
+}
+#[derive(Accounts)]
+pub struct DepositCreatorBond<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    #[account(
+        seeds = [SEED_GLOBAL_STATE],
+        bump = global_state.bump,
+        constraint = !global_state.is_paused(PAUSE_STAKING) @ ProtocolError::ProtocolPaused
+    )]
+    pub global_state: Account<'info, GlobalState>,
+
+    #[account(
+        mut,
+        seeds = [b"collection", collection.owner.as_ref(), collection.collection_id.as_bytes()],
+        bump = collection.bump,
+        constraint = collection.owner == owner.key() @ ProtocolError::Unauthorized
+    )]
+    pub collection: Account<'info, CollectionState>,
+
+    /// Creator bond vault (PDA token account owned by the Collection PDA)
+    /// Funds pro-rata refunds to purchasers when a video is censored
+    #[account(
+        init_if_needed,
+        payer = owner,
+        seeds = [SEED_CREATOR_BOND, collection.key().as_ref()],
+        bump,
+        token::mint = collection_mint,
+        token::authority = collection,
+        token::token_program = token_program,
+    )]
+    pub bond_vault: InterfaceAccount<'info, TokenAccount>,
+
+    /// Owner's collection token account (source of the bond)
+    #[account(
+        mut,
+        constraint = owner_token_account.owner == owner.key() @ ProtocolError::Unauthorized,
+        constraint = owner_token_account.mint == collection_mint.key() @ ProtocolError::Unauthorized
+    )]
+    pub owner_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(
+        constraint = collection_mint.key() == collection.mint @ ProtocolError::Unauthorized
+    )]
+    pub collection_mint: InterfaceAccount<'info, Mint>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>,
+}
+
+/// Deposits collection tokens into the creator bond vault.
+/// The bond backs censorship refunds: when a moderator censors a video, the video's
+/// pro-rata share of the bond is reserved for purchasers (see resolve_cid_censorship).
+/// Bonded tokens cannot be withdrawn by the owner.
+pub fn deposit_creator_bond(ctx: Context<DepositCreatorBond>, amount: u64) -> Result<()> {
+    require!(amount > 0, ProtocolError::InsufficientFunds);
+
+    let transfer_ix = TransferChecked {
+        from: ctx.accounts.owner_token_account.to_account_info(),
+        mint: ctx.accounts.collection_mint.to_account_info(),
+        to: ctx.accounts.bond_vault.to_account_info(),
+        authority: ctx.accounts.owner.to_account_info(),
+    };
+    let cpi_ctx = CpiContext::new(ctx.accounts.token_program.to_account_info(), transfer_ix);
+    anchor_spl::token_interface::transfer_checked(cpi_ctx, amount, ctx.accounts.collection_mint.decimals)?;
+
+    let collection = &mut ctx.accounts.collection;
+    collection.creator_bond = collection.creator_bond
+        .checked_add(amount)
+        .ok_or(ProtocolError::MathOverflow)?;
+
+    msg!(
+        "CreatorBondDeposited: Collection={} Amount={} TotalBond={}",
+        collection.collection_id,
+        amount,
+        collection.creator_bond
+    );
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct SetCollectionFeeOverrides<'info> {
+    pub owner: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"collection", collection.owner.as_ref(), collection.collection_id.as_bytes()],
+        bump = collection.bump,
+        constraint = collection.owner == owner.key() @ ProtocolError::Unauthorized
+    )]
+    pub collection: Account<'info, CollectionState>,
+
+    #[account(
+        seeds = [SEED_GLOBAL_STATE],
+        bump = global_state.bump,
+        constraint = !global_state.is_paused(PAUSE_MINTING) @ ProtocolError::ProtocolPaused
+    )]
+    pub global_state: Account<'info, GlobalState>,
+}
+
+/// Override the protocol creator share for a single collection, within protocol bounds.
+/// creator_share_bps: Creator share of minted supply; fixed once tokens are minted
+/// Passing None clears the override so the collection follows GlobalState again. The purchase
+/// split is set with update_collection.
+pub fn set_collection_fee_overrides(
+    ctx: Context<SetCollectionFeeOverrides>,
+    creator_share_bps: Option<u16>,
+) -> Result<()> {
+    if let Some(share_bps) = creator_share_bps {
+        require!(share_bps <= MAX_CREATOR_SHARE_BPS, ProtocolError::InvalidFeeConfig);
+    }
+
+    let collection = &mut ctx.accounts.collection;
+    if creator_share_bps != collection.creator_share_override_bps {
+        require!(!collection.tokens_minted, ProtocolError::Unauthorized);
+        collection.creator_share_override_bps = creator_share_bps;
+    }
+
+    msg!(
+        "CollectionFeeOverridesSet: Collection={} CreatorShareBps={:?}",
+        collection.key(),
+        collection.creator_share_override_bps
+    );
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(update: CollectionUpdate)]
+pub struct UpdateCollection<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"collection", collection.owner.as_ref(), collection.collection_id.as_bytes()],
+        bump = collection.bump,
+        constraint = collection.owner == owner.key() @ ProtocolError::Unauthorized,
+        // Grow the account when videos are added (shrinking is rejected in the handler)
+        realloc = CollectionState::space(update.total_videos.unwrap_or(collection.total_videos)),
+        realloc::payer = owner,
+        realloc::zero = false
+    )]
+    pub collection: Account<'info, CollectionState>,
+
+    /// CHECK: The collection's staking pool, which may not exist yet; read in the handler to
+    /// lock lp_fee_staker_bps once tokens are staked
+    #[account(
+        seeds = [SEED_STAKING_POOL, collection.key().as_ref()],
+        bump
+    )]
+    pub staking_pool: UncheckedAccount<'info>,
+
+    #[account(
+        seeds = [SEED_GLOBAL_STATE],
+        bump = global_state.bump,
+        constraint = !global_state.is_paused(PAUSE_MINTING) @ ProtocolError::ProtocolPaused
+    )]
+    pub global_state: Account<'info, GlobalState>,
+
+    pub system_program: Program<'info, System>,
+    pub clock: Sysvar<'info, Clock>,
+}
+
+/// Update owner-editable collection parameters; only provided fields change.
+/// - cid_hash: publishes a new manifest; the previous hash is kept in previous_cid_hashes
+/// - total_videos: may only grow, and only before any copyright claim has been paid
+/// - lp_fee_staker_bps: staker share of harvested Orca LP fees, within MIN/MAX_LP_FEE_STAKER_BPS;
+///   fixed once the staking pool holds tokens
+/// - staker_split_bps / creator_royalty_bps: fixed after the first purchase (see set_purchase_split)
+pub fn update_collection(ctx: Context<UpdateCollection>, update: CollectionUpdate) -> Result<()> {
+    let global_split_bps = ctx.accounts.global_state.staker_split_bps;
+    let collection = &mut ctx.accounts.collection;
+
+    require!(!collection.is_blacklisted, ProtocolError::Unauthorized);
+
+    if let Some(cid_hash) = update.cid_hash {
+        if cid_hash != collection.cid_hash {
+            if collection.previous_cid_hashes.len() == MAX_CID_HISTORY {
+                collection.previous_cid_hashes.remove(0);
+            }
+            let previous = collection.cid_hash;
+            collection.previous_cid_hashes.push(previous);
+            collection.cid_hash = cid_hash;
+            collection.manifest_version = collection.manifest_version
+                .checked_add(1)
+                .ok_or(ProtocolError::MathOverflow)?;
+        }
+    }
+
+    if let Some(access_threshold_usd) = update.access_threshold_usd {
+        collection.access_threshold_usd = access_threshold_usd;
+    }
+
+    if let Some(oracle_feed) = update.oracle_feed {
+        collection.oracle_feed = oracle_feed;
+    }
+
+    if let Some(total_videos) = update.total_videos {
+        require!(total_videos >= collection.total_videos, ProtocolError::InvalidVideoCount);
+        if total_videos > collection.total_videos {
+            // Claims are paid per video from the initial vault amount; adding videos after a
+            // payout would re-price shares that have already been claimed
+            require!(
+                collection.claimed_share_bps.iter().all(|&bps| bps == 0),
+                ProtocolError::InvalidVideoCount
+            );
+            collection.total_videos = total_videos;
+            collection.claimed_share_bps.resize(total_videos as usize, 0);
+            collection.censored_bitmap.resize((total_videos as usize).div_ceil(8), 0);
+            collection.refund_reserved_bitmap.resize((total_videos as usize).div_ceil(8), 0);
+        }
+    }
+
+    if let Some(lp_fee_staker_bps) = update.lp_fee_staker_bps {
+        require!(
+            (MIN_LP_FEE_STAKER_BPS..=MAX_LP_FEE_STAKER_BPS).contains(&lp_fee_staker_bps),
+            ProtocolError::InvalidFeeConfig
+        );
+        if lp_fee_staker_bps != collection.lp_fee_staker_bps {
+            // Stakers joined under the current split; only an empty pool may see it change
+            let staking_pool = &ctx.accounts.staking_pool;
+            if !staking_pool.data_is_empty() {
+                let pool = CollectionStakingPool::try_deserialize(&mut &staking_pool.data.borrow()[..])?;
+                require!(pool.total_staked == 0, ProtocolError::Unauthorized);
+            }
+            collection.lp_fee_staker_bps = lp_fee_staker_bps;
+        }
+    }
+
+    if update.staker_split_bps.is_some() || update.creator_royalty_bps.is_some() {
+        let split_bps = update.staker_split_bps.or(collection.staker_split_override_bps);
+        let royalty_bps = update.creator_royalty_bps.unwrap_or(collection.creator_royalty_bps);
+        set_purchase_split(collection, split_bps, royalty_bps, global_split_bps)?;
+    }
+
+    emit!(CollectionUpdated {
+        collection: collection.key(),
+        update,
+        manifest_version: collection.manifest_version,
+        timestamp: ctx.accounts.clock.unix_timestamp,
+    });
+    msg!(
+        "CollectionUpdated: Collection={} ManifestVersion={} TotalVideos={} StakerSplitBps={:?} CreatorRoyaltyBps={}",
+        collection.key(),
+        collection.manifest_version,
+        collection.total_videos,
+        collection.staker_split_override_bps,
+        collection.creator_royalty_bps
+    );
+
+    Ok(())
+}
+
+/// Set a collection's purchase split from update_collection.
+/// Both values are fixed after the first purchase. A royalty without a staker split pins the
+/// current GlobalState split, so later protocol changes cannot push the escrow below
+/// MIN_PEER_ESCROW_BPS.
+fn set_purchase_split(
+    collection: &mut CollectionState,
+    staker_split_bps: Option<u16>,
+    creator_royalty_bps: u16,
+    global_split_bps: u16,
+) -> Result<()> {
+    let split_bps = staker_split_bps.or((creator_royalty_bps > 0).then_some(global_split_bps));
+    validate_purchase_split(split_bps.unwrap_or(global_split_bps), creator_royalty_bps)?;
+
+    if split_bps != collection.staker_split_override_bps
+        || creator_royalty_bps != collection.creator_royalty_bps
+    {
+        require!(collection.total_purchases == 0, ProtocolError::Unauthorized);
+    }
+    collection.staker_split_override_bps = split_bps;
+    collection.creator_royalty_bps = creator_royalty_bps;
+    Ok(())
+}
+
+/// Check a per-collection purchase split against protocol bounds: the staker split stays within
+/// MIN/MAX_STAKER_SPLIT_BPS, the royalty within MAX_CREATOR_ROYALTY_BPS, and the peers escrow
+/// keeps at least MIN_PEER_ESCROW_BPS
+pub(crate) fn validate_purchase_split(staker_split_bps: u16, creator_royalty_bps: u16) -> Result<()> {
+    require!(
+        (MIN_STAKER_SPLIT_BPS..=MAX_STAKER_SPLIT_BPS).contains(&staker_split_bps),
+        ProtocolError::InvalidFeeConfig
+    );
+    require!(creator_royalty_bps <= MAX_CREATOR_ROYALTY_BPS, ProtocolError::InvalidFeeConfig);
+    require!(
+        staker_split_bps + creator_royalty_bps + MIN_PEER_ESCROW_BPS <= FULL_SHARE_BPS,
+        ProtocolError::InvalidFeeConfig
+    );
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct UpdateCollectionMetadata<'info> {
+    /// Pays for any growth of the mint's metadata
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"collection", collection.owner.as_ref(), collection.collection_id.as_bytes()],
+        bump = collection.bump,
+        constraint = collection.owner == owner.key() @ ProtocolError::Unauthorized
+    )]
+    pub collection: Account<'info, CollectionState>,
+
+    #[account(
+        seeds = [SEED_GLOBAL_STATE],
+        bump = global_state.bump,
+        constraint = !global_state.is_paused(PAUSE_MINTING) @ ProtocolError::ProtocolPaused
+    )]
+    pub global_state: Account<'info, GlobalState>,
+
+    /// CHECK: Collection mint holding the TokenMetadata extension
+    #[account(
+        mut,
+        address = collection.mint @ ProtocolError::InvalidAccount
+    )]
+    pub mint: UncheckedAccount<'info>,
+
+    /// CHECK: Token-2022 program (owner of the metadata extension)
+    #[account(address = spl_token_2022::ID)]
+    pub token_program: UncheckedAccount<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+/// Update the Token-2022 metadata on the collection mint; only provided fields change.
+/// A new name is also written to CollectionState.name so the two stay in sync; this is the
+/// only way to rename a collection.
+pub fn update_collection_metadata(
+    ctx: Context<UpdateCollectionMetadata>,
+    name: Option<String>,
+    symbol: Option<String>,
+    uri: Option<String>,
+) -> Result<()> {
+    require!(!ctx.accounts.collection.is_blacklisted, ProtocolError::Unauthorized);
+    if let Some(name) = &name {
+        require!(name.len() <= MAX_NAME_LEN, ProtocolError::StringTooLong);
+    }
+    if let Some(symbol) = &symbol {
+        require!(symbol.len() <= MAX_SYMBOL_LEN, ProtocolError::StringTooLong);
+    }
+    if let Some(uri) = &uri {
+        require!(uri.len() <= MAX_URL_LEN, ProtocolError::StringTooLong);
+    }
+
+    let collection = &ctx.accounts.collection;
+    let bump = [collection.bump];
+    let seeds = [
+        b"collection".as_ref(),
+        collection.owner.as_ref(),
+        collection.collection_id.as_bytes(),
+        &bump,
+    ];
+    let updates = [
+        (Field::Name, name.clone()),
+        (Field::Symbol, symbol),
+        (Field::Uri, uri),
+    ];
+    for (field, value) in updates {
+        let Some(value) = value else { continue };
+        token_metadata_update_field(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                TokenMetadataUpdateField {
+                    program_id: ctx.accounts.token_program.to_account_info(),
+                    metadata: ctx.accounts.mint.to_account_info(),
+                    update_authority: ctx.accounts.collection.to_account_info(),
+                },
+                &[&seeds[..]],
+            ),
+            field,
+            value,
+        )?;
+    }
+
+    // Token-2022 reallocs the mint without funding it; keep it rent-exempt at its new size
+    let mint = ctx.accounts.mint.to_account_info();
+    let required = Rent::get()?.minimum_balance(mint.data_len());
+    if required > mint.lamports() {
+        let shortfall = required - mint.lamports();
+        transfer(
+            CpiContext::new(
+                ctx.accounts.system_program.to_account_info(),
+                Transfer {
+                    from: ctx.accounts.owner.to_account_info(),
+                    to: mint,
+                },
+            ),
+            shortfall,
+        )?;
+    }
+
+    let collection = &mut ctx.accounts.collection;
+    if let Some(name) = name {
+        collection.name = name;
+    }
+
+    msg!(
+        "CollectionMetadataUpdated: Collection={} Mint={}",
+        collection.key(),
+        collection.mint
+    );
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn collection_symbol_keeps_uppercased_alphanumerics() {
+        assert_eq!(collection_symbol("cooking-101"), "COOKING101");
+        assert_eq!(collection_symbol("a_very-long-collection"), "AVERYLONGC");
+        assert_eq!(collection_symbol("--x--"), "X");
+    }
+
+    #[test]
+    fn bps_of_rounds_down() {
+        assert_eq!(bps_of(1_000, 2_500).unwrap(), 250);
+        assert_eq!(bps_of(999, 1_000).unwrap(), 99);
+        assert_eq!(bps_of(1_000, 0).unwrap(), 0);
+    }
+
+    #[test]
+    fn bps_of_handles_large_supplies() {
+        // amount * bps overflows u64 but not the u128 intermediate
+        assert_eq!(bps_of(u64::MAX, 10_000).unwrap(), u64::MAX);
+        assert_eq!(bps_of(u64::MAX, 5_000).unwrap(), u64::MAX / 2);
+        assert_eq!(bps_of(u64::MAX, 20_000).unwrap_err(), ProtocolError::MathOverflow.into());
+    }
+}
@@ -6,7 +6,7 @@ pub mod instructions;
 pub mod state;
 
 use instructions::*;
-use state::TicketType;
+use state::{AdminAction, CollectionTokenConfig, CollectionUpdate, DisbursementCategory, MintConfigUpdate, ServiceType, TicketType};
 
 declare_id!("jk9Hqt4dLcLcQzeDvVQ1actvY5EZu6cvT3SUc7JLM4m");
 
@@ -28,7 +28,7 @@ pub mod solana_program {
         cid_hash: [u8; 32],
         access_threshold_usd: u64,
         total_videos: u16,
-        performer_share_percent: Option<u8>
+        token_config: CollectionTokenConfig
     ) -> Result<()> {
         instructions::user::create_collection(
             ctx, 
@@ -37,7 +37,7 @@ pub mod solana_program {
             cid_hash, 
             access_threshold_usd, 
             total_videos,
-            performer_share_percent
+            token_config
         )
     }
 
@@ -48,6 +48,40 @@ pub mod solana_program {
         instructions::user::mint_collection_tokens(ctx, amount)
     }
 
+    pub fn set_collection_fee_overrides(
+        ctx: Context<SetCollectionFeeOverrides>,
+        creator_share_bps: Option<u16>,
+    ) -> Result<()> {
+        instructions::user::set_collection_fee_overrides(ctx, creator_share_bps)
+    }
+
+    pub fn update_collection(
+        ctx: Context<UpdateCollection>,
+        update: CollectionUpdate,
+    ) -> Result<()> {
+        instructions::user::update_collection(ctx, update)
+    }
+
+    pub fn update_collection_metadata(
+        ctx: Context<UpdateCollectionMetadata>,
+        name: Option<String>,
+        symbol: Option<String>,
+        uri: Option<String>,
+    ) -> Result<()> {
+        instructions::user::update_collection_metadata(ctx, name, symbol, uri)
+    }
+
+    pub fn deposit_creator_bond(
+        ctx: Context<DepositCreatorBond>,
+        amount: u64,
+    ) -> Result<()> {
+        instructions::user::deposit_creator_bond(ctx, amount)
+    }
+
+    pub fn claim_creator_vesting(ctx: Context<ClaimCreatorVesting>) -> Result<()> {
+        instructions::user::claim_creator_vesting(ctx)
+    }
+
     pub fn burn_unclaimed_tokens(ctx: Context<BurnUnclaimedTokens>) -> Result<()> {
         instructions::user::burn_unclaimed_tokens(ctx)
     }
@@ -69,6 +103,15 @@ pub mod solana_program {
         instructions::access::purchase_access(ctx, total_amount, cid_hash)
     }
 
+    pub fn purchase_access_with_swap<'info>(
+        ctx: Context<'_, '_, '_, 'info, PurchaseAccessWithSwap<'info>>,
+        amount_in: u64,
+        min_amount_out: u64,
+        cid_hash: [u8; 32],
+    ) -> Result<()> {
+        instructions::access::purchase_access_with_swap(ctx, amount_in, min_amount_out, cid_hash)
+    }
+
     pub fn release_escrow<'info>(
         ctx: Context<'_, '_, '_, 'info, ReleaseEscrow<'info>>,
         peer_wallets: Vec<Pubkey>,
@@ -101,9 +144,10 @@ pub mod solana_program {
         indexer_url: String,
         registry_url: String,
         mod_stake_min: u64,
-        fee_basis_points: u16
+        fee_basis_points: u16,
+        whirlpools_config: Pubkey
     ) -> Result<()> {
-        instructions::admin::initialize_protocol(ctx, indexer_url, registry_url, mod_stake_min, fee_basis_points)
+        instructions::admin::initialize_protocol(ctx, indexer_url, registry_url, mod_stake_min, fee_basis_points, whirlpools_config)
     }
 
     pub fn update_global_state(
@@ -112,8 +156,26 @@ pub mod solana_program {
         registry_url: Option<String>,
         mod_stake_min: Option<u64>,
         fee_basis_points: Option<u16>,
+        staker_split_bps: Option<u16>,
+        mint_config: MintConfigUpdate,
     ) -> Result<()> {
-        instructions::admin::update_global_state(ctx, indexer_url, registry_url, mod_stake_min, fee_basis_points)
+        instructions::admin::update_global_state(
+            ctx,
+            indexer_url,
+            registry_url,
+            mod_stake_min,
+            fee_basis_points,
+            staker_split_bps,
+            mint_config,
+        )
+    }
+
+    pub fn execute_config_change(ctx: Context<ExecuteConfigChange>) -> Result<()> {
+        instructions::admin::execute_config_change(ctx)
+    }
+
+    pub fn cancel_config_change(ctx: Context<CancelConfigChange>) -> Result<()> {
+        instructions::admin::cancel_config_change(ctx)
     }
 
     pub fn disable_global_state_updates(
@@ -122,6 +184,196 @@ pub mod solana_program {
         instructions::admin::disable_global_state_updates(ctx)
     }
 
+    pub fn propose_admin(ctx: Context<ProposeAdmin>, new_admin: Pubkey) -> Result<()> {
+        instructions::admin::propose_admin(ctx, new_admin)
+    }
+
+    pub fn accept_admin(ctx: Context<AcceptAdmin>) -> Result<()> {
+        instructions::admin::accept_admin(ctx)
+    }
+
+    pub fn set_pause_flags(ctx: Context<SetPauseFlags>, paused_flags: u8) -> Result<()> {
+        instructions::admin::set_pause_flags(ctx, paused_flags)
+    }
+
+    pub fn set_guardian(ctx: Context<SetGuardian>, guardian: Option<Pubkey>) -> Result<()> {
+        instructions::admin::set_guardian(ctx, guardian)
+    }
+
+    pub fn create_admin_multisig(
+        ctx: Context<CreateAdminMultisig>,
+        signers: Vec<Pubkey>,
+        threshold: u8,
+    ) -> Result<()> {
+        instructions::admin::create_admin_multisig(ctx, signers, threshold)
+    }
+
+    pub fn propose_admin_action(
+        ctx: Context<ProposeAdminAction>,
+        action: AdminAction,
+    ) -> Result<()> {
+        instructions::admin::propose_admin_action(ctx, action)
+    }
+
+    pub fn approve_admin_action(ctx: Context<ApproveAdminAction>) -> Result<()> {
+        instructions::admin::approve_admin_action(ctx)
+    }
+
+    pub fn execute_admin_action(ctx: Context<ExecuteAdminAction>) -> Result<()> {
+        instructions::admin::execute_admin_action(ctx)
+    }
+
+    pub fn initialize_treasury(ctx: Context<InitializeTreasury>) -> Result<()> {
+        instructions::treasury::initialize_treasury(ctx)
+    }
+
+    pub fn create_treasury_budget(
+        ctx: Context<CreateTreasuryBudget>,
+        name: String,
+        category: DisbursementCategory,
+        allocated: u64,
+    ) -> Result<()> {
+        instructions::treasury::create_treasury_budget(ctx, name, category, allocated)
+    }
+
+    pub fn update_treasury_budget(
+        ctx: Context<UpdateTreasuryBudget>,
+        allocated: u64,
+    ) -> Result<()> {
+        instructions::treasury::update_treasury_budget(ctx, allocated)
+    }
+
+    pub fn disburse_from_treasury(
+        ctx: Context<DisburseFromTreasury>,
+        amount: u64,
+        memo: String,
+    ) -> Result<()> {
+        instructions::treasury::disburse_from_treasury(ctx, amount, memo)
+    }
+
+    pub fn initialize_governance(
+        ctx: Context<InitializeGovernance>,
+        quorum_votes: u64,
+        proposal_threshold: u64,
+        voting_period: i64,
+        execution_delay: i64,
+    ) -> Result<()> {
+        instructions::governance::initialize_governance(ctx, quorum_votes, proposal_threshold, voting_period, execution_delay)
+    }
+
+    pub fn accept_governance_admin(ctx: Context<AcceptGovernanceAdmin>) -> Result<()> {
+        instructions::governance::accept_governance_admin(ctx)
+    }
+
+    pub fn lock_governance_tokens(ctx: Context<LockGovernanceTokens>, amount: u64) -> Result<()> {
+        instructions::governance::lock_governance_tokens(ctx, amount)
+    }
+
+    pub fn unlock_governance_tokens(ctx: Context<UnlockGovernanceTokens>, amount: u64) -> Result<()> {
+        instructions::governance::unlock_governance_tokens(ctx, amount)
+    }
+
+    pub fn create_governance_proposal(
+        ctx: Context<CreateGovernanceProposal>,
+        action: AdminAction,
+    ) -> Result<()> {
+        instructions::governance::create_governance_proposal(ctx, action)
+    }
+
+    pub fn cast_governance_vote(ctx: Context<CastGovernanceVote>, support: bool) -> Result<()> {
+        instructions::governance::cast_governance_vote(ctx, support)
+    }
+
+    pub fn execute_governance_proposal(ctx: Context<ExecuteGovernanceProposal>) -> Result<()> {
+        instructions::governance::execute_governance_proposal(ctx)
+    }
+
+    pub fn register_service_endpoint(
+        ctx: Context<RegisterServiceEndpoint>,
+        service_type: ServiceType,
+        url: String,
+        bond_amount: u64,
+    ) -> Result<()> {
+        instructions::services::register_service_endpoint(ctx, service_type, url, bond_amount)
+    }
+
+    pub fn deregister_service_endpoint(
+        ctx: Context<DeregisterServiceEndpoint>,
+        service_type: ServiceType,
+    ) -> Result<()> {
+        instructions::services::deregister_service_endpoint(ctx, service_type)
+    }
+
+    pub fn withdraw_service_bond(
+        ctx: Context<WithdrawServiceBond>,
+        service_type: ServiceType,
+    ) -> Result<()> {
+        instructions::services::withdraw_service_bond(ctx, service_type)
+    }
+
+    pub fn report_service_endpoint(
+        ctx: Context<ReportServiceEndpoint>,
+        target_id: String,
+        operator: Pubkey,
+        service_type: ServiceType,
+        reason: String,
+        evidence_hash: [u8; 32],
+    ) -> Result<()> {
+        instructions::moderation::report_service_endpoint(ctx, target_id, operator, service_type, reason, evidence_hash)
+    }
+
+    pub fn delist_service_endpoint(
+        ctx: Context<DelistServiceEndpoint>,
+        operator: Pubkey,
+        service_type: ServiceType,
+    ) -> Result<()> {
+        instructions::services::delist_service_endpoint(ctx, operator, service_type)
+    }
+
+    pub fn migrate_global_state(ctx: Context<MigrateGlobalState>) -> Result<()> {
+        instructions::migration::migrate_global_state(ctx)
+    }
+
+    pub fn migrate_collection(ctx: Context<MigrateCollection>) -> Result<()> {
+        instructions::migration::migrate_collection(ctx)
+    }
+
+    pub fn migrate_user_account(ctx: Context<MigrateAccount>) -> Result<()> {
+        instructions::migration::migrate_user_account(ctx)
+    }
+
+    pub fn migrate_access_escrow(ctx: Context<MigrateAccount>) -> Result<()> {
+        instructions::migration::migrate_access_escrow(ctx)
+    }
+
+    pub fn migrate_cid_reveal(ctx: Context<MigrateAccount>) -> Result<()> {
+        instructions::migration::migrate_cid_reveal(ctx)
+    }
+
+    pub fn migrate_peer_trust_state(ctx: Context<MigrateAccount>) -> Result<()> {
+        instructions::migration::migrate_peer_trust_state(ctx)
+    }
+
+    pub fn migrate_pinner_state(ctx: Context<MigrateAccount>) -> Result<()> {
+        instructions::migration::migrate_pinner_state(ctx)
+    }
+
+    pub fn migrate_moderator_stake(ctx: Context<MigrateAccount>) -> Result<()> {
+        instructions::migration::migrate_moderator_stake(ctx)
+    }
+
+    pub fn migrate_staking_pool(ctx: Context<MigrateAccount>) -> Result<()> {
+        instructions::migration::migrate_staking_pool(ctx)
+    }
+
+    pub fn migrate_staker_position(ctx: Context<MigrateAccount>) -> Result<()> {
+        instructions::migration::migrate_staker_position(ctx)
+    }
+
+    pub fn migrate_mod_ticket(ctx: Context<MigrateModTicket>) -> Result<()> {
+        instructions::migration::migrate_mod_ticket(ctx)
+    }
+
     pub fn stake_moderator(
         ctx: Context<StakeModerator>,
         stake_amount: u64,
@@ -135,6 +387,14 @@ pub mod solana_program {
         instructions::staking::slash_moderator(ctx)
     }
 
+    pub fn register_moderator_specializations(
+        ctx: Context<RegisterModeratorSpecializations>,
+        specializations: u8,
+        jurisdictions: Vec<[u8; 2]>,
+    ) -> Result<()> {
+        instructions::staking::register_moderator_specializations(ctx, specializations, jurisdictions)
+    }
+
     pub fn stake_collection_tokens(
         ctx: Context<StakeCollectionTokens>,
         amount: u64,
@@ -146,6 +406,10 @@ pub mod solana_program {
         instructions::staking::claim_staking_rewards(ctx)
     }
 
+    pub fn claim_staking_capgm_rewards(ctx: Context<ClaimStakingCapgmRewards>) -> Result<()> {
+        instructions::staking::claim_staking_capgm_rewards(ctx)
+    }
+
     pub fn unstake_collection_tokens(
         ctx: Context<UnstakeCollectionTokens>,
         amount: u64,
@@ -158,9 +422,11 @@ pub mod solana_program {
         target_id: String,
         ticket_type: TicketType,
         reason: String,
-        claim_indices: Vec<u16>
+        evidence_hash: [u8; 32],
+        claim_indices: Vec<u16>,
+        claim_shares_bps: Vec<u16>
     ) -> Result<()> {
-        instructions::moderation::create_ticket(ctx, target_id, ticket_type, reason, claim_indices)
+        instructions::moderation::create_ticket(ctx, target_id, ticket_type, reason, evidence_hash, claim_indices, claim_shares_bps)
     }
 
     pub fn resolve_ticket(
@@ -177,6 +443,17 @@ pub mod solana_program {
         instructions::moderation::resolve_copyright_claim(ctx, verdict)
     }
 
+    pub fn submit_counter_notice(
+        ctx: Context<SubmitCounterNotice>,
+        counter_notice_hash: [u8; 32],
+    ) -> Result<()> {
+        instructions::moderation::submit_counter_notice(ctx, counter_notice_hash)
+    }
+
+    pub fn reassign_ticket(ctx: Context<ReassignTicket>) -> Result<()> {
+        instructions::moderation::reassign_ticket(ctx)
+    }
+
     pub fn resolve_cid_censorship(
         ctx: Context<ResolveCidCensorship>,
         verdict: bool,
@@ -186,6 +463,13 @@ pub mod solana_program {
         instructions::moderation::resolve_cid_censorship(ctx, verdict, censored_cid, video_index)
     }
 
+    pub fn claim_censorship_refund(
+        ctx: Context<ClaimCensorshipRefund>,
+        access_nft_mint: Pubkey,
+    ) -> Result<()> {
+        instructions::moderation::claim_censorship_refund(ctx, access_nft_mint)
+    }
+
     pub fn initialize_orca_pool(
         ctx: Context<InitializeOrcaPool>,
         tick_spacing: u16,
@@ -210,4 +494,62 @@ pub mod solana_program {
     ) -> Result<()> {
         instructions::orca::deposit_liquidity_to_orca(ctx, liquidity_amount, token_max_a, token_max_b)
     }
+
+    pub fn collect_orca_fees<'info>(
+        ctx: Context<'_, '_, 'info, 'info, CollectOrcaFees<'info>>,
+        reward_indices: Vec<u8>,
+    ) -> Result<()> {
+        instructions::orca::collect_orca_fees(ctx, reward_indices)
+    }
+
+    pub fn claim_owner_rewards(ctx: Context<ClaimOwnerRewards>) -> Result<()> {
+        instructions::orca::claim_owner_rewards(ctx)
+    }
+
+    pub fn decrease_orca_liquidity(
+        ctx: Context<ManageOrcaLiquidity>,
+        liquidity_amount: u128,
+        token_min_a: u64,
+        token_min_b: u64,
+    ) -> Result<()> {
+        instructions::orca::decrease_orca_liquidity(ctx, liquidity_amount, token_min_a, token_min_b)
+    }
+
+    pub fn increase_orca_liquidity(
+        ctx: Context<ManageOrcaLiquidity>,
+        liquidity_amount: u128,
+        token_max_a: u64,
+        token_max_b: u64,
+    ) -> Result<()> {
+        instructions::orca::increase_orca_liquidity(ctx, liquidity_amount, token_max_a, token_max_b)
+    }
+
+    pub fn claim_vested_liquidity(
+        ctx: Context<ClaimVestedLiquidity>,
+        liquidity_amount: u128,
+        token_min_a: u64,
+        token_min_b: u64,
+    ) -> Result<()> {
+        instructions::orca::claim_vested_liquidity(ctx, liquidity_amount, token_min_a, token_min_b)
+    }
+
+    pub fn close_orca_position(ctx: Context<CloseOrcaPosition>) -> Result<()> {
+        instructions::orca::close_orca_position(ctx)
+    }
+
+    pub fn resume_launch(ctx: Context<ResumeLaunch>) -> Result<()> {
+        instructions::launch::resume_launch(ctx)
+    }
+
+    pub fn abort_launch(ctx: Context<AbortLaunch>) -> Result<()> {
+        instructions::launch::abort_launch(ctx)
+    }
+
+    pub fn initialize_price_observation(ctx: Context<InitializePriceObservation>) -> Result<()> {
+        instructions::oracle::initialize_price_observation(ctx)
+    }
+
+    pub fn record_price_observation(ctx: Context<RecordPriceObservation>) -> Result<()> {
+        instructions::oracle::record_price_observation(ctx)
+    }
 }
@@ -3,8 +3,17 @@ use anchor_lang::prelude::*;
 pub const MAX_ID_LEN: usize = 32;
 pub const MAX_URL_LEN: usize = 200;
 pub const MAX_NAME_LEN: usize = 50;
+pub const MAX_SYMBOL_LEN: usize = 10; // Token-2022 metadata symbol on collection mints
 pub const MAX_IPNS_KEY_LEN: usize = 100;
 pub const MAX_REASON_LEN: usize = 200;
+pub const MAX_CLAIM_INDICES: usize = 32;
+pub const FULL_SHARE_BPS: u16 = 10000; // 100% of a video's claim allocation
+pub const MAX_JURISDICTIONS: usize = 8;
+pub const MAX_REGISTERED_MODERATORS: usize = 64;
+pub const MAX_ADMIN_SIGNERS: usize = 10;
+pub const PRICE_OBSERVATION_SLOTS: usize = 8;
+pub const MAX_SERVICE_ENDPOINTS: usize = 16;
+pub const MAX_CID_HISTORY: usize = 8; // Superseded manifest hashes kept on CollectionState
 
 #[account]
 pub struct GlobalState {
@@ -16,13 +25,318 @@ pub struct GlobalState {
     pub capgm_mint: Pubkey,        // The CAPGM ecosystem token mint
     pub fee_basis_points: u16,     // Purchase fee in basis points (default: 200 = 2%). Collected on purchases and sent to treasury. Configurable via update_global_state.
     pub updates_disabled: bool,    // If true, GlobalState can no longer be updated (one-way lock)
+    pub pending_admin: Option<Pubkey>, // Proposed admin awaiting accept_admin (two-step handover)
+    pub staker_split_bps: u16,     // Share of post-fee purchase amount sent to stakers (remainder to peers escrow)
+    pub creator_share_bps: u16,    // Share of minted collection supply sent to the creator
+    pub paused_flags: u8,          // PAUSE_* bitfield; each instruction checks its own scope
+    pub guardian: Option<Pubkey>,  // Emergency key that may pause (but not unpause)
+    pub stablecoin_mint: Pubkey,   // Stablecoin accepted by purchase_access_with_swap (default = disabled)
+    pub stablecoin_pool: Pubkey,   // Stablecoin/CAPGM Whirlpool used for the first swap hop
+    pub whirlpools_config: Pubkey, // Only WhirlpoolsConfig that collection pools may be created under
+    pub min_collection_supply: u64, // Smallest supply mint_collection_tokens accepts (whole tokens)
+    pub max_collection_supply: u64, // Largest supply mint_collection_tokens accepts (whole tokens)
+    pub version: u8,               // Account layout version (GlobalState::VERSION); see migrate_global_state
     pub bump: u8,
 }
 
 impl GlobalState {
+    pub const VERSION: u8 = 1;
     // 8 (discriminator) + 32 (admin) + 32 (treasury) + 200 (indexer_api_url) + 200 (node_registry_url) 
-    // + 8 (moderator_stake_minimum) + 32 (capgm_mint) + 2 (fee_basis_points) + 1 (updates_disabled) + 1 (bump)
-    pub const MAX_SIZE: usize = 8 + 32 + 32 + 200 + 200 + 8 + 32 + 2 + 1 + 1;
+    // + 8 (moderator_stake_minimum) + 32 (capgm_mint) + 2 (fee_basis_points) + 1 (updates_disabled)
+    // + 33 (pending_admin) + 2 (staker_split_bps) + 2 (creator_share_bps)
+    // + 1 (paused_flags) + 33 (guardian) + 32 (stablecoin_mint) + 32 (stablecoin_pool)
+    // + 32 (whirlpools_config) + 8 (min_collection_supply) + 8 (max_collection_supply)
+    // + 1 (version) + 1 (bump)
+    pub const MAX_SIZE: usize = 8 + 32 + 32 + 200 + 200 + 8 + 32 + 2 + 1 + 33 + 2 + 2 + 1 + 33 + 32 + 32 + 32 + 8 + 8 + 1 + 1;
+
+    pub fn is_paused(&self, scope: u8) -> bool {
+        self.paused_flags & scope != 0
+    }
+}
+
+#[account]
+pub struct AdminMultisig {
+    pub signers: Vec<Pubkey>, // M-of-N admin key set
+    pub threshold: u8,        // Approvals required to execute a proposal
+    pub proposal_count: u64,  // Monotonic counter used to derive proposal PDAs
+    pub version: u8,
+    pub bump: u8,
+}
+
+impl AdminMultisig {
+    pub const VERSION: u8 = 1;
+    // 8 (discriminator) + 4 (vec length) + MAX_ADMIN_SIGNERS * 32 (signers) + 1 (threshold)
+    // + 8 (proposal_count) + 1 (version) + 1 (bump)
+    pub const MAX_SIZE: usize = 8 + 4 + MAX_ADMIN_SIGNERS * 32 + 1 + 8 + 1 + 1;
+}
+
+/// GlobalState parameter changes; only provided fields are updated
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq, Debug, Default)]
+pub struct ConfigUpdate {
+    pub indexer_url: Option<String>,
+    pub registry_url: Option<String>,
+    pub mod_stake_min: Option<u64>,
+    pub fee_basis_points: Option<u16>,
+    pub treasury: Option<Pubkey>,
+    pub capgm_mint: Option<Pubkey>,
+    pub staker_split_bps: Option<u16>,
+    pub creator_share_bps: Option<u16>,
+    pub stablecoin_mint: Option<Pubkey>,
+    pub stablecoin_pool: Option<Pubkey>,
+    pub whirlpools_config: Option<Pubkey>,
+    pub min_collection_supply: Option<u64>,
+    pub max_collection_supply: Option<u64>,
+}
+
+impl ConfigUpdate {
+    // (1 + 4 + MAX_URL_LEN) * 2 (urls) + 9 (mod_stake_min) + 3 (fee_basis_points) + 33 * 2 (treasury, capgm_mint)
+    // + 3 (staker_split_bps) + 3 (creator_share_bps) + 33 * 3 (stablecoin_mint, stablecoin_pool, whirlpools_config)
+    // + 9 * 2 (min_collection_supply, max_collection_supply)
+    pub const MAX_SIZE: usize = (1 + 4 + MAX_URL_LEN) * 2 + 9 + 3 + 33 * 2 + 3 + 3 + 33 * 3 + 9 * 2;
+}
+
+/// Collection minting fields of update_global_state; None leaves a field unchanged
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub struct MintConfigUpdate {
+    pub creator_share_bps: Option<u16>,
+    pub min_collection_supply: Option<u64>,
+    pub max_collection_supply: Option<u64>,
+}
+
+/// Privileged changes that require multisig approval or a governance vote
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq, Debug)]
+pub enum AdminAction {
+    UpdateGlobalState(Box<ConfigUpdate>), // Queued behind the config timelock on multisig execution
+    ProposeAdmin { new_admin: Pubkey },
+    ChangeSigners { signers: Vec<Pubkey>, threshold: u8 }, // Multisig only
+    DisableGlobalStateUpdates,
+    SetPauseFlags { paused_flags: u8 },
+    SetGuardian { guardian: Option<Pubkey> },
+    // Admin-only instructions with their own accounts. Executing the proposal issues an
+    // AdminAuthorization that the matching instruction consumes in place of an admin signature.
+    InitializeTreasury,
+    CreateTreasuryBudget { mint: Pubkey, name: String, category: DisbursementCategory, allocated: u64 },
+    UpdateTreasuryBudget { budget: Pubkey, allocated: u64 },
+    DisburseFromTreasury { budget: Pubkey, recipient_token_account: Pubkey, amount: u64, memo: String },
+    InitializeGovernance { quorum_votes: u64, proposal_threshold: u64, voting_period: i64, execution_delay: i64 },
+    SlashModerator { moderator: Pubkey },
+    CancelConfigChange { queued_at: i64 }, // Identifies the PendingConfigChange by its queue time
+}
+
+impl AdminAction {
+    // 1 (variant) + largest variant (UpdateGlobalState)
+    pub const MAX_SIZE: usize = 1 + ConfigUpdate::MAX_SIZE;
+
+    /// Actions carried out by their own instruction rather than by the proposal executor
+    pub fn requires_authorization(&self) -> bool {
+        matches!(
+            self,
+            AdminAction::InitializeTreasury
+                | AdminAction::CreateTreasuryBudget { .. }
+                | AdminAction::UpdateTreasuryBudget { .. }
+                | AdminAction::DisburseFromTreasury { .. }
+                | AdminAction::InitializeGovernance { .. }
+                | AdminAction::SlashModerator { .. }
+                | AdminAction::CancelConfigChange { .. }
+        )
+    }
+}
+
+/// One-shot permission for an admin-only instruction, issued when a multisig or governance
+/// proposal executes while that PDA is GlobalState.admin. Closed by the instruction it authorizes.
+#[account]
+pub struct AdminAuthorization {
+    pub authorized_by: Pubkey, // Multisig or Governance PDA; must still be admin when consumed
+    pub proposal: Pubkey,      // AdminProposal or GovernanceProposal that issued it
+    pub action: AdminAction,   // Exact call the consuming instruction must match
+    pub issued_at: i64,
+    pub version: u8,
+    pub bump: u8,
+}
+
+impl AdminAuthorization {
+    pub const VERSION: u8 = 1;
+    // 8 (discriminator) + 32 (authorized_by) + 32 (proposal) + AdminAction::MAX_SIZE (action)
+    // + 8 (issued_at) + 1 (version) + 1 (bump)
+    pub const MAX_SIZE: usize = 8 + 32 + 32 + AdminAction::MAX_SIZE + 8 + 1 + 1;
+}
+
+#[account]
+pub struct PendingConfigChange {
+    pub proposer: Pubkey,     // Admin key (or multisig PDA) that queued the change
+    pub rent_payer: Pubkey,   // Refunded when the change is executed or cancelled
+    pub update: ConfigUpdate,
+    pub queued_at: i64,
+    pub execute_after: i64,   // Earliest timestamp execute_config_change may apply the update
+    pub version: u8,
+    pub bump: u8,
+}
+
+impl PendingConfigChange {
+    pub const VERSION: u8 = 1;
+    // 8 (discriminator) + 32 (proposer) + 32 (rent_payer) + ConfigUpdate::MAX_SIZE (update)
+    // + 8 (queued_at) + 8 (execute_after) + 1 (version) + 1 (bump)
+    pub const MAX_SIZE: usize = 8 + 32 + 32 + ConfigUpdate::MAX_SIZE + 8 + 8 + 1 + 1;
+}
+
+#[account]
+pub struct AdminProposal {
+    pub multisig: Pubkey,
+    pub proposal_id: u64,
+    pub proposer: Pubkey,
+    pub action: AdminAction,
+    pub approvals: Vec<Pubkey>, // Signers that approved (proposer approves implicitly)
+    pub executed: bool,
+    pub created_at: i64,
+    pub version: u8,
+    pub bump: u8,
+}
+
+impl AdminProposal {
+    pub const VERSION: u8 = 1;
+    // 8 (discriminator) + 32 (multisig) + 8 (proposal_id) + 32 (proposer) + AdminAction::MAX_SIZE (action)
+    // + 4 + MAX_ADMIN_SIGNERS * 32 (approvals) + 1 (executed) + 8 (created_at) + 1 (version) + 1 (bump)
+    pub const MAX_SIZE: usize = 8 + 32 + 8 + 32 + AdminAction::MAX_SIZE + 4 + MAX_ADMIN_SIGNERS * 32 + 1 + 8 + 1 + 1;
+}
+
+#[account]
+pub struct ProtocolTreasury {
+    pub payout_count: u64, // Monotonic counter used to derive TreasuryPayout PDAs
+    pub version: u8,
+    pub bump: u8,
+}
+
+impl ProtocolTreasury {
+    pub const VERSION: u8 = 1;
+    // 8 (discriminator) + 8 (payout_count) + 1 (version) + 1 (bump)
+    pub const MAX_SIZE: usize = 8 + 8 + 1 + 1;
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum DisbursementCategory {
+    Grant,
+    ModeratorRewards,
+    Operations,
+}
+
+#[account]
+pub struct TreasuryBudget {
+    pub mint: Pubkey,                   // Token this budget is denominated in
+    pub name: String,                   // Budget label (e.g., "q3-grants")
+    pub category: DisbursementCategory,
+    pub allocated: u64,                 // Approved spending cap
+    pub spent: u64,                     // Total disbursed against this budget
+    pub approved_by: Pubkey,            // Admin that created or last resized the budget
+    pub created_at: i64,
+    pub version: u8,
+    pub bump: u8,
+}
+
+impl TreasuryBudget {
+    pub const VERSION: u8 = 1;
+    // 8 (discriminator) + 32 (mint) + 4 + MAX_NAME_LEN (name) + 1 (category) + 8 (allocated)
+    // + 8 (spent) + 32 (approved_by) + 8 (created_at) + 1 (version) + 1 (bump)
+    pub const MAX_SIZE: usize = 8 + 32 + 4 + MAX_NAME_LEN + 1 + 8 + 8 + 32 + 8 + 1 + 1;
+}
+
+#[account]
+pub struct TreasuryPayout {
+    pub payout_id: u64,
+    pub budget: Pubkey,
+    pub mint: Pubkey,
+    pub recipient: Pubkey,              // Owner of the receiving token account
+    pub amount: u64,
+    pub category: DisbursementCategory,
+    pub memo: String,                   // Purpose of the payout (e.g., grant proposal reference)
+    pub approved_by: Pubkey,
+    pub paid_at: i64,
+    pub version: u8,
+    pub bump: u8,
+}
+
+impl TreasuryPayout {
+    pub const VERSION: u8 = 1;
+    // 8 (discriminator) + 8 (payout_id) + 32 (budget) + 32 (mint) + 32 (recipient) + 8 (amount)
+    // + 1 (category) + 4 + MAX_REASON_LEN (memo) + 32 (approved_by) + 8 (paid_at) + 1 (version) + 1 (bump)
+    pub const MAX_SIZE: usize = 8 + 8 + 32 + 32 + 32 + 8 + 1 + 4 + MAX_REASON_LEN + 32 + 8 + 1 + 1;
+}
+
+#[account]
+pub struct Governance {
+    pub capgm_mint: Pubkey,       // Voting token; fixed at initialization
+    pub quorum_votes: u64,        // Minimum votes in favour for a proposal to pass
+    pub proposal_threshold: u64,  // Locked CAPGM required to create a proposal
+    pub voting_period: i64,       // Seconds a proposal accepts votes
+    pub execution_delay: i64,     // Timelock between the end of voting and execution
+    pub proposal_count: u64,      // Monotonic counter used to derive proposal PDAs
+    pub total_locked: u64,        // CAPGM currently held in the governance vault
+    pub version: u8,
+    pub bump: u8,
+}
+
+impl Governance {
+    pub const VERSION: u8 = 1;
+    // 8 (discriminator) + 32 (capgm_mint) + 8 (quorum_votes) + 8 (proposal_threshold)
+    // + 8 (voting_period) + 8 (execution_delay) + 8 (proposal_count) + 8 (total_locked) + 1 (version) + 1 (bump)
+    pub const MAX_SIZE: usize = 8 + 32 + 8 + 8 + 8 + 8 + 8 + 8 + 1 + 1;
+}
+
+#[account]
+pub struct VoterLock {
+    pub owner: Pubkey,
+    pub amount: u64,        // Locked CAPGM; this is the voter's weight
+    pub locked_until: i64,  // End of the latest voting period this lock has voted in
+    pub version: u8,
+    pub bump: u8,
+}
+
+impl VoterLock {
+    pub const VERSION: u8 = 1;
+    // 8 (discriminator) + 32 (owner) + 8 (amount) + 8 (locked_until) + 1 (version) + 1 (bump)
+    pub const MAX_SIZE: usize = 8 + 32 + 8 + 8 + 1 + 1;
+}
+
+#[account]
+pub struct GovernanceProposal {
+    pub proposal_id: u64,
+    pub proposer: Pubkey,
+    pub action: AdminAction,    // Admin action carried out if the proposal passes
+    pub votes_for: u64,
+    pub votes_against: u64,
+    pub created_at: i64,
+    pub voting_ends_at: i64,
+    pub execute_after: i64,     // voting_ends_at + Governance.execution_delay
+    pub executed: bool,
+    pub version: u8,
+    pub bump: u8,
+}
+
+impl GovernanceProposal {
+    pub const VERSION: u8 = 1;
+    // 8 (discriminator) + 8 (proposal_id) + 32 (proposer) + AdminAction::MAX_SIZE (action)
+    // + 8 (votes_for) + 8 (votes_against) + 8 (created_at) + 8 (voting_ends_at)
+    // + 8 (execute_after) + 1 (executed) + 1 (version) + 1 (bump)
+    pub const MAX_SIZE: usize = 8 + 8 + 32 + AdminAction::MAX_SIZE + 8 + 8 + 8 + 8 + 8 + 1 + 1 + 1;
+
+    pub fn has_passed(&self, quorum_votes: u64) -> bool {
+        self.votes_for >= quorum_votes && self.votes_for > self.votes_against
+    }
+}
+
+#[account]
+pub struct VoteRecord {
+    pub proposal: Pubkey,
+    pub voter: Pubkey,
+    pub weight: u64,
+    pub support: bool,
+    pub version: u8,
+    pub bump: u8,
+}
+
+impl VoteRecord {
+    pub const VERSION: u8 = 1;
+    // 8 (discriminator) + 32 (proposal) + 32 (voter) + 8 (weight) + 1 (support) + 1 (version) + 1 (bump)
+    pub const MAX_SIZE: usize = 8 + 32 + 32 + 8 + 1 + 1 + 1;
 }
 
 #[account]
@@ -30,14 +344,17 @@ pub struct UserAccount {
     pub authority: Pubkey,
     pub ipns_key: String,   // IPNS key pointing to off-chain metadata (Avatar, Bio)
     pub is_online: bool,
+    pub version: u8,
     pub bump: u8,
 }
 
 impl UserAccount {
-    pub const MAX_SIZE: usize = 8 + 32 + MAX_IPNS_KEY_LEN + 1 + 1;
+    pub const VERSION: u8 = 1;
+    pub const MAX_SIZE: usize = 8 + 32 + MAX_IPNS_KEY_LEN + 1 + 1 + 1;
 }
 
 #[account]
+#[derive(Default)]
 pub struct CollectionState {
     pub owner: Pubkey,      // Collection owner (matches design)
     pub collection_id: String, // Unique slug (e.g., "cooking-101")
@@ -49,40 +366,140 @@ pub struct CollectionState {
     pub total_trust_score: u64, // Aggregate reliability of this collection's swarm
     pub is_blacklisted: bool,  // Moderator toggle for illegal content
     pub name: String,
-    pub content_cid: String,   // IPFS CID - DEPRECATED: Use cid_hash for privacy
     pub access_threshold_usd: u64, // In USD cents (e.g. 1000 = $10.00)
     pub oracle_feed: Pubkey,   // Price feed for this specific Collection Token
     
     // Reward Logic
-    pub owner_reward_balance: u64, // Accumulated 20% fees for Owner
+    pub owner_reward_balance: u64, // Harvested collection token LP fees owed to the owner (held in reserve A)
     pub staker_reward_balance: u64,   // Accumulated 10% fees for CAPGM Stakers
     pub tokens_minted: bool,          // Whether collection tokens have been minted (one-time operation)
     
     // Proportional Copyright Claims
     pub total_videos: u16,                // Total videos in collection (e.g., 10)
     pub claim_vault_initial_amount: u64,  // The original amount reserved for claims (set during mint)
-    pub claimed_bitmap: Vec<u8>,          // Bitmask: 1 = claimed, 0 = unclaimed
+    pub claimed_share_bps: Vec<u16>,      // Per-video claimed share in basis points (10000 = fully claimed)
     pub censored_bitmap: Vec<u8>,         // Bitmask: 1 = censored, 0 = active
     
     // Configurable Shares
-    pub claim_share_percent: u8, // Percentage of supply reserved for claim vault (default 10)
+    pub claim_share_bps: u16, // Share of supply reserved for claim vault (default 1000 = 10%)
+
+    // Censorship Refunds
+    pub total_purchases: u64, // Number of purchase_access calls (denominator for pro-rata refunds)
+    pub creator_bond: u64,    // Unreserved collection tokens held in the creator bond vault
+    pub refund_reserved_bitmap: Vec<u8>, // Bitmask: 1 = a censorship refund was already reserved for this video
+
+    // Fee Schedule Overrides (None = use GlobalState values)
+    pub staker_split_override_bps: Option<u16>,
+    pub creator_share_override_bps: Option<u16>,
+    pub creator_royalty_bps: u16, // Share of post-fee purchases paid directly to the owner (0 = none)
+
+    // Manifest History
+    pub manifest_version: u32,             // Incremented on every cid_hash change (0 = original manifest)
+    pub previous_cid_hashes: Vec<[u8; 32]>, // Last MAX_CID_HISTORY superseded cid_hash values, oldest first
 
+    // Orca Liquidity
+    pub lp_fee_staker_bps: u16,           // Share of harvested LP fees sent to stakers (fixed once tokens are staked)
+    pub owner_capgm_reward_balance: u64,  // Harvested CAPGM fees owed to the owner (held in reserve B)
+    pub staker_capgm_reward_balance: u64, // Harvested CAPGM fees owed to stakers, not yet claimed (held in reserve B)
+    pub last_rebalance_at: i64,           // Last decrease_orca_liquidity (starts the rebalance cooldown)
+    pub position_mint: Pubkey,            // NFT mint of the live Whirlpool position (default while none is open)
+    pub launch_token_deposit: u64,        // Collection tokens paired at launch (with creator_capgm_deposit, basis of the LP floor)
+    pub creator_capgm_deposit: u64,       // CAPGM the creator paired at launch; vests back via claim_vested_liquidity
+    pub creator_capgm_withdrawn: u64,     // CAPGM already returned through claim_vested_liquidity
+    pub liquidity_locked_at: i64,         // Launch deposit time (start of the LP lock period)
+
+    // Creator Vesting (creator share of supply held in the creator_vesting vault)
+    pub creator_vesting_cliff_seconds: i64,    // Nothing unlocks before start + cliff
+    pub creator_vesting_duration_seconds: i64, // Fully unlocked at start + duration (linear)
+    pub creator_vesting_start: i64,            // Set by mint_collection_tokens
+    pub creator_vesting_total: u64,            // Creator share minted into the vault
+    pub creator_vesting_claimed: u64,          // Already released through claim_creator_vesting
+
+    // Launch
+    pub launch_stage: LaunchStage, // Purchases open once Launched
+
+    pub version: u8,
     pub bump: u8,
 }
 
 impl CollectionState {
+    pub const VERSION: u8 = 1;
     // 8 (discriminator) + 32 (owner) + MAX_ID_LEN (collection_id) + 32 (cid_hash) + 32 (mint) + 32 (pool_address) 
     // + 32 (claim_vault) + 8 (claim_deadline) + 8 (total_trust_score) + 1 (is_blacklisted) + MAX_NAME_LEN (name)
-    // + MAX_URL_LEN (content_cid) + 8 (access_threshold_usd) + 32 (oracle_feed)
+    // + 8 (access_threshold_usd) + 32 (oracle_feed)
     // + 8 (owner_reward_balance) + 8 (staker_reward_balance)
     // + 1 (tokens_minted) + 2 (total_videos) + 8 (claim_vault_initial_amount)
-    // + 4 (claimed_bitmap length) + 4 (censored_bitmap length)
-    // + 1 (claim_share_percent)
-    // + 1 (bump)
-    // Note: Bitmap vectors are variable-length and space is calculated dynamically in create_collection
-    pub const BASE_SIZE: usize = 8 + 32 + MAX_ID_LEN + 32 + 32 + 32 + 32 + 8 + 8 + 1 + MAX_NAME_LEN + MAX_URL_LEN + 8 + 32 + 8 + 8 + 1 + 2 + 8 + 4 + 4 + 1 + 1;
-    // Legacy MAX_SIZE kept for backward compatibility, but actual space calculation is done dynamically
-    pub const MAX_SIZE: usize = BASE_SIZE;
+    // + 4 (claimed_share_bps length) + 4 (censored_bitmap length)
+    // + 2 (claim_share_bps)
+    // + 8 (total_purchases) + 8 (creator_bond) + 4 (refund_reserved_bitmap length)
+    // + 3 (staker_split_override_bps) + 3 (creator_share_override_bps) + 2 (creator_royalty_bps)
+    // + 4 (manifest_version) + 4 + 32 * MAX_CID_HISTORY (previous_cid_hashes)
+    // + 2 (lp_fee_staker_bps) + 8 (owner_capgm_reward_balance) + 8 (staker_capgm_reward_balance) + 8 (last_rebalance_at)
+    // + 32 (position_mint) + 8 (launch_token_deposit) + 8 (creator_capgm_deposit) + 8 (creator_capgm_withdrawn) + 8 (liquidity_locked_at)
+    // + 8 (creator_vesting_cliff_seconds) + 8 (creator_vesting_duration_seconds) + 8 (creator_vesting_start)
+    // + 8 (creator_vesting_total) + 8 (creator_vesting_claimed)
+    // + 1 (launch_stage)
+    // + 1 (version) + 1 (bump)
+    // Note: claimed_share_bps (2 bytes per video), censored_bitmap and refund_reserved_bitmap
+    // (1 bit per video each) are variable-length; use CollectionState::space for the full account size
+    pub const BASE_SIZE: usize = 8 + 32 + MAX_ID_LEN + 32 + 32 + 32 + 32 + 8 + 8 + 1 + MAX_NAME_LEN + 8 + 32 + 8 + 8 + 1 + 2 + 8 + 4 + 4 + 2 + 8 + 8 + 4 + 3 + 3 + 2 + 4 + 4 + 32 * MAX_CID_HISTORY + 2 + 8 + 8 + 8 + 32 + 8 + 8 + 8 + 8 + 8 + 8 + 8 + 8 + 8 + 1 + 1 + 1;
+
+    /// Account size for a collection with `total_videos` videos: base size + 2 bytes per
+    /// video for claimed shares + ceil(total_videos / 8) each for the censored and refund bitmaps
+    pub fn space(total_videos: u16) -> usize {
+        Self::BASE_SIZE + total_videos as usize * 2 + (total_videos as usize).div_ceil(8) * 2
+    }
+
+    /// Creator allocation unlocked at `now`: nothing before the cliff, then linear
+    /// from creator_vesting_start until creator_vesting_duration_seconds have passed
+    pub fn creator_vested_amount(&self, now: i64) -> Result<u64> {
+        let elapsed = now.saturating_sub(self.creator_vesting_start);
+        if elapsed < self.creator_vesting_cliff_seconds {
+            return Ok(0);
+        }
+        if elapsed >= self.creator_vesting_duration_seconds {
+            return Ok(self.creator_vesting_total);
+        }
+        let vested = (self.creator_vesting_total as u128)
+            .checked_mul(elapsed as u128)
+            .ok_or(crate::errors::ProtocolError::MathOverflow)?
+            / self.creator_vesting_duration_seconds as u128;
+        u64::try_from(vested).map_err(|_| crate::errors::ProtocolError::MathOverflow.into())
+    }
+}
+
+/// Collection launch progress. Each launch instruction requires the previous stage,
+/// so a launch interrupted partway through can be resumed or aborted cleanly.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub enum LaunchStage {
+    #[default]
+    Created,         // create_collection
+    TokensMinted,    // mint_collection_tokens (liquidity share staged in the reserve)
+    PoolInitialized, // initialize_orca_pool
+    PositionOpened,  // open_orca_position
+    Launched,        // deposit_liquidity_to_orca; purchases enabled
+    Aborted,         // abort_launch; reserve burned, collection closed to purchases
+}
+
+/// Token options chosen at create_collection; None uses the protocol default
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub struct CollectionTokenConfig {
+    pub claim_share_bps: Option<u16>,                 // Claim vault share of supply (default DEFAULT_CLAIM_SHARE_BPS)
+    pub decimals: Option<u8>,                          // Mint decimals, up to MAX_COLLECTION_DECIMALS (default 6)
+    pub creator_vesting_cliff_seconds: Option<i64>,    // Default DEFAULT_CREATOR_VESTING_CLIFF_SECONDS
+    pub creator_vesting_duration_seconds: Option<i64>, // Default DEFAULT_CREATOR_VESTING_DURATION_SECONDS
+}
+
+/// Owner-editable collection parameters; only provided fields are updated
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq, Debug, Default)]
+pub struct CollectionUpdate {
+    pub cid_hash: Option<[u8; 32]>,       // New manifest hash; the old one moves to previous_cid_hashes
+    pub access_threshold_usd: Option<u64>,
+    pub oracle_feed: Option<Pubkey>,
+    pub total_videos: Option<u16>,        // May only grow; the account is reallocated to fit
+    pub staker_split_bps: Option<u16>,    // Share of post-fee purchases sent to stakers
+    pub creator_royalty_bps: Option<u16>, // Share of post-fee purchases paid to the owner
+    pub lp_fee_staker_bps: Option<u16>,   // Share of harvested LP fees sent to stakers
 }
 
 #[account]
@@ -94,11 +511,13 @@ pub struct AccessEscrow {
     pub amount_locked: u64,       // Tokens (50% of purchase), waiting for release to peers
     pub created_at: i64,          // Timestamp for 24-hour burn timeout logic
     pub is_cid_revealed: bool,    // Whether a pinner has revealed the CID
+    pub version: u8,
     pub bump: u8,
 }
 
 impl AccessEscrow {
-    pub const MAX_SIZE: usize = 8 + 32 + 32 + 32 + 32 + 8 + 8 + 1 + 1;
+    pub const VERSION: u8 = 1;
+    pub const MAX_SIZE: usize = 8 + 32 + 32 + 32 + 32 + 8 + 8 + 1 + 1 + 1;
 }
 
 #[account]
@@ -107,12 +526,14 @@ pub struct CidReveal {
     pub pinner: Pubkey,              // The peer who revealed the CID (must be a registered pinner)
     pub encrypted_cid: Vec<u8>,      // CID encrypted with purchaser's public key (X25519-XSalsa20-Poly1305)
     pub revealed_at: i64,            // Timestamp of reveal
+    pub version: u8,
     pub bump: u8,
 }
 
 impl CidReveal {
-    // 8 (discriminator) + 32 (escrow) + 32 (pinner) + 4 (vec length) + 200 (encrypted CID, typically ~100 bytes) + 8 (timestamp) + 1 (bump)
-    pub const MAX_SIZE: usize = 8 + 32 + 32 + 4 + 200 + 8 + 1;
+    pub const VERSION: u8 = 1;
+    // 8 (discriminator) + 32 (escrow) + 32 (pinner) + 4 (vec length) + 200 (encrypted CID, typically ~100 bytes) + 8 (timestamp) + 1 (version) + 1 (bump)
+    pub const MAX_SIZE: usize = 8 + 32 + 32 + 4 + 200 + 8 + 1 + 1;
 }
 
 #[account]
@@ -121,10 +542,12 @@ pub struct PeerTrustState {
     pub total_successful_serves: u64, // Total number of released escrows
     pub trust_score: u64,             // Weighted score (Serves * Consistency)
     pub last_active: i64,             // For pruning inactive nodes
+    pub version: u8,
 }
 
 impl PeerTrustState {
-    pub const MAX_SIZE: usize = 8 + 32 + 8 + 8 + 8;
+    pub const VERSION: u8 = 1;
+    pub const MAX_SIZE: usize = 8 + 32 + 8 + 8 + 8 + 1;
 }
 
 #[account]
@@ -132,6 +555,13 @@ pub struct PinnerState {
     pub collection: Pubkey,
     pub pinner: Pubkey,
     pub is_active: bool,
+    pub version: u8,
+}
+
+impl PinnerState {
+    pub const VERSION: u8 = 1;
+    // 8 (discriminator) + 32 (collection) + 32 (pinner) + 1 (is_active) + 1 (version)
+    pub const MAX_SIZE: usize = 8 + 32 + 32 + 1 + 1;
 }
 
 #[account]
@@ -145,18 +575,78 @@ pub struct ModTicket {
     pub resolver: Option<Pubkey>, // Moderator who resolved it
     pub created_at: i64,        // Unix timestamp when the ticket was created
     pub claim_indices: Vec<u16>, // Specific video indices being claimed (e.g., [0, 3, 5])
+    pub claim_shares_bps: Vec<u16>, // Share of each claimed video's allocation, in basis points (parallel to claim_indices)
+    pub collection: Pubkey,      // Target collection (set at creation for CopyrightClaim, at resolution for CidCensorship)
+    pub evidence_hash: [u8; 32], // SHA-256 hash of the reporter's off-chain evidence CID
+    pub counter_notice_hash: [u8; 32], // SHA-256 hash of the owner's counter-notice evidence CID
+    pub counter_notice_at: i64,  // Timestamp of the counter-notice (0 = none submitted)
+    pub assigned_moderators: Vec<Pubkey>, // Randomly assigned resolvers (never empty)
+    pub assigned_at: i64,        // Timestamp of the latest (re)assignment
+    pub refund_per_purchase: u64,      // CidCensorship: tokens paid per purchaser claim (0 = no refund)
+    pub refund_claims_remaining: u64,  // CidCensorship: claims left before the reserved budget is exhausted
+    pub refund_purchase_cutoff: u64,   // CidCensorship: only purchases with purchase_index below this may claim
+    pub reported_operator: Pubkey,     // ServiceEndpointReport: operator of the reported endpoint
+    pub reported_service: Option<ServiceType>, // ServiceEndpointReport: service type of the reported endpoint
+    pub version: u8,
     pub bump: u8,
 }
 
 impl ModTicket {
+    pub const VERSION: u8 = 1;
     // 8 (discriminator) + 32 (reporter) + MAX_ID_LEN (target_id) + 1 (ticket_type) + MAX_REASON_LEN (reason)
     // + 1 (resolved) + 1 (verdict) + 33 (resolver Option<Pubkey>) + 8 (created_at)
     // + 4 (claim_indices length) + variable (claim_indices Vec<u16>)
-    // + 1 (bump)
-    // Note: claim_indices vector is variable-length and space is calculated dynamically in create_ticket
-    pub const BASE_SIZE: usize = 8 + 32 + MAX_ID_LEN + 1 + MAX_REASON_LEN + 1 + 1 + 33 + 8 + 4 + 1;
+    // + 4 (claim_shares_bps length) + variable (claim_shares_bps Vec<u16>)
+    // + 32 (collection) + 32 (evidence_hash) + 32 (counter_notice_hash) + 8 (counter_notice_at)
+    // + 4 + MODERATORS_PER_TICKET * 32 (assigned_moderators) + 8 (assigned_at)
+    // + 8 (refund_per_purchase) + 8 (refund_claims_remaining) + 8 (refund_purchase_cutoff)
+    // + 32 (reported_operator) + 2 (reported_service) + 1 (version) + 1 (bump)
+    // Note: claim vectors are variable-length and space is calculated dynamically in create_ticket
+    pub const BASE_SIZE: usize = 8 + 32 + MAX_ID_LEN + 1 + MAX_REASON_LEN + 1 + 1 + 33 + 8 + 4 + 4 + 32 + 32 + 32 + 8
+        + 4 + crate::constants::MODERATORS_PER_TICKET * 32 + 8 + 8 + 8 + 8 + 32 + 2 + 1 + 1;
     // Legacy MAX_SIZE kept for backward compatibility, but actual space calculation is done dynamically
-    pub const MAX_SIZE: usize = BASE_SIZE + 64; // Assume max ~32 video indices (64 bytes) for default
+    pub const MAX_SIZE: usize = Self::BASE_SIZE + MAX_CLAIM_INDICES * 2 * 2; // Max indices + matching shares
+
+    /// Whether `moderator` is one of the resolvers drawn for this ticket
+    pub fn is_assigned_to(&self, moderator: &Pubkey) -> bool {
+        self.assigned_moderators.contains(moderator)
+    }
+}
+
+/// Permanent record of one purchase_access call, keyed by its Access NFT. Unlike the
+/// AccessEscrow it outlives escrow release, so it proves when the purchase happened.
+#[account]
+pub struct PurchaseRecord {
+    pub collection: Pubkey,
+    pub purchaser: Pubkey,
+    pub access_nft_mint: Pubkey,
+    pub purchase_index: u64,     // CollectionState.total_purchases before this purchase
+    pub purchased_at: i64,
+    pub version: u8,
+    pub bump: u8,
+}
+
+impl PurchaseRecord {
+    pub const VERSION: u8 = 1;
+    // 8 (discriminator) + 32 (collection) + 32 (purchaser) + 32 (access_nft_mint)
+    // + 8 (purchase_index) + 8 (purchased_at) + 1 (version) + 1 (bump)
+    pub const MAX_SIZE: usize = 8 + 32 + 32 + 32 + 8 + 8 + 1 + 1;
+}
+
+#[account]
+pub struct CensorshipRefundReceipt {
+    pub ticket: Pubkey,          // The CidCensorship ticket the refund was paid from
+    pub claimant: Pubkey,        // Purchaser who received the refund
+    pub access_nft_mint: Pubkey, // Access NFT used as proof of purchase (one claim per NFT)
+    pub amount: u64,             // Collection tokens refunded
+    pub claimed_at: i64,
+    pub version: u8,
+    pub bump: u8,
+}
+
+impl CensorshipRefundReceipt {
+    pub const VERSION: u8 = 1;
+    pub const MAX_SIZE: usize = 8 + 32 + 32 + 32 + 8 + 8 + 1 + 1;
 }
 
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
@@ -164,6 +654,20 @@ pub enum TicketType {
     ContentReport,   // Flagging illegal or TOS-violating content
     CopyrightClaim, // IP disputes - transfers proportional Claim Vault tokens to claimant
     CidCensorship,   // Censoring specific CIDs - reimburses stakeholders from collection pools
+    ServiceEndpointReport, // Misbehaving indexer/registry endpoint - forfeits the operator's bond
+}
+
+impl TicketType {
+    /// Specialization a moderator must have registered to resolve this ticket type
+    pub fn required_specialization(&self) -> u8 {
+        match self {
+            TicketType::ContentReport => crate::constants::SPECIALIZATION_ILLEGAL_CONTENT,
+            TicketType::CopyrightClaim => crate::constants::SPECIALIZATION_COPYRIGHT,
+            TicketType::CidCensorship => crate::constants::SPECIALIZATION_CID_CENSORSHIP,
+            // Endpoints are reported for serving censored or forged data
+            TicketType::ServiceEndpointReport => crate::constants::SPECIALIZATION_CID_CENSORSHIP,
+        }
+    }
 }
 
 #[account]
@@ -172,11 +676,119 @@ pub struct ModeratorStake {
     pub stake_amount: u64,      // Amount of CAPGM staked
     pub is_active: bool,
     pub slash_count: u32,      // Number of times slashed
+    pub specializations: u8,   // Bitflags of SPECIALIZATION_* ticket types this moderator may resolve
+    pub jurisdictions: Vec<[u8; 2]>, // Optional ISO 3166-1 alpha-2 codes (e.g. b"US"), informational
+    pub version: u8,
     pub bump: u8,
 }
 
 impl ModeratorStake {
-    pub const MAX_SIZE: usize = 8 + 32 + 8 + 1 + 4 + 1;
+    pub const VERSION: u8 = 1;
+    // 8 (discriminator) + 32 (moderator) + 8 (stake_amount) + 1 (is_active) + 4 (slash_count)
+    // + 1 (specializations) + 4 + MAX_JURISDICTIONS * 2 (jurisdictions) + 1 (version) + 1 (bump)
+    pub const MAX_SIZE: usize = 8 + 32 + 8 + 1 + 4 + 1 + 4 + MAX_JURISDICTIONS * 2 + 1 + 1;
+
+    pub fn can_resolve(&self, ticket_type: TicketType) -> bool {
+        self.specializations & ticket_type.required_specialization() != 0
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub struct ModeratorEntry {
+    pub moderator: Pubkey,
+    pub stake_amount: u64,    // Mirrors ModeratorStake.stake_amount (selection weight)
+    pub specializations: u8,  // Mirrors ModeratorStake.specializations
+    pub is_active: bool,      // Mirrors ModeratorStake.is_active; inactive entries are never drawn
+}
+
+impl ModeratorEntry {
+    pub const SIZE: usize = 32 + 8 + 1 + 1;
+}
+
+#[account]
+pub struct ModeratorRegistry {
+    pub moderators: Vec<ModeratorEntry>, // Active moderators eligible for random ticket assignment
+    pub version: u8,
+    pub bump: u8,
+}
+
+impl ModeratorRegistry {
+    pub const VERSION: u8 = 1;
+    // 8 (discriminator) + 4 (vec length) + MAX_REGISTERED_MODERATORS * ModeratorEntry::SIZE + 1 (version) + 1 (bump)
+    pub const MAX_SIZE: usize = 8 + 4 + MAX_REGISTERED_MODERATORS * ModeratorEntry::SIZE + 1 + 1;
+
+    /// Insert or refresh a moderator's entry. When the registry is full, the lowest-staked
+    /// entry is evicted if the newcomer outstakes it; returns the evicted moderator, if any.
+    /// Evicted moderators keep their stake and re-enter by staking more.
+    pub fn upsert(&mut self, entry: ModeratorEntry) -> Result<Option<Pubkey>> {
+        if let Some(existing) = self.moderators.iter_mut().find(|e| e.moderator == entry.moderator) {
+            *existing = entry;
+            return Ok(None);
+        }
+        if self.moderators.len() < MAX_REGISTERED_MODERATORS {
+            self.moderators.push(entry);
+            return Ok(None);
+        }
+        let (lowest, lowest_stake) = self.moderators
+            .iter()
+            .enumerate()
+            .map(|(i, e)| (i, if e.is_active { e.stake_amount } else { 0 }))
+            .min_by_key(|&(_, stake)| stake)
+            .ok_or(crate::errors::ProtocolError::ModeratorRegistryFull)?;
+        require!(
+            entry.stake_amount > lowest_stake,
+            crate::errors::ProtocolError::ModeratorRegistryFull
+        );
+        let evicted = std::mem::replace(&mut self.moderators[lowest], entry);
+        Ok(Some(evicted.moderator))
+    }
+
+    pub fn remove(&mut self, moderator: &Pubkey) {
+        self.moderators.retain(|e| e.moderator != *moderator);
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum ServiceType {
+    Indexer,      // Off-chain indexer API (GlobalState.indexer_api_url is the default)
+    NodeRegistry, // Node registry (GlobalState.node_registry_url is the default)
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq, Debug)]
+pub struct ServiceEndpoint {
+    pub operator: Pubkey,
+    pub service_type: ServiceType,
+    pub url: String,
+    pub bond: u64,          // CAPGM held in the registry bond vault for this endpoint
+    pub registered_at: i64,
+    pub unbonding_since: i64, // 0 = listed; otherwise deregistered and awaiting withdraw_service_bond
+}
+
+impl ServiceEndpoint {
+    // 32 (operator) + 1 (service_type) + 4 + MAX_URL_LEN (url) + 8 (bond) + 8 (registered_at)
+    // + 8 (unbonding_since)
+    pub const MAX_SIZE: usize = 32 + 1 + 4 + MAX_URL_LEN + 8 + 8 + 8;
+}
+
+#[account]
+pub struct ServiceRegistry {
+    pub endpoints: Vec<ServiceEndpoint>, // Bonded endpoints clients may choose from
+    pub version: u8,
+    pub bump: u8,
+}
+
+impl ServiceRegistry {
+    pub const VERSION: u8 = 1;
+    // 8 (discriminator) + 4 (vec length) + MAX_SERVICE_ENDPOINTS * ServiceEndpoint::MAX_SIZE
+    // + 1 (version) + 1 (bump)
+    pub const MAX_SIZE: usize = 8 + 4 + MAX_SERVICE_ENDPOINTS * ServiceEndpoint::MAX_SIZE + 1 + 1;
+
+    /// Index of an operator's endpoint for a service type (one per operator per type)
+    pub fn position(&self, operator: &Pubkey, service_type: ServiceType) -> Option<usize> {
+        self.endpoints
+            .iter()
+            .position(|e| e.operator == *operator && e.service_type == service_type)
+    }
 }
 
 #[account]
@@ -184,11 +796,14 @@ pub struct CollectionStakingPool {
     pub collection: Pubkey,           // The collection this pool is for
     pub total_staked: u64,            // Total collection tokens staked in this pool
     pub reward_per_token: u128,       // Accumulated rewards per token (scaled by REWARD_PRECISION)
+    pub capgm_reward_per_token: u128, // Accumulated CAPGM LP fee rewards per token (scaled by REWARD_PRECISION)
+    pub version: u8,
     pub bump: u8,
 }
 
 impl CollectionStakingPool {
-    pub const MAX_SIZE: usize = 8 + 32 + 8 + 16 + 1;
+    pub const VERSION: u8 = 1;
+    pub const MAX_SIZE: usize = 8 + 32 + 8 + 16 + 16 + 1 + 1;
 }
 
 #[account]
@@ -197,9 +812,603 @@ pub struct StakerPosition {
     pub collection: Pubkey,           // The collection being staked
     pub amount_staked: u64,           // Number of collection tokens staked
     pub reward_debt: u128,            // Used to calculate pending rewards (scaled by REWARD_PRECISION)
+    pub capgm_reward_debt: u128,      // Same, against capgm_reward_per_token
+    pub capgm_rewards_owed: u64,      // CAPGM settled on stake changes, paid by claim_staking_capgm_rewards
+    pub version: u8,
     pub bump: u8,
 }
 
 impl StakerPosition {
-    pub const MAX_SIZE: usize = 8 + 32 + 32 + 8 + 16 + 1;
+    pub const VERSION: u8 = 1;
+    pub const MAX_SIZE: usize = 8 + 32 + 32 + 8 + 16 + 16 + 8 + 1 + 1;
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default, PartialEq, Eq, Debug)]
+pub struct PriceSample {
+    pub timestamp: i64,
+    pub slot: u64,
+    pub sqrt_price_cumulative: u128, // Sum of sqrt_price_x64 * seconds held (wrapping)
+}
+
+impl PriceSample {
+    pub const SIZE: usize = 8 + 8 + 16;
+}
+
+/// Ring buffer of cumulative Whirlpool sqrt prices, one per pool (see record_price_observation)
+#[account]
+pub struct PriceObservation {
+    pub whirlpool: Pubkey,
+    pub decimals_a: u8,               // Whirlpool token_mint_a decimals
+    pub decimals_b: u8,               // Whirlpool token_mint_b decimals
+    pub last_sqrt_price: u128,        // sqrt_price_x64 read by the latest crank, clamped to PRICE_OBSERVATION_MAX_STEP_BPS
+    pub samples: [PriceSample; PRICE_OBSERVATION_SLOTS],
+    pub head: u8,                     // Index of the latest sample
+    pub count: u8,                    // Filled slots (<= PRICE_OBSERVATION_SLOTS)
+    pub version: u8,
+    pub bump: u8,
+}
+
+impl PriceObservation {
+    pub const VERSION: u8 = 1;
+    // 8 (discriminator) + 32 (whirlpool) + 1 + 1 (decimals) + 16 (last_sqrt_price)
+    // + PRICE_OBSERVATION_SLOTS * PriceSample::SIZE (samples) + 1 (head) + 1 (count) + 1 (version) + 1 (bump)
+    pub const MAX_SIZE: usize = 8 + 32 + 1 + 1 + 16 + PRICE_OBSERVATION_SLOTS * PriceSample::SIZE + 1 + 1 + 1 + 1;
+
+    pub fn latest(&self) -> PriceSample {
+        self.samples[self.head as usize]
+    }
+
+    /// Oldest sample still in the buffer
+    pub fn oldest(&self) -> PriceSample {
+        if (self.count as usize) < PRICE_OBSERVATION_SLOTS {
+            self.samples[0]
+        } else {
+            self.samples[(self.head as usize + 1) % PRICE_OBSERVATION_SLOTS]
+        }
+    }
+
+    /// Accrue last_sqrt_price up to `now` and store `sqrt_price`, moved at most
+    /// PRICE_OBSERVATION_MAX_STEP_BPS from the previous price, as the new latest price.
+    /// Fails within PRICE_OBSERVATION_INTERVAL_SECONDS of the latest sample or in its slot.
+    pub fn record(&mut self, now: i64, slot: u64, sqrt_price: u128) -> Result<()> {
+        let latest = self.latest();
+        require!(
+            now.saturating_sub(latest.timestamp) >= crate::constants::PRICE_OBSERVATION_INTERVAL_SECONDS
+                && slot > latest.slot,
+            crate::errors::ProtocolError::ObservationTooRecent
+        );
+        let elapsed = now.saturating_sub(latest.timestamp).max(0) as u128;
+        let sample = PriceSample {
+            timestamp: now,
+            slot,
+            sqrt_price_cumulative: latest
+                .sqrt_price_cumulative
+                .wrapping_add(self.last_sqrt_price.wrapping_mul(elapsed)),
+        };
+        self.head = ((self.head as usize + 1) % PRICE_OBSERVATION_SLOTS) as u8;
+        self.samples[self.head as usize] = sample;
+        self.count = (self.count + 1).min(PRICE_OBSERVATION_SLOTS as u8);
+
+        let max_step = self.last_sqrt_price
+            .saturating_mul(crate::constants::PRICE_OBSERVATION_MAX_STEP_BPS)
+            / 10000;
+        self.last_sqrt_price = sqrt_price.clamp(
+            self.last_sqrt_price.saturating_sub(max_step),
+            self.last_sqrt_price.saturating_add(max_step),
+        );
+        Ok(())
+    }
+
+    /// Time-weighted average sqrt_price_x64 across the buffer. Fails unless the latest
+    /// sample is fresh, the buffer holds TWAP_MIN_SAMPLES and spans at least
+    /// TWAP_MIN_WINDOW_SECONDS.
+    pub fn twap_sqrt_price(&self, now: i64) -> Result<u128> {
+        let latest = self.latest();
+        let oldest = self.oldest();
+        let window = latest.timestamp.saturating_sub(oldest.timestamp);
+        require!(
+            now.saturating_sub(latest.timestamp) <= crate::constants::PRICE_OBSERVATION_MAX_AGE_SECONDS
+                && self.count >= crate::constants::TWAP_MIN_SAMPLES
+                && window >= crate::constants::TWAP_MIN_WINDOW_SECONDS,
+            crate::errors::ProtocolError::InvalidOraclePrice
+        );
+        Ok(latest.sqrt_price_cumulative.wrapping_sub(oldest.sqrt_price_cumulative) / window as u128)
+    }
+}
+
+// ============================================================================
+// Legacy Layouts (version 0)
+// ============================================================================
+//
+// Accounts written before the version byte existed, copied field for field from the
+// deployed layout. VERSION 1 is the first versioned layout and covers every change
+// shipped with it, so these migrate straight to the current types via the matching
+// migrate_* instruction. A later layout change must bump VERSION and keep the V1 layout
+// here alongside V0.
+
+/// GlobalState before GlobalState::VERSION 1 (no version byte)
+#[derive(AnchorDeserialize)]
+pub struct GlobalStateV0 {
+    pub admin: Pubkey,
+    pub treasury: Pubkey,
+    pub indexer_api_url: String,
+    pub node_registry_url: String,
+    pub moderator_stake_minimum: u64,
+    pub capgm_mint: Pubkey,
+    pub fee_basis_points: u16,
+    pub updates_disabled: bool,
+    pub bump: u8,
+}
+
+impl GlobalStateV0 {
+    pub fn migrate(self) -> GlobalState {
+        GlobalState {
+            admin: self.admin,
+            treasury: self.treasury,
+            indexer_api_url: self.indexer_api_url,
+            node_registry_url: self.node_registry_url,
+            moderator_stake_minimum: self.moderator_stake_minimum,
+            capgm_mint: self.capgm_mint,
+            fee_basis_points: self.fee_basis_points,
+            updates_disabled: self.updates_disabled,
+            pending_admin: None,
+            staker_split_bps: crate::constants::DEFAULT_STAKER_SPLIT_BPS,
+            creator_share_bps: crate::constants::DEFAULT_CREATOR_SHARE_BPS,
+            paused_flags: 0,
+            guardian: None,
+            stablecoin_mint: Pubkey::default(),
+            stablecoin_pool: Pubkey::default(),
+            whirlpools_config: Pubkey::default(),
+            min_collection_supply: crate::constants::DEFAULT_MIN_COLLECTION_SUPPLY,
+            max_collection_supply: crate::constants::DEFAULT_MAX_COLLECTION_SUPPLY,
+            version: GlobalState::VERSION,
+            bump: self.bump,
+        }
+    }
+}
+
+/// CollectionState before CollectionState::VERSION 1 (no version byte, deprecated content_cid)
+#[derive(AnchorDeserialize)]
+pub struct CollectionStateV0 {
+    pub owner: Pubkey,
+    pub collection_id: String,
+    pub cid_hash: [u8; 32],
+    pub mint: Pubkey,
+    pub pool_address: Pubkey,
+    pub claim_vault: Pubkey,
+    pub claim_deadline: i64,
+    pub total_trust_score: u64,
+    pub is_blacklisted: bool,
+    pub name: String,
+    pub content_cid: String, // Dropped by the migration
+    pub access_threshold_usd: u64,
+    pub oracle_feed: Pubkey,
+    pub owner_reward_balance: u64,
+    pub staker_reward_balance: u64,
+    pub tokens_minted: bool,
+    pub total_videos: u16,
+    pub claim_vault_initial_amount: u64,
+    pub claimed_bitmap: Vec<u8>, // 1 bit per video; becomes claimed_share_bps
+    pub censored_bitmap: Vec<u8>,
+    pub claim_share_percent: u8,
+    pub bump: u8,
+}
+
+impl CollectionStateV0 {
+    pub fn migrate(self) -> CollectionState {
+        // A set claimed bit meant the whole video allocation was paid out
+        let claimed_share_bps = (0..self.total_videos as usize)
+            .map(|i| {
+                let claimed = self.claimed_bitmap.get(i / 8).is_some_and(|byte| (byte >> (i % 8)) & 1 == 1);
+                if claimed { FULL_SHARE_BPS } else { 0 }
+            })
+            .collect();
+        CollectionState {
+            owner: self.owner,
+            collection_id: self.collection_id,
+            cid_hash: self.cid_hash,
+            mint: self.mint,
+            pool_address: self.pool_address,
+            claim_vault: self.claim_vault,
+            claim_deadline: self.claim_deadline,
+            total_trust_score: self.total_trust_score,
+            is_blacklisted: self.is_blacklisted,
+            name: self.name,
+            access_threshold_usd: self.access_threshold_usd,
+            oracle_feed: self.oracle_feed,
+            owner_reward_balance: self.owner_reward_balance,
+            staker_reward_balance: self.staker_reward_balance,
+            tokens_minted: self.tokens_minted,
+            total_videos: self.total_videos,
+            claim_vault_initial_amount: self.claim_vault_initial_amount,
+            claimed_share_bps,
+            censored_bitmap: self.censored_bitmap,
+            claim_share_bps: self.claim_share_percent as u16 * 100,
+            total_purchases: 0,
+            creator_bond: 0,
+            refund_reserved_bitmap: vec![0; (self.total_videos as usize).div_ceil(8)],
+            staker_split_override_bps: None,
+            creator_share_override_bps: None,
+            creator_royalty_bps: 0,
+            manifest_version: 0,
+            previous_cid_hashes: Vec::new(),
+            lp_fee_staker_bps: crate::constants::DEFAULT_LP_FEE_STAKER_BPS,
+            owner_capgm_reward_balance: 0,
+            staker_capgm_reward_balance: 0,
+            last_rebalance_at: 0,
+            position_mint: Pubkey::default(),
+            launch_token_deposit: 0,
+            creator_capgm_deposit: 0,
+            creator_capgm_withdrawn: 0,
+            liquidity_locked_at: 0,
+            // Minted legacy collections paid the creator share out directly; nothing vests
+            creator_vesting_cliff_seconds: crate::constants::DEFAULT_CREATOR_VESTING_CLIFF_SECONDS,
+            creator_vesting_duration_seconds: crate::constants::DEFAULT_CREATOR_VESTING_DURATION_SECONDS,
+            creator_vesting_start: 0,
+            creator_vesting_total: 0,
+            creator_vesting_claimed: 0,
+            // Minted legacy collections were launched by hand; keep them purchasable
+            launch_stage: if self.tokens_minted { LaunchStage::Launched } else { LaunchStage::Created },
+            version: CollectionState::VERSION,
+            bump: self.bump,
+        }
+    }
+}
+
+/// UserAccount before UserAccount::VERSION 1 (no version byte)
+#[derive(AnchorDeserialize)]
+pub struct UserAccountV0 {
+    pub authority: Pubkey,
+    pub ipns_key: String,
+    pub is_online: bool,
+    pub bump: u8,
+}
+
+impl UserAccountV0 {
+    pub fn migrate(self) -> UserAccount {
+        UserAccount {
+            authority: self.authority,
+            ipns_key: self.ipns_key,
+            is_online: self.is_online,
+            version: UserAccount::VERSION,
+            bump: self.bump,
+        }
+    }
+}
+
+/// AccessEscrow before AccessEscrow::VERSION 1 (no version byte)
+#[derive(AnchorDeserialize)]
+pub struct AccessEscrowV0 {
+    pub purchaser: Pubkey,
+    pub collection: Pubkey,
+    pub access_nft_mint: Pubkey,
+    pub cid_hash: [u8; 32],
+    pub amount_locked: u64,
+    pub created_at: i64,
+    pub is_cid_revealed: bool,
+    pub bump: u8,
+}
+
+impl AccessEscrowV0 {
+    pub fn migrate(self) -> AccessEscrow {
+        AccessEscrow {
+            purchaser: self.purchaser,
+            collection: self.collection,
+            access_nft_mint: self.access_nft_mint,
+            cid_hash: self.cid_hash,
+            amount_locked: self.amount_locked,
+            created_at: self.created_at,
+            is_cid_revealed: self.is_cid_revealed,
+            version: AccessEscrow::VERSION,
+            bump: self.bump,
+        }
+    }
+}
+
+/// CidReveal before CidReveal::VERSION 1 (no version byte)
+#[derive(AnchorDeserialize)]
+pub struct CidRevealV0 {
+    pub escrow: Pubkey,
+    pub pinner: Pubkey,
+    pub encrypted_cid: Vec<u8>,
+    pub revealed_at: i64,
+    pub bump: u8,
+}
+
+impl CidRevealV0 {
+    pub fn migrate(self) -> CidReveal {
+        CidReveal {
+            escrow: self.escrow,
+            pinner: self.pinner,
+            encrypted_cid: self.encrypted_cid,
+            revealed_at: self.revealed_at,
+            version: CidReveal::VERSION,
+            bump: self.bump,
+        }
+    }
+}
+
+/// PeerTrustState before PeerTrustState::VERSION 1 (no version byte)
+#[derive(AnchorDeserialize)]
+pub struct PeerTrustStateV0 {
+    pub peer_wallet: Pubkey,
+    pub total_successful_serves: u64,
+    pub trust_score: u64,
+    pub last_active: i64,
+}
+
+impl PeerTrustStateV0 {
+    pub fn migrate(self) -> PeerTrustState {
+        PeerTrustState {
+            peer_wallet: self.peer_wallet,
+            total_successful_serves: self.total_successful_serves,
+            trust_score: self.trust_score,
+            last_active: self.last_active,
+            version: PeerTrustState::VERSION,
+        }
+    }
+}
+
+/// PinnerState before PinnerState::VERSION 1 (no version byte)
+#[derive(AnchorDeserialize)]
+pub struct PinnerStateV0 {
+    pub collection: Pubkey,
+    pub pinner: Pubkey,
+    pub is_active: bool,
+}
+
+impl PinnerStateV0 {
+    pub fn migrate(self) -> PinnerState {
+        PinnerState {
+            collection: self.collection,
+            pinner: self.pinner,
+            is_active: self.is_active,
+            version: PinnerState::VERSION,
+        }
+    }
+}
+
+/// ModTicket before ModTicket::VERSION 1 (no version byte, claims, evidence or assignment)
+#[derive(AnchorDeserialize)]
+pub struct ModTicketV0 {
+    pub reporter: Pubkey,
+    pub target_id: String,
+    pub ticket_type: TicketType, // Legacy tickets only use the first three variants
+    pub reason: String,
+    pub resolved: bool,
+    pub verdict: bool,
+    pub resolver: Option<Pubkey>,
+    pub created_at: i64,
+    pub claim_indices: Vec<u16>,
+    pub bump: u8,
+}
+
+impl ModTicketV0 {
+    /// `collection` is the claimed collection of a copyright claim (default when unknown).
+    /// Legacy tickets have no assignees; reassign_ticket draws them immediately.
+    pub fn migrate(self, collection: Pubkey) -> ModTicket {
+        // A legacy claim took each claimed video's whole allocation
+        let claim_shares_bps = vec![FULL_SHARE_BPS; self.claim_indices.len()];
+        ModTicket {
+            reporter: self.reporter,
+            target_id: self.target_id,
+            ticket_type: self.ticket_type,
+            reason: self.reason,
+            resolved: self.resolved,
+            verdict: self.verdict,
+            resolver: self.resolver,
+            created_at: self.created_at,
+            claim_indices: self.claim_indices,
+            claim_shares_bps,
+            collection,
+            evidence_hash: [0; 32],
+            counter_notice_hash: [0; 32],
+            counter_notice_at: 0,
+            assigned_moderators: Vec::new(),
+            assigned_at: 0,
+            refund_per_purchase: 0,
+            refund_claims_remaining: 0,
+            refund_purchase_cutoff: 0,
+            reported_operator: Pubkey::default(),
+            reported_service: None,
+            version: ModTicket::VERSION,
+            bump: self.bump,
+        }
+    }
+}
+
+/// ModeratorStake before ModeratorStake::VERSION 1 (no version byte or specializations)
+#[derive(AnchorDeserialize)]
+pub struct ModeratorStakeV0 {
+    pub moderator: Pubkey,
+    pub stake_amount: u64,
+    pub is_active: bool,
+    pub slash_count: u32,
+    pub bump: u8,
+}
+
+impl ModeratorStakeV0 {
+    pub fn migrate(self) -> ModeratorStake {
+        ModeratorStake {
+            moderator: self.moderator,
+            stake_amount: self.stake_amount,
+            is_active: self.is_active,
+            slash_count: self.slash_count,
+            // Legacy moderators could resolve every ticket type
+            specializations: crate::constants::SPECIALIZATION_ALL,
+            jurisdictions: Vec::new(),
+            version: ModeratorStake::VERSION,
+            bump: self.bump,
+        }
+    }
+}
+
+/// CollectionStakingPool before CollectionStakingPool::VERSION 1 (no version byte or CAPGM index)
+#[derive(AnchorDeserialize)]
+pub struct CollectionStakingPoolV0 {
+    pub collection: Pubkey,
+    pub total_staked: u64,
+    pub reward_per_token: u128,
+    pub bump: u8,
+}
+
+impl CollectionStakingPoolV0 {
+    pub fn migrate(self) -> CollectionStakingPool {
+        CollectionStakingPool {
+            collection: self.collection,
+            total_staked: self.total_staked,
+            reward_per_token: self.reward_per_token,
+            capgm_reward_per_token: 0,
+            version: CollectionStakingPool::VERSION,
+            bump: self.bump,
+        }
+    }
+}
+
+/// StakerPosition before StakerPosition::VERSION 1 (no version byte or CAPGM rewards)
+#[derive(AnchorDeserialize)]
+pub struct StakerPositionV0 {
+    pub staker: Pubkey,
+    pub collection: Pubkey,
+    pub amount_staked: u64,
+    pub reward_debt: u128,
+    pub bump: u8,
+}
+
+impl StakerPositionV0 {
+    /// A zero CAPGM debt credits the position with every CAPGM reward the pool accrued
+    /// while it was staked but not yet migrated
+    pub fn migrate(self) -> StakerPosition {
+        StakerPosition {
+            staker: self.staker,
+            collection: self.collection,
+            amount_staked: self.amount_staked,
+            reward_debt: self.reward_debt,
+            capgm_reward_debt: 0,
+            capgm_rewards_owed: 0,
+            version: StakerPosition::VERSION,
+            bump: self.bump,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::constants::*;
+    use crate::errors::ProtocolError;
+
+    const START: i64 = 1_700_000_000;
+    const SQRT_PRICE: u128 = 1 << 64;
+
+    fn observation() -> PriceObservation {
+        let mut samples = [PriceSample::default(); PRICE_OBSERVATION_SLOTS];
+        samples[0] = PriceSample { timestamp: START, slot: 1, sqrt_price_cumulative: 0 };
+        PriceObservation {
+            whirlpool: Pubkey::default(),
+            decimals_a: 6,
+            decimals_b: 6,
+            last_sqrt_price: SQRT_PRICE,
+            samples,
+            head: 0,
+            count: 1,
+            version: PriceObservation::VERSION,
+            bump: 0,
+        }
+    }
+
+    fn vesting_collection() -> CollectionState {
+        CollectionState {
+            creator_vesting_start: START,
+            creator_vesting_cliff_seconds: 90 * SECONDS_IN_DAY,
+            creator_vesting_duration_seconds: 360 * SECONDS_IN_DAY,
+            creator_vesting_total: 1_200_000,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn creator_vested_amount_is_zero_before_the_cliff() {
+        let collection = vesting_collection();
+        assert_eq!(collection.creator_vested_amount(START).unwrap(), 0);
+        assert_eq!(collection.creator_vested_amount(START + 90 * SECONDS_IN_DAY - 1).unwrap(), 0);
+        // A clock before the vesting start never unlocks anything
+        assert_eq!(collection.creator_vested_amount(START - 1).unwrap(), 0);
+    }
+
+    #[test]
+    fn creator_vested_amount_unlocks_linearly_from_the_start() {
+        let collection = vesting_collection();
+        // At the cliff the time since the start has vested at once
+        assert_eq!(collection.creator_vested_amount(START + 90 * SECONDS_IN_DAY).unwrap(), 300_000);
+        assert_eq!(collection.creator_vested_amount(START + 180 * SECONDS_IN_DAY).unwrap(), 600_000);
+        assert_eq!(collection.creator_vested_amount(START + 360 * SECONDS_IN_DAY).unwrap(), 1_200_000);
+        assert_eq!(collection.creator_vested_amount(START + 720 * SECONDS_IN_DAY).unwrap(), 1_200_000);
+    }
+
+    #[test]
+    fn record_rejects_samples_within_the_interval_or_slot() {
+        let mut observation = observation();
+        assert_eq!(
+            observation.record(START + PRICE_OBSERVATION_INTERVAL_SECONDS - 1, 2, SQRT_PRICE).unwrap_err(),
+            ProtocolError::ObservationTooRecent.into()
+        );
+        assert_eq!(
+            observation.record(START + PRICE_OBSERVATION_INTERVAL_SECONDS, 1, SQRT_PRICE).unwrap_err(),
+            ProtocolError::ObservationTooRecent.into()
+        );
+        assert!(observation.record(START + PRICE_OBSERVATION_INTERVAL_SECONDS, 2, SQRT_PRICE).is_ok());
+        assert_eq!(observation.count, 2);
+    }
+
+    #[test]
+    fn record_clamps_each_step() {
+        let mut observation = observation();
+        observation.record(START + PRICE_OBSERVATION_INTERVAL_SECONDS, 2, SQRT_PRICE * 10).unwrap();
+        let raised = SQRT_PRICE + SQRT_PRICE / 100;
+        assert_eq!(observation.last_sqrt_price, raised);
+        observation.record(START + 2 * PRICE_OBSERVATION_INTERVAL_SECONDS, 3, 0).unwrap();
+        assert_eq!(observation.last_sqrt_price, raised - raised / 100);
+    }
+
+    #[test]
+    fn twap_sqrt_price_weights_prices_by_time_held() {
+        let mut observation = observation();
+        let mut now = START;
+        for slot in 2..=7 {
+            now += PRICE_OBSERVATION_INTERVAL_SECONDS;
+            observation.record(now, slot, SQRT_PRICE).unwrap();
+        }
+        assert_eq!(observation.twap_sqrt_price(now).unwrap(), SQRT_PRICE);
+
+        // A single pushed sample moves the average by at most one clamped step
+        now += PRICE_OBSERVATION_INTERVAL_SECONDS;
+        observation.record(now, 8, SQRT_PRICE * 4).unwrap();
+        now += PRICE_OBSERVATION_INTERVAL_SECONDS;
+        observation.record(now, 9, SQRT_PRICE).unwrap();
+        let twap = observation.twap_sqrt_price(now).unwrap();
+        assert!(twap > SQRT_PRICE && twap <= SQRT_PRICE + SQRT_PRICE / 100);
+    }
+
+    #[test]
+    fn twap_sqrt_price_requires_enough_fresh_samples() {
+        let mut observation = observation();
+        // Two samples far enough apart still span the window, but are too few
+        observation.record(START + TWAP_MIN_WINDOW_SECONDS, 2, SQRT_PRICE).unwrap();
+        assert_eq!(
+            observation.twap_sqrt_price(START + TWAP_MIN_WINDOW_SECONDS).unwrap_err(),
+            ProtocolError::InvalidOraclePrice.into()
+        );
+
+        let mut now = START + TWAP_MIN_WINDOW_SECONDS;
+        for slot in 3..=6 {
+            now += PRICE_OBSERVATION_INTERVAL_SECONDS;
+            observation.record(now, slot, SQRT_PRICE).unwrap();
+        }
+        assert!(observation.twap_sqrt_price(now).is_ok());
+        assert_eq!(
+            observation.twap_sqrt_price(now + PRICE_OBSERVATION_MAX_AGE_SECONDS + 1).unwrap_err(),
+            ProtocolError::InvalidOraclePrice.into()
+        );
+    }
 }
@@ -0,0 +1,296 @@
+import { expect } from "chai";
+import { PublicKey, SystemProgram } from "@solana/web3.js";
+import {
+  TOKEN_PROGRAM_ID,
+  createMint,
+  getAssociatedTokenAddressSync,
+  getOrCreateAssociatedTokenAccount,
+  mintTo,
+} from "@solana/spl-token";
+import { BN } from "@coral-xyz/anchor";
+import {
+  program,
+  admin,
+  user,
+  provider,
+  capgmMint,
+  setupAccounts,
+  getGlobalStatePDA,
+  getGovernancePDA,
+  getVoterLockPDA,
+  getGovernanceProposalPDA,
+  accountExists,
+  mintExistsAndValid,
+} from "./helpers/setup";
+
+describe("CAPGM Governance", () => {
+  const DAY = 24 * 60 * 60;
+  let governancePDA: PublicKey;
+  let governanceVault: PublicKey;
+  let userCapgmAccount: PublicKey;
+  let proposalPDA: PublicKey;
+
+  const emptyUpdate = {
+    indexerUrl: null,
+    registryUrl: null,
+    modStakeMin: null,
+    feeBasisPoints: null,
+    treasury: null,
+    capgmMint: null,
+    stakerSplitBps: null,
+    creatorShareBps: null,
+    stablecoinMint: null,
+    stablecoinPool: null,
+    whirlpoolsConfig: null,
+    minCollectionSupply: null,
+    maxCollectionSupply: null,
+  };
+
+  before(async () => {
+    await setupAccounts();
+    const { ensureProtocolInitialized } = await import("./helpers/setup");
+    await ensureProtocolInitialized();
+
+    // GlobalState stores capgmMint's address; make sure a real mint lives there
+    if (!(await mintExistsAndValid(capgmMint.publicKey))) {
+      await createMint(provider.connection, admin, admin.publicKey, null, 6, capgmMint);
+    }
+
+    [governancePDA] = getGovernancePDA();
+    governanceVault = getAssociatedTokenAddressSync(capgmMint.publicKey, governancePDA, true);
+    if (!(await accountExists(governancePDA))) {
+      await program.methods
+        .initializeGovernance(new BN(1_000), new BN(100), new BN(DAY), new BN(2 * DAY))
+        .accountsPartial({
+          admin: admin.publicKey,
+          globalState: getGlobalStatePDA()[0],
+          governance: governancePDA,
+          capgmMint: capgmMint.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([admin])
+        .rpc();
+    }
+
+    userCapgmAccount = (
+      await getOrCreateAssociatedTokenAccount(provider.connection, admin, capgmMint.publicKey, user.publicKey)
+    ).address;
+    await mintTo(provider.connection, admin, capgmMint.publicKey, userCapgmAccount, admin, 5_000);
+  });
+
+  it("Locks CAPGM for voting weight", async () => {
+    const [voterLockPDA] = getVoterLockPDA(user.publicKey);
+
+    await program.methods
+      .lockGovernanceTokens(new BN(1_500))
+      .accountsPartial({
+        owner: user.publicKey,
+        governance: governancePDA,
+        voterLock: voterLockPDA,
+        ownerTokenAccount: userCapgmAccount,
+        governanceVault,
+        capgmMint: capgmMint.publicKey,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([user])
+      .rpc();
+
+    const voterLock = await program.account.voterLock.fetch(voterLockPDA);
+    expect(voterLock.amount.toNumber()).to.equal(1_500);
+  });
+
+  it("Rejects proposals outside protocol bounds", async () => {
+    const governance = await program.account.governance.fetch(governancePDA);
+    const [pda] = getGovernanceProposalPDA(governance.proposalCount);
+
+    try {
+      await program.methods
+        .createGovernanceProposal({ updateGlobalState: { 0: { ...emptyUpdate, feeBasisPoints: 5_000 } } })
+        .accountsPartial({
+          proposer: user.publicKey,
+          governance: governancePDA,
+          voterLock: getVoterLockPDA(user.publicKey)[0],
+          proposal: pda,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([user])
+        .rpc();
+      expect.fail("Should have failed");
+    } catch (err: unknown) {
+      expect(err.toString()).to.include("InvalidFeeConfig");
+    }
+  });
+
+  it("Rejects collection supply bounds outside the protocol cap", async () => {
+    const governance = await program.account.governance.fetch(governancePDA);
+    const [pda] = getGovernanceProposalPDA(governance.proposalCount);
+
+    try {
+      await program.methods
+        .createGovernanceProposal({ updateGlobalState: { 0: { ...emptyUpdate, minCollectionSupply: new BN(0) } } })
+        .accountsPartial({
+          proposer: user.publicKey,
+          governance: governancePDA,
+          voterLock: getVoterLockPDA(user.publicKey)[0],
+          proposal: pda,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([user])
+        .rpc();
+      expect.fail("Should have failed");
+    } catch (err: unknown) {
+      expect(err.toString()).to.include("InvalidSupply");
+    }
+  });
+
+  it("Blocks new proposals while governance is paused", async () => {
+    const PAUSE_GOVERNANCE = 1 << 6;
+    const [globalStatePDA] = getGlobalStatePDA();
+    const governance = await program.account.governance.fetch(governancePDA);
+    const [pda] = getGovernanceProposalPDA(governance.proposalCount);
+
+    await program.methods
+      .setPauseFlags(PAUSE_GOVERNANCE)
+      .accountsPartial({ authority: admin.publicKey, globalState: globalStatePDA })
+      .signers([admin])
+      .rpc();
+
+    try {
+      await program.methods
+        .createGovernanceProposal({ updateGlobalState: { 0: { ...emptyUpdate, feeBasisPoints: 300 } } })
+        .accountsPartial({
+          proposer: user.publicKey,
+          governance: governancePDA,
+          voterLock: getVoterLockPDA(user.publicKey)[0],
+          proposal: pda,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([user])
+        .rpc();
+      expect.fail("Should have failed");
+    } catch (err: unknown) {
+      expect(err.toString()).to.include("ProtocolPaused");
+    } finally {
+      await program.methods
+        .setPauseFlags(0)
+        .accountsPartial({ authority: admin.publicKey, globalState: globalStatePDA })
+        .signers([admin])
+        .rpc();
+    }
+  });
+
+  it("Rejects multisig signer changes", async () => {
+    const governance = await program.account.governance.fetch(governancePDA);
+    const [pda] = getGovernanceProposalPDA(governance.proposalCount);
+
+    try {
+      await program.methods
+        .createGovernanceProposal({ changeSigners: { signers: [user.publicKey], threshold: 1 } })
+        .accountsPartial({
+          proposer: user.publicKey,
+          governance: governancePDA,
+          voterLock: getVoterLockPDA(user.publicKey)[0],
+          proposal: pda,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([user])
+        .rpc();
+      expect.fail("Should have failed");
+    } catch (err: unknown) {
+      expect(err.toString()).to.include("Unauthorized");
+    }
+  });
+
+  it("Creates a proposal and records a vote", async () => {
+    const governance = await program.account.governance.fetch(governancePDA);
+    [proposalPDA] = getGovernanceProposalPDA(governance.proposalCount);
+    const [voterLockPDA] = getVoterLockPDA(user.publicKey);
+
+    await program.methods
+      .createGovernanceProposal({ updateGlobalState: { 0: { ...emptyUpdate, feeBasisPoints: 300 } } })
+      .accountsPartial({
+        proposer: user.publicKey,
+        governance: governancePDA,
+        voterLock: voterLockPDA,
+        proposal: proposalPDA,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([user])
+      .rpc();
+
+    await program.methods
+      .castGovernanceVote(true)
+      .accountsPartial({
+        voter: user.publicKey,
+        proposal: proposalPDA,
+        voterLock: voterLockPDA,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([user])
+      .rpc();
+
+    const proposal = await program.account.governanceProposal.fetch(proposalPDA);
+    expect(proposal.votesFor.toNumber()).to.equal(1_500);
+    expect(proposal.action.updateGlobalState?.[0].feeBasisPoints).to.equal(300);
+
+    const voterLock = await program.account.voterLock.fetch(voterLockPDA);
+    expect(voterLock.lockedUntil.toNumber()).to.equal(proposal.votingEndsAt.toNumber());
+  });
+
+  it("Fails if the same voter votes twice", async () => {
+    try {
+      await program.methods
+        .castGovernanceVote(false)
+        .accountsPartial({
+          voter: user.publicKey,
+          proposal: proposalPDA,
+          voterLock: getVoterLockPDA(user.publicKey)[0],
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([user])
+        .rpc();
+      expect.fail("Should have failed");
+    } catch (err: unknown) {
+      expect(err.toString()).to.include("already in use");
+    }
+  });
+
+  it("Keeps voted tokens locked until voting closes", async () => {
+    try {
+      await program.methods
+        .unlockGovernanceTokens(new BN(1_500))
+        .accountsPartial({
+          owner: user.publicKey,
+          governance: governancePDA,
+          voterLock: getVoterLockPDA(user.publicKey)[0],
+          ownerTokenAccount: userCapgmAccount,
+          governanceVault,
+          capgmMint: capgmMint.publicKey,
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .signers([user])
+        .rpc();
+      expect.fail("Should have failed");
+    } catch (err: unknown) {
+      expect(err.toString()).to.include("TokensLocked");
+    }
+  });
+
+  it("Fails to execute while governance does not hold admin authority", async () => {
+    try {
+      await program.methods
+        .executeGovernanceProposal()
+        .accountsPartial({
+          executor: user.publicKey,
+          governance: governancePDA,
+          proposal: proposalPDA,
+        })
+        .signers([user])
+        .rpc();
+      expect.fail("Should have failed");
+    } catch (err: unknown) {
+      expect(err.toString()).to.include("GovernanceNotAdmin");
+    }
+  });
+});
@@ -16,9 +16,21 @@ export const pinner = Keypair.generate();
 export const performer = Keypair.generate();
 export const moderator = Keypair.generate();
 export const treasury = Keypair.generate();
-export const capgmMint = Keypair.generate();
+// Whirlpools order their mints by key and collection mints must sort before CAPGM
+// (create_collection rejects the rest), so grind a CAPGM mint near the top of the key range
+export const capgmMint = grindKeypair((key) => key.toBytes()[0] === 0xff && key.toBytes()[1] >= 0xf0);
 export const oracleFeed = Keypair.generate();
 
+// Generate keypairs until one's public key satisfies `accept`
+export function grindKeypair(accept: (key: PublicKey) => boolean): Keypair {
+  for (;;) {
+    const keypair = Keypair.generate();
+    if (accept(keypair.publicKey)) {
+      return keypair;
+    }
+  }
+}
+
 // Setup: Airdrop SOL to test accounts
 export async function setupAccounts(): Promise<void> {
   const airdropAmount = 10 * LAMPORTS_PER_SOL;
@@ -111,6 +123,118 @@ export const getPeerTrustStatePDA = (peerWallet: PublicKey): [PublicKey, number]
   );
 };
 
+export const getCreatorBondPDA = (collection: PublicKey): [PublicKey, number] => {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from("creator_bond"), collection.toBuffer()],
+    program.programId
+  );
+};
+
+export const getPendingConfigChangePDA = (globalState: PublicKey): [PublicKey, number] => {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from("pending_config"), globalState.toBuffer()],
+    program.programId
+  );
+};
+
+export const getAdminMultisigPDA = (): [PublicKey, number] => {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from("admin_multisig")],
+    program.programId
+  );
+};
+
+export const getAdminProposalPDA = (multisig: PublicKey, proposalId: anchor.BN): [PublicKey, number] => {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from("admin_proposal"), multisig.toBuffer(), proposalId.toArrayLike(Buffer, "le", 8)],
+    program.programId
+  );
+};
+
+export const getAdminAuthorizationPDA = (proposal: PublicKey): [PublicKey, number] => {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from("admin_authorization"), proposal.toBuffer()],
+    program.programId
+  );
+};
+
+export const getTreasuryPDA = (): [PublicKey, number] => {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from("treasury")],
+    program.programId
+  );
+};
+
+export const getTreasuryBudgetPDA = (mint: PublicKey, name: string): [PublicKey, number] => {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from("treasury_budget"), mint.toBuffer(), Buffer.from(name)],
+    program.programId
+  );
+};
+
+export const getGovernancePDA = (): [PublicKey, number] => {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from("governance")],
+    program.programId
+  );
+};
+
+export const getVoterLockPDA = (owner: PublicKey): [PublicKey, number] => {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from("voter_lock"), owner.toBuffer()],
+    program.programId
+  );
+};
+
+export const getGovernanceProposalPDA = (proposalId: anchor.BN): [PublicKey, number] => {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from("gov_proposal"), proposalId.toArrayLike(Buffer, "le", 8)],
+    program.programId
+  );
+};
+
+export const getServiceRegistryPDA = (): [PublicKey, number] => {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from("service_registry")],
+    program.programId
+  );
+};
+
+export const getCreatorVestingPDA = (collection: PublicKey): [PublicKey, number] => {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from("creator_vesting"), collection.toBuffer()],
+    program.programId
+  );
+};
+
+export const getPriceObservationPDA = (whirlpool: PublicKey): [PublicKey, number] => {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from("price_observation"), whirlpool.toBuffer()],
+    program.programId
+  );
+};
+
+export const getModeratorRegistryPDA = (): [PublicKey, number] => {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from("moderator_registry")],
+    program.programId
+  );
+};
+
+export const getCensorshipRefundReceiptPDA = (ticket: PublicKey, accessNftMint: PublicKey): [PublicKey, number] => {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from("censorship_refund"), ticket.toBuffer(), accessNftMint.toBuffer()],
+    program.programId
+  );
+};
+
+export const getPurchaseRecordPDA = (accessNftMint: PublicKey): [PublicKey, number] => {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from("purchase_record"), accessNftMint.toBuffer()],
+    program.programId
+  );
+};
+
 // Helper to check if an account exists
 export async function accountExists(accountPubkey: PublicKey): Promise<boolean> {
   try {
@@ -144,10 +268,10 @@ export async function ensureProtocolInitialized(): Promise<void> {
   
   if (!exists) {
     const { SystemProgram } = await import("@solana/web3.js");
-    const { INDEXER_URL, REGISTRY_URL, MOD_STAKE_MIN, FEE_BASIS_POINTS } = await import("./constants");
+    const { INDEXER_URL, REGISTRY_URL, MOD_STAKE_MIN, FEE_BASIS_POINTS, WHIRLPOOLS_CONFIG } = await import("./constants");
     
     await program.methods
-      .initializeProtocol(INDEXER_URL, REGISTRY_URL, MOD_STAKE_MIN, FEE_BASIS_POINTS)
+      .initializeProtocol(INDEXER_URL, REGISTRY_URL, MOD_STAKE_MIN, FEE_BASIS_POINTS, WHIRLPOOLS_CONFIG)
       .accountsPartial({
         admin: admin.publicKey,
         globalState: globalStatePDA,
@@ -26,6 +26,7 @@ import {
   REGISTRY_URL,
   MOD_STAKE_MIN,
   FEE_BASIS_POINTS,
+  WHIRLPOOLS_CONFIG,
   IPNS_KEY,
   COLLECTION_ID,
   COLLECTION_NAME,
@@ -48,7 +49,7 @@ describe("Integration Tests", () => {
         await program.account.globalState.fetch(globalStatePDA);
       } catch {
         await program.methods
-          .initializeProtocol(INDEXER_URL, REGISTRY_URL, MOD_STAKE_MIN, FEE_BASIS_POINTS)
+          .initializeProtocol(INDEXER_URL, REGISTRY_URL, MOD_STAKE_MIN, FEE_BASIS_POINTS, WHIRLPOOLS_CONFIG)
           .accountsPartial({
             admin: admin.publicKey,
             globalState: globalStatePDA,
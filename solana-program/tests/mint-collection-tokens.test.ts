@@ -15,6 +15,7 @@ import {
   setupAccounts,
   getCollectionPDA,
   getMintPDA,
+  getCreatorVestingPDA,
   provider,
 } from "./helpers/setup";
 import {
@@ -28,6 +29,7 @@ describe("Mint Collection Tokens", () => {
   let collectionPDA: PublicKey;
   let mint: PublicKey;
   let creatorTokenAccount: PublicKey;
+  let creatorVestingVault: PublicKey;
   let orcaPoolTokenAccount: PublicKey;
   let orcaProgram: PublicKey;
 
@@ -81,6 +83,9 @@ describe("Mint Collection Tokens", () => {
       mint = mintPDA;
     }
 
+    // The creator share is minted into the vesting vault, not the creator's wallet
+    [creatorVestingVault] = getCreatorVestingPDA(collectionPDA);
+
     // Get or create creator's token account
     creatorTokenAccount = await getAssociatedTokenAddress(mint, user.publicKey);
     
@@ -140,7 +145,7 @@ describe("Mint Collection Tokens", () => {
     let claimVaultBalance = 0;
     let orcaBalance = 0;
     try {
-      const account = await getAccount(provider.connection, creatorTokenAccount);
+      const account = await getAccount(provider.connection, creatorVestingVault);
       creatorBalance = Number(account.amount);
     } catch {
       creatorBalance = 0;
@@ -168,7 +173,7 @@ describe("Mint Collection Tokens", () => {
           creator: user.publicKey,
           collection: collectionPDA,
           mint: mint,
-          creatorTokenAccount: creatorTokenAccount,
+          creatorVestingVault: creatorVestingVault,
           claimVault: claimVault,
           orcaLiquidityPool: orcaPoolTokenAccount,
           orcaProgram: orcaProgram,
@@ -178,8 +183,8 @@ describe("Mint Collection Tokens", () => {
         .signers([user])
         .rpc();
 
-    // Verify creator received 10% (with small tolerance for rounding)
-    const creatorAccount = await getAccount(provider.connection, creatorTokenAccount);
+    // Verify the creator vesting vault received 10% (with small tolerance for rounding)
+    const creatorAccount = await getAccount(provider.connection, creatorVestingVault);
     const newCreatorBalance = Number(creatorAccount.amount);
     const creatorReceived = newCreatorBalance - creatorBalance;
     
@@ -205,6 +210,9 @@ describe("Mint Collection Tokens", () => {
     const mintInfoAfter = await getMint(provider.connection, mint);
     const supplyAfter = Number(mintInfoAfter.supply);
     expect(supplyAfter - supplyBefore).to.equal(mintAmount.toNumber());
+
+    const collectionAfter = await program.account.collectionState.fetch(collectionPDA);
+    expect(collectionAfter.launchStage).to.deep.equal({ tokensMinted: {} });
   });
 
   it("Fails if called by non-owner", async () => {
@@ -224,7 +232,7 @@ describe("Mint Collection Tokens", () => {
           creator: unauthorizedUser.publicKey,
           collection: collectionPDA,
           mint: mint,
-          creatorTokenAccount: creatorTokenAccount,
+          creatorVestingVault: creatorVestingVault,
           claimVault: claimVault,
           orcaLiquidityPool: orcaPoolTokenAccount,
           orcaProgram: orcaProgram,
@@ -253,7 +261,7 @@ describe("Mint Collection Tokens", () => {
           creator: user.publicKey,
           collection: collectionPDA,
           mint: mint,
-          creatorTokenAccount: creatorTokenAccount,
+          creatorVestingVault: creatorVestingVault,
           claimVault: claimVault,
           orcaLiquidityPool: orcaPoolTokenAccount,
           orcaProgram: orcaProgram,
@@ -284,7 +292,7 @@ describe("Mint Collection Tokens", () => {
           creator: user.publicKey,
           collection: fakeCollection,
           mint: fakeMintPDA,
-          creatorTokenAccount: creatorTokenAccount,
+          creatorVestingVault: creatorVestingVault,
           claimVault: fakeClaimVault,
           orcaLiquidityPool: orcaPoolTokenAccount,
           orcaProgram: orcaProgram,
@@ -318,7 +326,7 @@ describe("Mint Collection Tokens", () => {
           creator: user.publicKey,
           collection: collectionPDA,
           mint: fakeMint,
-          creatorTokenAccount: creatorTokenAccount,
+          creatorVestingVault: creatorVestingVault,
           claimVault: claimVault,
           orcaLiquidityPool: orcaPoolTokenAccount,
           orcaProgram: orcaProgram,
@@ -341,7 +349,7 @@ describe("Mint Collection Tokens", () => {
     // Get initial balances
     let creatorBalance = 0;
     try {
-      const account = await getAccount(provider.connection, creatorTokenAccount);
+      const account = await getAccount(provider.connection, creatorVestingVault);
       creatorBalance = Number(account.amount);
     } catch {
       creatorBalance = 0;
@@ -360,7 +368,7 @@ describe("Mint Collection Tokens", () => {
           creator: user.publicKey,
           collection: collectionPDA,
           mint: mint,
-          creatorTokenAccount: creatorTokenAccount,
+          creatorVestingVault: creatorVestingVault,
           claimVault: claimVault,
           orcaLiquidityPool: orcaPoolTokenAccount,
           orcaProgram: orcaProgram,
@@ -376,7 +384,7 @@ describe("Mint Collection Tokens", () => {
     expect(supplyAfter - supplyBefore).to.equal(mintAmount.toNumber());
 
     // Verify creator received approximately 10% (with remainder handling)
-    const creatorAccount = await getAccount(provider.connection, creatorTokenAccount);
+    const creatorAccount = await getAccount(provider.connection, creatorVestingVault);
     const newCreatorBalance = Number(creatorAccount.amount);
     const creatorReceived = newCreatorBalance - creatorBalance;
     
@@ -397,7 +405,7 @@ describe("Mint Collection Tokens", () => {
     // Get initial balances
     let creatorBalance = 0;
     try {
-      const account = await getAccount(provider.connection, creatorTokenAccount);
+      const account = await getAccount(provider.connection, creatorVestingVault);
       creatorBalance = Number(account.amount);
     } catch {
       creatorBalance = 0;
@@ -417,7 +425,7 @@ describe("Mint Collection Tokens", () => {
         creator: user.publicKey,
         collection: collectionPDA,
         mint: mint,
-        creatorTokenAccount: creatorTokenAccount,
+        creatorVestingVault: creatorVestingVault,
         claimVault: claimVault,
         orcaLiquidityPool: orcaPoolTokenAccount,
         orcaProgram: orcaProgram,
@@ -434,7 +442,7 @@ describe("Mint Collection Tokens", () => {
         creator: user.publicKey,
         collection: collectionPDA,
         mint: mint,
-        creatorTokenAccount: creatorTokenAccount,
+        creatorVestingVault: creatorVestingVault,
         claimVault: claimVault,
         orcaLiquidityPool: orcaPoolTokenAccount,
         orcaProgram: orcaProgram,
@@ -451,7 +459,7 @@ describe("Mint Collection Tokens", () => {
     expect(supplyAfter - supplyBefore).to.equal(totalMinted);
 
     // Verify creator received approximately 10% of total
-    const creatorAccount = await getAccount(provider.connection, creatorTokenAccount);
+    const creatorAccount = await getAccount(provider.connection, creatorVestingVault);
     const newCreatorBalance = Number(creatorAccount.amount);
     const creatorReceived = newCreatorBalance - creatorBalance;
     const expectedCreatorTotal = totalMinted * 0.1;
@@ -460,4 +468,27 @@ describe("Mint Collection Tokens", () => {
     expect(creatorReceived).to.be.at.least(expectedCreatorTotal - 1_000_000);
     expect(creatorReceived).to.be.at.most(expectedCreatorTotal + 1_000_000);
   });
+
+  it("Keeps the creator allocation locked until the vesting cliff", async () => {
+    const collection = await program.account.collectionState.fetch(collectionPDA);
+    expect(collection.creatorVestingTotal.toNumber()).to.be.greaterThan(0);
+
+    try {
+      await program.methods
+        .claimCreatorVesting()
+        .accountsPartial({
+          owner: user.publicKey,
+          collection: collectionPDA,
+          creatorVestingVault,
+          ownerTokenAccount: creatorTokenAccount,
+          collectionMint: mint,
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .signers([user])
+        .rpc();
+      expect.fail("Should have failed - cliff not reached");
+    } catch (err: unknown) {
+      expect(err.toString()).to.include("NothingVested");
+    }
+  });
 });
@@ -1,5 +1,5 @@
 import { expect } from "chai";
-import { Keypair, SystemProgram, PublicKey } from "@solana/web3.js";
+import { Keypair, SystemProgram, PublicKey, SYSVAR_SLOT_HASHES_PUBKEY } from "@solana/web3.js";
 import {
   program,
   user,
@@ -9,9 +9,12 @@ import {
   getModTicketPDA,
   getModeratorStakePDA,
   getGlobalStatePDA,
+  getCensorshipRefundReceiptPDA,
+  getPurchaseRecordPDA,
+  getModeratorRegistryPDA,
   provider,
 } from "./helpers/setup";
-import { TARGET_ID, REASON } from "./helpers/constants";
+import { TARGET_ID, REASON, EVIDENCE_HASH } from "./helpers/constants";
 
 describe("Moderation", () => {
   let globalStatePDA: PublicKey;
@@ -50,6 +53,16 @@ describe("Moderation", () => {
         .signers([moderator])
         .rpc();
     }
+
+    // Moderators can only resolve ticket types they are registered for
+    await program.methods
+      .registerModeratorSpecializations(0b111, [])
+      .accountsPartial({
+        moderator: moderator.publicKey,
+        moderatorStake: moderatorStakePDA,
+      })
+      .signers([moderator])
+      .rpc();
   });
 
   describe("Create Ticket", () => {
@@ -469,4 +482,207 @@ describe("Moderation", () => {
       }
     });
   });
+
+  describe("Partial-Share Claims", () => {
+    it("Fails if claim shares don't match claim indices", async () => {
+      const uniqueTargetId = `p${Date.now()}`.slice(0, 32);
+      const [ticketPDA] = getModTicketPDA(uniqueTargetId);
+
+      try {
+        await program.methods
+          .createTicket(uniqueTargetId, { contentReport: {} }, REASON, EVIDENCE_HASH, [0, 1], [5000])
+          .accountsPartial({
+            reporter: user.publicKey,
+            ticket: ticketPDA,
+            collection: null,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([user])
+          .rpc();
+        expect.fail("Should have failed");
+      } catch (err: unknown) {
+        expect(err.toString()).to.include("InvalidFeeConfig");
+      }
+    });
+  });
+
+  describe("Evidence & Counter-Notices", () => {
+    it("Stores the evidence hash and starts without a counter-notice", async () => {
+      const uniqueTargetId = `e${Date.now()}`.slice(0, 32);
+      const [ticketPDA] = getModTicketPDA(uniqueTargetId);
+
+      await program.methods
+        .createTicket(uniqueTargetId, { contentReport: {} }, REASON, EVIDENCE_HASH, [], [])
+        .accountsPartial({
+          reporter: user.publicKey,
+          ticket: ticketPDA,
+          collection: null,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([user])
+        .rpc();
+
+      const ticket = await program.account.modTicket.fetch(ticketPDA);
+      expect(Buffer.from(ticket.evidenceHash).equals(Buffer.from(EVIDENCE_HASH))).to.be.true;
+      expect(ticket.counterNoticeAt.toNumber()).to.equal(0);
+      expect(ticket.collection.toString()).to.equal(PublicKey.default.toString());
+    });
+
+    it("Fails to submit a counter-notice for a ticket without a collection", async () => {
+      const uniqueTargetId = `e2${Date.now()}`.slice(0, 32);
+      const [ticketPDA] = getModTicketPDA(uniqueTargetId);
+
+      await program.methods
+        .createTicket(uniqueTargetId, { contentReport: {} }, REASON, EVIDENCE_HASH, [], [])
+        .accountsPartial({
+          reporter: user.publicKey,
+          ticket: ticketPDA,
+          collection: null,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([user])
+        .rpc();
+
+      try {
+        await program.methods
+          .submitCounterNotice(EVIDENCE_HASH)
+          .accountsPartial({
+            owner: performer.publicKey,
+            ticket: ticketPDA,
+          })
+          .signers([performer])
+          .rpc();
+        expect.fail("Should have failed");
+      } catch (err: unknown) {
+        const errStr = err.toString();
+        expect(errStr.includes("Unauthorized") || errStr.includes("Constraint") || errStr.includes("Account")).to.be.true;
+      }
+    });
+  });
+
+  describe("Censorship Refunds", () => {
+    it("Initializes new tickets with no refund reserved", async () => {
+      const uniqueTargetId = `r${Date.now()}`.slice(0, 32);
+      const [ticketPDA] = getModTicketPDA(uniqueTargetId);
+
+      await program.methods
+        .createTicket(uniqueTargetId, { cidCensorship: {} }, REASON, EVIDENCE_HASH, [], [])
+        .accountsPartial({
+          reporter: user.publicKey,
+          ticket: ticketPDA,
+          collection: null,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([user])
+        .rpc();
+
+      const ticket = await program.account.modTicket.fetch(ticketPDA);
+      expect(ticket.refundPerPurchase.toNumber()).to.equal(0);
+      expect(ticket.refundClaimsRemaining.toNumber()).to.equal(0);
+      expect(ticket.refundPurchaseCutoff.toNumber()).to.equal(0);
+      expect(ticket.collection.toString()).to.equal(PublicKey.default.toString());
+    });
+
+    it("Fails to claim a refund from an unresolved ticket", async () => {
+      const uniqueTargetId = `r2${Date.now()}`.slice(0, 32);
+      const [ticketPDA] = getModTicketPDA(uniqueTargetId);
+      const accessNftMint = Keypair.generate().publicKey;
+      const [receiptPDA] = getCensorshipRefundReceiptPDA(ticketPDA, accessNftMint);
+
+      await program.methods
+        .createTicket(uniqueTargetId, { cidCensorship: {} }, REASON, EVIDENCE_HASH, [], [])
+        .accountsPartial({
+          reporter: user.publicKey,
+          ticket: ticketPDA,
+          collection: null,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([user])
+        .rpc();
+
+      try {
+        await program.methods
+          .claimCensorshipRefund(accessNftMint)
+          .accountsPartial({
+            claimant: user.publicKey,
+            ticket: ticketPDA,
+            refundReceipt: receiptPDA,
+            purchaseRecord: getPurchaseRecordPDA(accessNftMint)[0],
+          })
+          .signers([user])
+          .rpc();
+        expect.fail("Should have failed");
+      } catch (err: unknown) {
+        const errStr = err.toString();
+        // Collection/vault accounts are not provided, so account resolution may fail first
+        expect(errStr.includes("NoRefundAvailable") || errStr.includes("Constraint") || errStr.includes("Account")).to.be.true;
+      }
+    });
+  });
+
+  describe("Moderator Assignment", () => {
+    it("Assigns registered moderators to new tickets", async () => {
+      const uniqueTargetId = `a${Date.now()}`.slice(0, 32);
+      const [ticketPDA] = getModTicketPDA(uniqueTargetId);
+      const [registryPDA] = getModeratorRegistryPDA();
+
+      await program.methods
+        .createTicket(uniqueTargetId, { contentReport: {} }, REASON, EVIDENCE_HASH, [], [])
+        .accountsPartial({
+          reporter: user.publicKey,
+          ticket: ticketPDA,
+          collection: null,
+          moderatorRegistry: registryPDA,
+          slotHashes: SYSVAR_SLOT_HASHES_PUBKEY,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([user])
+        .rpc();
+
+      const ticket = await program.account.modTicket.fetch(ticketPDA);
+      const registry = await program.account.moderatorRegistry.fetch(registryPDA);
+      const registered = registry.moderators
+        .filter((e: { isActive: boolean }) => e.isActive)
+        .map((e: { moderator: PublicKey }) => e.moderator.toString());
+
+      expect(ticket.assignedModerators.length).to.be.greaterThan(0);
+      expect(ticket.assignedModerators.length).to.be.at.most(3);
+      ticket.assignedModerators.forEach((m: PublicKey) => {
+        expect(registered).to.include(m.toString());
+      });
+      expect(ticket.assignedAt.toNumber()).to.equal(ticket.createdAt.toNumber());
+    });
+
+    it("Fails to reassign before the assignment times out", async () => {
+      const uniqueTargetId = `a2${Date.now()}`.slice(0, 32);
+      const [ticketPDA] = getModTicketPDA(uniqueTargetId);
+
+      await program.methods
+        .createTicket(uniqueTargetId, { contentReport: {} }, REASON, EVIDENCE_HASH, [], [])
+        .accountsPartial({
+          reporter: user.publicKey,
+          ticket: ticketPDA,
+          collection: null,
+          slotHashes: SYSVAR_SLOT_HASHES_PUBKEY,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([user])
+        .rpc();
+
+      try {
+        await program.methods
+          .reassignTicket()
+          .accountsPartial({
+            caller: user.publicKey,
+            ticket: ticketPDA,
+            slotHashes: SYSVAR_SLOT_HASHES_PUBKEY,
+          })
+          .signers([user])
+          .rpc();
+        expect.fail("Should have failed");
+      } catch (err: unknown) {
+        expect(err.toString()).to.include("AssignmentNotExpired");
+      }
+    });
+  });
 });
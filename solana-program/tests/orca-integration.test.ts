@@ -187,6 +187,9 @@ describe("Orca Whirlpool Integration", () => {
     // Uncomment when testing with real Orca program on devnet
 
     /*
+    // whirlpoolConfig must equal GlobalState.whirlpoolsConfig, collectionMint must sort
+    // before capgmMint, and the price must put one access within the launch price band
+    // (valued through GlobalState.stablecoinPool); otherwise this fails with InvalidPoolConfig
     const tickSpacing = 64;
     const initialPrice = 0.01; // 1 Collection Token = 0.01 CAPGM
     const initialSqrtPrice = calculateSqrtPriceX64(initialPrice);
@@ -222,6 +225,8 @@ describe("Orca Whirlpool Integration", () => {
         collectionMint,
         capgmMint,
         whirlpoolConfig,
+        stablecoinPool,
+        stablecoinMint,
         whirlpool,
         tokenVaultA,
         tokenVaultB,
@@ -325,6 +330,187 @@ describe("Orca Whirlpool Integration", () => {
     console.log("⚠️  Skipping liquidity deposit (requires Orca program)");
   });
 
+  it("Step 6: Collects LP fees for stakers and the owner (SKIP if Orca not available)", async () => {
+    // NOTE: This test will fail without actual Orca program
+    // Uncomment when testing with real Orca program on devnet
+
+    /*
+    // Permissionless crank: any wallet may harvest the position's fees
+    await program.methods
+      .collectOrcaFees(Buffer.from([])) // no reward indices
+      .accountsPartial({
+        cranker: creator.publicKey,
+        collection,
+        whirlpool,
+        position,
+        positionTokenAccount,
+        tickArrayLower,
+        tickArrayUpper,
+        tokenMintA: collectionMint,
+        tokenMintB: capgmMint,
+        tokenVaultA,
+        tokenVaultB,
+        // ... remaining Orca and reserve accounts
+      })
+      .rpc();
+
+    const state = await program.account.collectionState.fetch(collection);
+    console.log("Owner LP fees (collection token):", state.ownerRewardBalance.toString());
+    console.log("Owner LP fees (CAPGM):", state.ownerCapgmRewardBalance.toString());
+    console.log("Unclaimed staker LP fees (CAPGM):", state.stakerCapgmRewardBalance.toString());
+    */
+
+    console.log("⚠️  Skipping LP fee collection (requires Orca program)");
+  });
+
+  it("Step 7: Rebalances the position to a new tick range (SKIP if Orca not available)", async () => {
+    // NOTE: This test will fail without actual Orca program
+    // Uncomment when testing with real Orca program on devnet
+
+    /*
+    const liquidityAccounts = {
+      authority: creator.publicKey,
+      collection,
+      whirlpool,
+      position,
+      positionTokenAccount,
+      tickArrayLower,
+      tickArrayUpper,
+      tokenMintA: collectionMint,
+      tokenMintB: capgmMint,
+      tokenVaultA,
+      tokenVaultB,
+      // ... remaining Orca and reserve accounts
+    };
+
+    // 1. Withdraw everything into the collection reserves (min amounts bound slippage)
+    await program.methods
+      .decreaseOrcaLiquidity(positionLiquidity, minA, minB)
+      .accountsPartial(liquidityAccounts)
+      .rpc();
+
+    // 2. Harvest outstanding fees, then close the empty position
+    // 3. Re-open at the new ticks with openOrcaPosition
+    // 4. Re-deposit from the reserves (max amounts bound slippage)
+    await program.methods
+      .increaseOrcaLiquidity(newLiquidity, maxA, maxB)
+      .accountsPartial({ ...liquidityAccounts, position: newPosition, positionTokenAccount: newPositionTokenAccount })
+      .rpc();
+
+    // A second withdrawal inside LIQUIDITY_REBALANCE_COOLDOWN_SECONDS is rejected
+    try {
+      await program.methods
+        .decreaseOrcaLiquidity(newLiquidity, minA, minB)
+        .accountsPartial({ ...liquidityAccounts, position: newPosition, positionTokenAccount: newPositionTokenAccount })
+        .rpc();
+      expect.fail("Should have failed");
+    } catch (err: unknown) {
+      expect(err.toString()).to.include("TimelockNotElapsed");
+    }
+    */
+
+    console.log("⚠️  Skipping rebalance (requires Orca program)");
+  });
+
+  it("Step 8: Purchases access paying in CAPGM via the collection pool (SKIP if Orca not available)", async () => {
+    // NOTE: This test will fail without actual Orca program
+    // Uncomment when testing with real Orca program on devnet
+
+    /*
+    await program.methods
+      .purchaseAccessWithSwap(capgmAmountIn, minCollectionTokensOut, cidHash)
+      .accountsPartial({
+        purchase: {
+          purchaser: buyer.publicKey,
+          collection,
+          purchaserTokenAccount: buyerCollectionTokenAccount,
+          // ... remaining purchaseAccess accounts
+        },
+        capgmMint,
+        purchaserCapgmAccount: buyerCapgmAccount,
+        stablecoinMint: null,
+        purchaserStablecoinAccount: null,
+        whirlpool,
+        tokenVaultA,
+        tokenVaultB,
+        tickArray0,
+        tickArray1,
+        tickArray2,
+        oracle,
+      })
+      .signers([buyer, accessNftMint])
+      .rpc();
+
+    // Paying in the stablecoin adds stablecoinMint / purchaserStablecoinAccount and passes the
+    // stablecoin/CAPGM pool as remainingAccounts. An output below minCollectionTokensOut
+    // fails inside Orca before any split happens.
+    */
+
+    console.log("⚠️  Skipping swap purchase (requires Orca program)");
+  });
+
+  it("Step 9: Claims vested launch liquidity after the LP lock (SKIP if Orca not available)", async () => {
+    // NOTE: This test will fail without actual Orca program
+    // Uncomment when testing with real Orca program on devnet
+
+    /*
+    // Before LP_LOCK_PERIOD_SECONDS have passed since deposit_liquidity_to_orca, this fails with LiquidityLocked
+    await program.methods
+      .claimVestedLiquidity(liquidityToWithdraw, new anchor.BN(0), new anchor.BN(0))
+      .accountsPartial({
+        liquidity: {
+          authority: creator.publicKey,
+          collection,
+          position,
+          // ... remaining manageOrcaLiquidity accounts
+        },
+        ownerCapgmAccount: creatorCapgmAccount,
+      })
+      .signers([creator])
+      .rpc();
+
+    // CAPGM paid out is capped at the vested share of creatorCapgmDeposit and never exceeds
+    // the unlocked share of it; collection tokens withdrawn alongside stay in the reserve.
+    const state = await program.account.collectionState.fetch(collection);
+    expect(state.creatorCapgmWithdrawn.toNumber()).to.be.greaterThan(0);
+    */
+
+    console.log("⚠️  Skipping vested liquidity claim (requires Orca program)");
+  });
+
+  it("Step 10: Records pool price observations for TWAP pricing (SKIP if Orca not available)", async () => {
+    // NOTE: This test will fail without actual Orca program
+    // Uncomment when testing with real Orca program on devnet
+
+    /*
+    const [poolObservation] = getPriceObservationPDA(whirlpool);
+    await program.methods
+      .initializePriceObservation()
+      .accountsPartial({
+        admin: provider.wallet.publicKey, // admin only; the first sample anchors the clamp
+        whirlpool,
+        tokenMintA: collectionMint,
+        tokenMintB: capgmMint,
+        priceObservation: poolObservation,
+      })
+      .rpc();
+
+    // Permissionless crank; a second call within PRICE_OBSERVATION_INTERVAL_SECONDS fails
+    // with ObservationTooRecent
+    await program.methods
+      .recordPriceObservation()
+      .accountsPartial({ priceObservation: poolObservation, whirlpool })
+      .rpc();
+
+    // Collections with no oracleFeed then pass poolObservation and the stablecoin pool's
+    // observation to purchaseAccess. Until the buffer holds TWAP_MIN_SAMPLES spanning
+    // TWAP_MIN_WINDOW_SECONDS the purchase fails with InvalidOraclePrice; paying less than one access at the TWAP
+    // fails with InsufficientFunds.
+    */
+
+    console.log("⚠️  Skipping price observations (requires Orca program)");
+  });
+
   // Helper functions
   function calculateSqrtPriceX64(price: number): anchor.BN {
     const sqrtPrice = Math.sqrt(price);
@@ -3,16 +3,22 @@ import { SystemProgram } from "@solana/web3.js";
 import {
   program,
   admin,
+  user,
   treasury,
   capgmMint,
   setupAccounts,
   getGlobalStatePDA,
+  getPendingConfigChangePDA,
+  getModTicketPDA,
 } from "./helpers/setup";
 import {
   INDEXER_URL,
   REGISTRY_URL,
   MOD_STAKE_MIN,
   FEE_BASIS_POINTS,
+  WHIRLPOOLS_CONFIG,
+  REASON,
+  EVIDENCE_HASH,
 } from "./helpers/constants";
 
 describe("Protocol Initialization", () => {
@@ -35,6 +41,9 @@ describe("Protocol Initialization", () => {
     expect(globalState.moderatorStakeMinimum).to.not.be.null;
     expect(globalState.capgmMint).to.not.be.null;
     expect(globalState.feeBasisPoints).to.be.a('number');
+    expect(globalState.stakerSplitBps).to.be.a('number');
+    expect(globalState.creatorShareBps).to.be.a('number');
+    expect(globalState.stablecoinMint).to.not.be.null;
     
     // If protocol was just initialized, verify exact values
     // Otherwise, just verify it's initialized (may have been initialized with different values)
@@ -55,6 +64,7 @@ describe("Protocol Initialization", () => {
       expect(globalState.moderatorStakeMinimum.toString()).to.equal(MOD_STAKE_MIN.toString());
       expect(globalState.capgmMint.toString()).to.equal(capgmMint.publicKey.toString());
       expect(globalState.feeBasisPoints).to.equal(FEE_BASIS_POINTS);
+      expect(globalState.whirlpoolsConfig.toString()).to.equal(WHIRLPOOLS_CONFIG.toString());
     }
   });
 
@@ -68,7 +78,8 @@ describe("Protocol Initialization", () => {
           longUrl,
           REGISTRY_URL,
           MOD_STAKE_MIN,
-          FEE_BASIS_POINTS
+          FEE_BASIS_POINTS,
+          WHIRLPOOLS_CONFIG
         )
         .accountsPartial({
           admin: admin.publicKey,
@@ -97,7 +108,8 @@ describe("Protocol Initialization", () => {
           INDEXER_URL,
           longUrl,
           MOD_STAKE_MIN,
-          FEE_BASIS_POINTS
+          FEE_BASIS_POINTS,
+          WHIRLPOOLS_CONFIG
         )
         .accountsPartial({
           admin: admin.publicKey,
@@ -125,7 +137,8 @@ describe("Protocol Initialization", () => {
           INDEXER_URL,
           REGISTRY_URL,
           MOD_STAKE_MIN,
-          FEE_BASIS_POINTS
+          FEE_BASIS_POINTS,
+          WHIRLPOOLS_CONFIG
         )
         .accountsPartial({
           admin: admin.publicKey,
@@ -143,3 +156,338 @@ describe("Protocol Initialization", () => {
     }
   });
 });
+
+describe("Admin Handover", () => {
+  before(async () => {
+    await setupAccounts();
+    const { ensureProtocolInitialized } = await import("./helpers/setup");
+    await ensureProtocolInitialized();
+  });
+
+  it("Fails if a non-admin proposes a new admin", async () => {
+    const [globalStatePDA] = getGlobalStatePDA();
+
+    try {
+      await program.methods
+        .proposeAdmin(user.publicKey)
+        .accountsPartial({
+          admin: user.publicKey,
+          globalState: globalStatePDA,
+        })
+        .signers([user])
+        .rpc();
+      expect.fail("Should have failed");
+    } catch (err: unknown) {
+      expect(err.toString()).to.include("Unauthorized");
+    }
+  });
+
+  it("Only the pending admin can accept", async () => {
+    const [globalStatePDA] = getGlobalStatePDA();
+
+    // Nominate the current admin so later test files keep a working admin key
+    await program.methods
+      .proposeAdmin(admin.publicKey)
+      .accountsPartial({
+        admin: admin.publicKey,
+        globalState: globalStatePDA,
+      })
+      .signers([admin])
+      .rpc();
+
+    let globalState = await program.account.globalState.fetch(globalStatePDA);
+    expect(globalState.pendingAdmin?.toString()).to.equal(admin.publicKey.toString());
+
+    try {
+      await program.methods
+        .acceptAdmin()
+        .accountsPartial({
+          newAdmin: user.publicKey,
+          globalState: globalStatePDA,
+        })
+        .signers([user])
+        .rpc();
+      expect.fail("Should have failed");
+    } catch (err: unknown) {
+      expect(err.toString()).to.include("Unauthorized");
+    }
+
+    await program.methods
+      .acceptAdmin()
+      .accountsPartial({
+        newAdmin: admin.publicKey,
+        globalState: globalStatePDA,
+      })
+      .signers([admin])
+      .rpc();
+
+    globalState = await program.account.globalState.fetch(globalStatePDA);
+    expect(globalState.admin.toString()).to.equal(admin.publicKey.toString());
+    expect(globalState.pendingAdmin).to.be.null;
+  });
+
+  it("Fails to create a multisig with threshold above the signer count", async () => {
+    const [globalStatePDA] = getGlobalStatePDA();
+
+    try {
+      await program.methods
+        .createAdminMultisig([admin.publicKey, user.publicKey], 3)
+        .accountsPartial({
+          admin: admin.publicKey,
+          globalState: globalStatePDA,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([admin])
+        .rpc();
+      expect.fail("Should have failed");
+    } catch (err: unknown) {
+      expect(err.toString()).to.include("InvalidMultisigConfig");
+    }
+  });
+});
+
+describe("Timelocked Config Changes", () => {
+  before(async () => {
+    await setupAccounts();
+    const { ensureProtocolInitialized } = await import("./helpers/setup");
+    await ensureProtocolInitialized();
+  });
+
+  it("Queues fee changes instead of applying them", async () => {
+    const [globalStatePDA] = getGlobalStatePDA();
+    const [pendingPDA] = getPendingConfigChangePDA(globalStatePDA);
+    const before = await program.account.globalState.fetch(globalStatePDA);
+
+    await program.methods
+      .updateGlobalState(null, null, null, 500, null, { creatorShareBps: null, minCollectionSupply: null, maxCollectionSupply: null })
+      .accountsPartial({
+        admin: admin.publicKey,
+        globalState: globalStatePDA,
+        newTreasury: before.treasury,
+        newCapgmMint: before.capgmMint,
+        newStablecoinMint: before.stablecoinMint,
+        newStablecoinPool: before.stablecoinPool,
+        newWhirlpoolsConfig: before.whirlpoolsConfig,
+        pendingConfigChange: pendingPDA,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([admin])
+      .rpc();
+
+    const after = await program.account.globalState.fetch(globalStatePDA);
+    expect(after.feeBasisPoints).to.equal(before.feeBasisPoints);
+
+    const pending = await program.account.pendingConfigChange.fetch(pendingPDA);
+    expect(pending.update.feeBasisPoints).to.equal(500);
+    expect(pending.update.treasury).to.be.null;
+    expect(pending.executeAfter.toNumber()).to.be.greaterThan(pending.queuedAt.toNumber());
+  });
+
+  it("Fails to execute before the timelock elapses", async () => {
+    const [globalStatePDA] = getGlobalStatePDA();
+    const [pendingPDA] = getPendingConfigChangePDA(globalStatePDA);
+
+    try {
+      await program.methods
+        .executeConfigChange()
+        .accountsPartial({
+          executor: user.publicKey,
+          globalState: globalStatePDA,
+          rentPayer: admin.publicKey,
+          pendingConfigChange: pendingPDA,
+        })
+        .signers([user])
+        .rpc();
+      expect.fail("Should have failed");
+    } catch (err: unknown) {
+      expect(err.toString()).to.include("TimelockNotElapsed");
+    }
+  });
+
+  it("Only the admin can cancel a queued change", async () => {
+    const [globalStatePDA] = getGlobalStatePDA();
+    const [pendingPDA] = getPendingConfigChangePDA(globalStatePDA);
+
+    try {
+      await program.methods
+        .cancelConfigChange()
+        .accountsPartial({
+          canceller: user.publicKey,
+          globalState: globalStatePDA,
+          multisig: null,
+          adminAuthorization: null,
+          rentPayer: admin.publicKey,
+          pendingConfigChange: pendingPDA,
+        })
+        .signers([user])
+        .rpc();
+      expect.fail("Should have failed");
+    } catch (err: unknown) {
+      expect(err.toString()).to.include("Unauthorized");
+    }
+
+    await program.methods
+      .cancelConfigChange()
+      .accountsPartial({
+        canceller: admin.publicKey,
+        globalState: globalStatePDA,
+        multisig: null,
+        adminAuthorization: null,
+        rentPayer: admin.publicKey,
+        pendingConfigChange: pendingPDA,
+      })
+      .signers([admin])
+      .rpc();
+
+    const pendingInfo = await program.provider.connection.getAccountInfo(pendingPDA);
+    expect(pendingInfo).to.be.null;
+  });
+
+  it("Rejects fees above the protocol maximum", async () => {
+    const [globalStatePDA] = getGlobalStatePDA();
+    const [pendingPDA] = getPendingConfigChangePDA(globalStatePDA);
+    const current = await program.account.globalState.fetch(globalStatePDA);
+
+    // MAX_PROTOCOL_FEE_BPS is 1000 (10%); a split outside 1000..=9000 is rejected the same way
+    for (const [fee, split] of [[1001, null], [null, 9500]]) {
+      try {
+        await program.methods
+          .updateGlobalState(null, null, null, fee, split, { creatorShareBps: null, minCollectionSupply: null, maxCollectionSupply: null })
+          .accountsPartial({
+            admin: admin.publicKey,
+            globalState: globalStatePDA,
+            newTreasury: current.treasury,
+            newCapgmMint: current.capgmMint,
+            newStablecoinMint: current.stablecoinMint,
+            newStablecoinPool: current.stablecoinPool,
+            newWhirlpoolsConfig: current.whirlpoolsConfig,
+            pendingConfigChange: pendingPDA,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([admin])
+          .rpc();
+        expect.fail("Should have failed");
+      } catch (err: unknown) {
+        expect(err.toString()).to.include("InvalidFeeConfig");
+      }
+    }
+  });
+});
+
+describe("Emergency Pause", () => {
+  const PAUSE_MODERATION = 1 << 5;
+
+  before(async () => {
+    await setupAccounts();
+    const { ensureProtocolInitialized } = await import("./helpers/setup");
+    await ensureProtocolInitialized();
+  });
+
+  it("Blocks instructions in a paused scope", async () => {
+    const [globalStatePDA] = getGlobalStatePDA();
+
+    await program.methods
+      .setPauseFlags(PAUSE_MODERATION)
+      .accountsPartial({ authority: admin.publicKey, globalState: globalStatePDA })
+      .signers([admin])
+      .rpc();
+
+    const targetId = `p${Date.now()}`.slice(0, 32);
+    const [ticketPDA] = getModTicketPDA(targetId);
+    try {
+      await program.methods
+        .createTicket(targetId, { contentReport: {} }, REASON, EVIDENCE_HASH, [], [])
+        .accountsPartial({
+          reporter: user.publicKey,
+          ticket: ticketPDA,
+          collection: null,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([user])
+        .rpc();
+      expect.fail("Should have failed");
+    } catch (err: unknown) {
+      expect(err.toString()).to.include("ProtocolPaused");
+    } finally {
+      await program.methods
+        .setPauseFlags(0)
+        .accountsPartial({ authority: admin.publicKey, globalState: globalStatePDA })
+        .signers([admin])
+        .rpc();
+    }
+  });
+
+  it("Guardian can pause but not unpause", async () => {
+    const [globalStatePDA] = getGlobalStatePDA();
+
+    await program.methods
+      .setGuardian(user.publicKey)
+      .accountsPartial({ admin: admin.publicKey, globalState: globalStatePDA })
+      .signers([admin])
+      .rpc();
+
+    await program.methods
+      .setPauseFlags(PAUSE_MODERATION)
+      .accountsPartial({ authority: user.publicKey, globalState: globalStatePDA })
+      .signers([user])
+      .rpc();
+
+    const globalState = await program.account.globalState.fetch(globalStatePDA);
+    expect(globalState.pausedFlags).to.equal(PAUSE_MODERATION);
+
+    try {
+      await program.methods
+        .setPauseFlags(0)
+        .accountsPartial({ authority: user.publicKey, globalState: globalStatePDA })
+        .signers([user])
+        .rpc();
+      expect.fail("Should have failed");
+    } catch (err: unknown) {
+      expect(err.toString()).to.include("Unauthorized");
+    } finally {
+      await program.methods
+        .setPauseFlags(0)
+        .accountsPartial({ authority: admin.publicKey, globalState: globalStatePDA })
+        .signers([admin])
+        .rpc();
+      await program.methods
+        .setGuardian(null)
+        .accountsPartial({ admin: admin.publicKey, globalState: globalStatePDA })
+        .signers([admin])
+        .rpc();
+    }
+  });
+});
+
+describe("Account Migrations", () => {
+  before(async () => {
+    await setupAccounts();
+    const { ensureProtocolInitialized } = await import("./helpers/setup");
+    await ensureProtocolInitialized();
+  });
+
+  it("Creates GlobalState on the current layout version", async () => {
+    const [globalStatePDA] = getGlobalStatePDA();
+    const globalState = await program.account.globalState.fetch(globalStatePDA);
+    expect(globalState.version).to.equal(1);
+  });
+
+  it("Fails to migrate an account that is already current", async () => {
+    const [globalStatePDA] = getGlobalStatePDA();
+
+    try {
+      await program.methods
+        .migrateGlobalState()
+        .accountsPartial({
+          payer: user.publicKey,
+          globalState: globalStatePDA,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([user])
+        .rpc();
+      expect.fail("Should have failed");
+    } catch (err: unknown) {
+      expect(err.toString()).to.include("AccountAlreadyMigrated");
+    }
+  });
+});
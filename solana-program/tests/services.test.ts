@@ -0,0 +1,202 @@
+import { expect } from "chai";
+import { PublicKey, SystemProgram } from "@solana/web3.js";
+import {
+  TOKEN_PROGRAM_ID,
+  createMint,
+  getAccount,
+  getAssociatedTokenAddressSync,
+  getOrCreateAssociatedTokenAccount,
+  mintTo,
+} from "@solana/spl-token";
+import { BN } from "@coral-xyz/anchor";
+import {
+  program,
+  admin,
+  user,
+  provider,
+  capgmMint,
+  treasury,
+  moderator,
+  setupAccounts,
+  getServiceRegistryPDA,
+  getModTicketPDA,
+  getModeratorStakePDA,
+  mintExistsAndValid,
+} from "./helpers/setup";
+
+describe("Service Registry", () => {
+  const MIN_BOND = new BN(100_000_000); // MIN_SERVICE_ENDPOINT_BOND
+  let registryPDA: PublicKey;
+  let bondVault: PublicKey;
+  let operatorCapgmAccount: PublicKey;
+
+  const registerAccounts = () => ({
+    operator: user.publicKey,
+    serviceRegistry: registryPDA,
+    operatorTokenAccount: operatorCapgmAccount,
+    bondVault,
+    capgmMint: capgmMint.publicKey,
+    tokenProgram: TOKEN_PROGRAM_ID,
+    systemProgram: SystemProgram.programId,
+  });
+
+  before(async () => {
+    await setupAccounts();
+    const { ensureProtocolInitialized } = await import("./helpers/setup");
+    await ensureProtocolInitialized();
+
+    // GlobalState stores capgmMint's address; make sure a real mint lives there
+    if (!(await mintExistsAndValid(capgmMint.publicKey))) {
+      await createMint(provider.connection, admin, admin.publicKey, null, 6, capgmMint);
+    }
+
+    [registryPDA] = getServiceRegistryPDA();
+    bondVault = getAssociatedTokenAddressSync(capgmMint.publicKey, registryPDA, true);
+    operatorCapgmAccount = (
+      await getOrCreateAssociatedTokenAccount(provider.connection, admin, capgmMint.publicKey, user.publicKey)
+    ).address;
+    await mintTo(provider.connection, admin, capgmMint.publicKey, operatorCapgmAccount, admin, 500_000_000);
+  });
+
+  it("Fails if a new endpoint posts less than the minimum bond", async () => {
+    try {
+      await program.methods
+        .registerServiceEndpoint({ indexer: {} }, "https://indexer.example.com", MIN_BOND.subn(1))
+        .accountsPartial(registerAccounts())
+        .signers([user])
+        .rpc();
+      expect.fail("Should have failed");
+    } catch (err: unknown) {
+      expect(err.toString()).to.include("InsufficientFunds");
+    }
+  });
+
+  it("Registers a bonded indexer endpoint and updates its URL", async () => {
+    await program.methods
+      .registerServiceEndpoint({ indexer: {} }, "https://indexer.example.com", MIN_BOND)
+      .accountsPartial(registerAccounts())
+      .signers([user])
+      .rpc();
+
+    await program.methods
+      .registerServiceEndpoint({ indexer: {} }, "https://indexer2.example.com", new BN(0))
+      .accountsPartial(registerAccounts())
+      .signers([user])
+      .rpc();
+
+    const registry = await program.account.serviceRegistry.fetch(registryPDA);
+    const endpoint = registry.endpoints.find((e) => e.operator.equals(user.publicKey));
+    expect(endpoint.url).to.equal("https://indexer2.example.com");
+    expect(endpoint.bond.toString()).to.equal(MIN_BOND.toString());
+  });
+
+  it("Keeps the bond in the vault while the endpoint unbonds", async () => {
+    await program.methods
+      .deregisterServiceEndpoint({ indexer: {} })
+      .accountsPartial({ operator: user.publicKey, serviceRegistry: registryPDA })
+      .signers([user])
+      .rpc();
+
+    const registry = await program.account.serviceRegistry.fetch(registryPDA);
+    const endpoint = registry.endpoints.find((e) => e.operator.equals(user.publicKey));
+    expect(endpoint.unbondingSince.toNumber()).to.be.greaterThan(0);
+
+    try {
+      await program.methods
+        .withdrawServiceBond({ indexer: {} })
+        .accountsPartial({
+          operator: user.publicKey,
+          serviceRegistry: registryPDA,
+          operatorTokenAccount: operatorCapgmAccount,
+          bondVault,
+          capgmMint: capgmMint.publicKey,
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .signers([user])
+        .rpc();
+      expect.fail("Should have failed");
+    } catch (err: unknown) {
+      expect(err.toString()).to.include("TimelockNotElapsed");
+    }
+  });
+
+  it("Re-registering cancels unbonding", async () => {
+    await program.methods
+      .registerServiceEndpoint({ indexer: {} }, "https://indexer2.example.com", new BN(0))
+      .accountsPartial(registerAccounts())
+      .signers([user])
+      .rpc();
+
+    const registry = await program.account.serviceRegistry.fetch(registryPDA);
+    const endpoint = registry.endpoints.find((e) => e.operator.equals(user.publicKey));
+    expect(endpoint.unbondingSince.toNumber()).to.equal(0);
+  });
+
+  it("Delists an endpoint only after its report is upheld", async function () {
+    const treasuryCapgmAccount = (
+      await getOrCreateAssociatedTokenAccount(provider.connection, admin, capgmMint.publicKey, treasury.publicKey)
+    ).address;
+    const targetId = `svc-${Date.now()}`.slice(0, 32);
+    const [ticketPDA] = getModTicketPDA(targetId);
+
+    try {
+      await program.methods
+        .reportServiceEndpoint(targetId, user.publicKey, { indexer: {} }, "serving forged manifests", Array(32).fill(1))
+        .accountsPartial({
+          reporter: admin.publicKey,
+          ticket: ticketPDA,
+          serviceRegistry: registryPDA,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([admin])
+        .rpc();
+    } catch (err: unknown) {
+      // Needs a registered CID-censorship moderator (set up by the moderation tests)
+      if (err.toString().includes("NoEligibleModerators")) return this.skip();
+      throw err;
+    }
+
+    const delist = () =>
+      program.methods
+        .delistServiceEndpoint(user.publicKey, { indexer: {} })
+        .accountsPartial({
+          executor: admin.publicKey,
+          ticket: ticketPDA,
+          serviceRegistry: registryPDA,
+          bondVault,
+          treasuryTokenAccount: treasuryCapgmAccount,
+          capgmMint: capgmMint.publicKey,
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .signers([admin])
+        .rpc();
+
+    try {
+      await delist();
+      expect.fail("Should have failed");
+    } catch (err: unknown) {
+      expect(err.toString()).to.include("TicketNotUpheld");
+    }
+
+    const ticket = await program.account.modTicket.fetch(ticketPDA);
+    if (!ticket.assignedModerators.some((m) => m.equals(moderator.publicKey))) return this.skip();
+
+    await program.methods
+      .resolveTicket(true)
+      .accountsPartial({
+        moderator: moderator.publicKey,
+        moderatorStake: getModeratorStakePDA(moderator.publicKey)[0],
+        ticket: ticketPDA,
+      })
+      .signers([moderator])
+      .rpc();
+
+    const before = await getAccount(provider.connection, treasuryCapgmAccount);
+    await delist();
+    const after = await getAccount(provider.connection, treasuryCapgmAccount);
+    expect((after.amount - before.amount).toString()).to.equal(MIN_BOND.toString());
+
+    const registry = await program.account.serviceRegistry.fetch(registryPDA);
+    expect(registry.endpoints.some((e) => e.operator.equals(user.publicKey))).to.be.false;
+  });
+});
@@ -192,4 +192,62 @@ describe("Moderator Staking", () => {
       }
     });
   });
+
+  describe("Register Moderator Specializations", () => {
+    let moderatorStakePDA: any;
+
+    before(async () => {
+      [moderatorStakePDA] = getModeratorStakePDA(moderator.publicKey);
+    });
+
+    it("Successfully registers specializations and jurisdictions", async () => {
+      const specializations = 0b010; // Copyright only
+      const jurisdictions = [Array.from(Buffer.from("US")), Array.from(Buffer.from("DE"))];
+
+      await program.methods
+        .registerModeratorSpecializations(specializations, jurisdictions)
+        .accountsPartial({
+          moderator: moderator.publicKey,
+          moderatorStake: moderatorStakePDA,
+        })
+        .signers([moderator])
+        .rpc();
+
+      const moderatorStake = await program.account.moderatorStake.fetch(moderatorStakePDA);
+      expect(moderatorStake.specializations).to.equal(specializations);
+      expect(moderatorStake.jurisdictions.length).to.equal(2);
+    });
+
+    it("Fails with an unknown specialization flag", async () => {
+      try {
+        await program.methods
+          .registerModeratorSpecializations(0b1000, [])
+          .accountsPartial({
+            moderator: moderator.publicKey,
+            moderatorStake: moderatorStakePDA,
+          })
+          .signers([moderator])
+          .rpc();
+        expect.fail("Should have failed");
+      } catch (err: unknown) {
+        expect(err.toString()).to.include("InvalidFeeConfig");
+      }
+    });
+
+    it("Fails with a lowercase jurisdiction code", async () => {
+      try {
+        await program.methods
+          .registerModeratorSpecializations(0b001, [Array.from(Buffer.from("us"))])
+          .accountsPartial({
+            moderator: moderator.publicKey,
+            moderatorStake: moderatorStakePDA,
+          })
+          .signers([moderator])
+          .rpc();
+        expect.fail("Should have failed");
+      } catch (err: unknown) {
+        expect(err.toString()).to.include("InvalidAccount");
+      }
+    });
+  });
 });
@@ -0,0 +1,306 @@
+import { expect } from "chai";
+import { PublicKey, SystemProgram } from "@solana/web3.js";
+import {
+  TOKEN_PROGRAM_ID,
+  createMint,
+  createAssociatedTokenAccount,
+  getAccount,
+  mintTo,
+} from "@solana/spl-token";
+import { BN } from "@coral-xyz/anchor";
+import {
+  program,
+  admin,
+  user,
+  provider,
+  setupAccounts,
+  getGlobalStatePDA,
+  getTreasuryPDA,
+  getTreasuryBudgetPDA,
+  getAdminMultisigPDA,
+  getAdminProposalPDA,
+  getAdminAuthorizationPDA,
+  accountExists,
+} from "./helpers/setup";
+
+describe("Protocol Treasury", () => {
+  let feeMint: PublicKey;
+  let treasuryPDA: PublicKey;
+  let treasuryVault: PublicKey;
+  let recipientTokenAccount: PublicKey;
+  const budgetName = `grants-${Date.now()}`.slice(0, 32);
+
+  const getPayoutPDA = (payoutId: BN): [PublicKey, number] =>
+    PublicKey.findProgramAddressSync(
+      [Buffer.from("treasury_payout"), payoutId.toArrayLike(Buffer, "le", 8)],
+      program.programId
+    );
+
+  before(async () => {
+    await setupAccounts();
+    const { ensureProtocolInitialized } = await import("./helpers/setup");
+    await ensureProtocolInitialized();
+
+    [treasuryPDA] = getTreasuryPDA();
+    if (!(await accountExists(treasuryPDA))) {
+      await program.methods
+        .initializeTreasury()
+        .accountsPartial({
+          admin: admin.publicKey,
+          globalState: getGlobalStatePDA()[0],
+          treasury: treasuryPDA,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([admin])
+        .rpc();
+    }
+
+    feeMint = await createMint(provider.connection, admin, admin.publicKey, null, 6);
+    treasuryVault = await createAssociatedTokenAccount(
+      provider.connection,
+      admin,
+      feeMint,
+      treasuryPDA,
+      undefined,
+      TOKEN_PROGRAM_ID,
+      undefined,
+      true // treasury is a PDA
+    );
+    await mintTo(provider.connection, admin, feeMint, treasuryVault, admin, 5_000);
+    recipientTokenAccount = await createAssociatedTokenAccount(
+      provider.connection,
+      admin,
+      feeMint,
+      user.publicKey
+    );
+  });
+
+  it("Fails if a non-admin creates a budget", async () => {
+    const [budgetPDA] = getTreasuryBudgetPDA(feeMint, "rogue");
+
+    try {
+      await program.methods
+        .createTreasuryBudget("rogue", { grant: {} }, new BN(1_000))
+        .accountsPartial({
+          admin: user.publicKey,
+          budget: budgetPDA,
+          mint: feeMint,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([user])
+        .rpc();
+      expect.fail("Should have failed");
+    } catch (err: unknown) {
+      expect(err.toString()).to.include("Unauthorized");
+    }
+  });
+
+  it("Disburses against a budget and records the payout", async () => {
+    const [budgetPDA] = getTreasuryBudgetPDA(feeMint, budgetName);
+
+    await program.methods
+      .createTreasuryBudget(budgetName, { grant: {} }, new BN(1_000))
+      .accountsPartial({
+        admin: admin.publicKey,
+        budget: budgetPDA,
+        mint: feeMint,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([admin])
+      .rpc();
+
+    const treasury = await program.account.protocolTreasury.fetch(treasuryPDA);
+    const [payoutPDA] = getPayoutPDA(treasury.payoutCount);
+
+    await program.methods
+      .disburseFromTreasury(new BN(600), "Grant #1: indexer hosting")
+      .accountsPartial({
+        admin: admin.publicKey,
+        treasury: treasuryPDA,
+        budget: budgetPDA,
+        treasuryVault,
+        recipientTokenAccount,
+        payout: payoutPDA,
+        mint: feeMint,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([admin])
+      .rpc();
+
+    const budget = await program.account.treasuryBudget.fetch(budgetPDA);
+    expect(budget.spent.toNumber()).to.equal(600);
+
+    const payout = await program.account.treasuryPayout.fetch(payoutPDA);
+    expect(payout.amount.toNumber()).to.equal(600);
+    expect(payout.recipient.toString()).to.equal(user.publicKey.toString());
+    expect(payout.memo).to.equal("Grant #1: indexer hosting");
+
+    const recipient = await getAccount(provider.connection, recipientTokenAccount);
+    expect(Number(recipient.amount)).to.equal(600);
+  });
+
+  it("Fails if a disbursement exceeds the remaining budget", async () => {
+    const [budgetPDA] = getTreasuryBudgetPDA(feeMint, budgetName);
+    const treasury = await program.account.protocolTreasury.fetch(treasuryPDA);
+    const [payoutPDA] = getPayoutPDA(treasury.payoutCount);
+
+    try {
+      await program.methods
+        .disburseFromTreasury(new BN(600), "Grant #2")
+        .accountsPartial({
+          admin: admin.publicKey,
+          treasury: treasuryPDA,
+          budget: budgetPDA,
+          treasuryVault,
+          recipientTokenAccount,
+          payout: payoutPDA,
+          mint: feeMint,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([admin])
+        .rpc();
+      expect.fail("Should have failed");
+    } catch (err: unknown) {
+      expect(err.toString()).to.include("BudgetExceeded");
+    }
+  });
+
+  describe("With the multisig as admin", () => {
+    const [multisigPDA] = getAdminMultisigPDA();
+    const relayedBudget = `ops-${Date.now()}`.slice(0, 32);
+
+    // Propose as admin, approve as user (2-of-2) and execute; returns the proposal PDA
+    const approveAction = async (action: any, authorization: boolean): Promise<PublicKey> => {
+      const multisig = await program.account.adminMultisig.fetch(multisigPDA);
+      const [proposalPDA] = getAdminProposalPDA(multisigPDA, multisig.proposalCount);
+
+      await program.methods
+        .proposeAdminAction(action)
+        .accountsPartial({
+          proposer: admin.publicKey,
+          multisig: multisigPDA,
+          proposal: proposalPDA,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([admin])
+        .rpc();
+      await program.methods
+        .approveAdminAction()
+        .accountsPartial({ signer: user.publicKey, multisig: multisigPDA, proposal: proposalPDA })
+        .signers([user])
+        .rpc();
+      await program.methods
+        .executeAdminAction()
+        .accountsPartial({
+          executor: admin.publicKey,
+          globalState: getGlobalStatePDA()[0],
+          multisig: multisigPDA,
+          proposal: proposalPDA,
+          pendingConfigChange: null,
+          adminAuthorization: authorization ? getAdminAuthorizationPDA(proposalPDA)[0] : null,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([admin])
+        .rpc();
+      return proposalPDA;
+    };
+
+    before(async () => {
+      await program.methods
+        .createAdminMultisig([admin.publicKey, user.publicKey], 2)
+        .accountsPartial({
+          admin: admin.publicKey,
+          globalState: getGlobalStatePDA()[0],
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([admin])
+        .rpc();
+    });
+
+    after(async () => {
+      // Hand admin back to the single key so later test files keep working
+      await approveAction({ proposeAdmin: { newAdmin: admin.publicKey } }, false);
+      await program.methods
+        .acceptAdmin()
+        .accountsPartial({ newAdmin: admin.publicKey, globalState: getGlobalStatePDA()[0] })
+        .signers([admin])
+        .rpc();
+    });
+
+    it("Fails if a former admin key signs directly", async () => {
+      const [budgetPDA] = getTreasuryBudgetPDA(feeMint, relayedBudget);
+
+      try {
+        await program.methods
+          .createTreasuryBudget(relayedBudget, { operations: {} }, new BN(500))
+          .accountsPartial({
+            admin: admin.publicKey,
+            budget: budgetPDA,
+            mint: feeMint,
+            adminAuthorization: null,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([admin])
+          .rpc();
+        expect.fail("Should have failed");
+      } catch (err: unknown) {
+        expect(err.toString()).to.include("Unauthorized");
+      }
+    });
+
+    it("Creates a budget with an authorization from an approved proposal", async () => {
+      const [budgetPDA] = getTreasuryBudgetPDA(feeMint, relayedBudget);
+      const proposalPDA = await approveAction(
+        {
+          createTreasuryBudget: {
+            mint: feeMint,
+            name: relayedBudget,
+            category: { operations: {} },
+            allocated: new BN(500),
+          },
+        },
+        true
+      );
+      const [authorizationPDA] = getAdminAuthorizationPDA(proposalPDA);
+
+      // Any signer may relay the authorization, but only for the approved arguments
+      try {
+        await program.methods
+          .createTreasuryBudget(relayedBudget, { operations: {} }, new BN(5_000))
+          .accountsPartial({
+            admin: user.publicKey,
+            budget: budgetPDA,
+            mint: feeMint,
+            adminAuthorization: authorizationPDA,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([user])
+          .rpc();
+        expect.fail("Should have failed");
+      } catch (err: unknown) {
+        expect(err.toString()).to.include("AuthorizationMismatch");
+      }
+
+      await program.methods
+        .createTreasuryBudget(relayedBudget, { operations: {} }, new BN(500))
+        .accountsPartial({
+          admin: user.publicKey,
+          budget: budgetPDA,
+          mint: feeMint,
+          adminAuthorization: authorizationPDA,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([user])
+        .rpc();
+
+      const budget = await program.account.treasuryBudget.fetch(budgetPDA);
+      expect(budget.allocated.toNumber()).to.equal(500);
+      expect(budget.approvedBy.toString()).to.equal(multisigPDA.toString());
+
+      // The authorization is consumed and cannot be replayed
+      expect(await accountExists(authorizationPDA)).to.be.false;
+    });
+  });
+});
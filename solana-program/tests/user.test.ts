@@ -1,6 +1,6 @@
 import { expect } from "chai";
-import { Keypair, SystemProgram, SYSVAR_RENT_PUBKEY } from "@solana/web3.js";
-import { TOKEN_PROGRAM_ID } from "@solana/spl-token";
+import { Keypair, PublicKey, SystemProgram, SYSVAR_RENT_PUBKEY } from "@solana/web3.js";
+import { TOKEN_PROGRAM_ID, TOKEN_2022_PROGRAM_ID, getTokenMetadata } from "@solana/spl-token";
 import {
   program,
   user,
@@ -9,6 +9,7 @@ import {
   getUserAccountPDA,
   getCollectionPDA,
   getMintPDA,
+  getGlobalStatePDA,
   provider,
 } from "./helpers/setup";
 import {
@@ -152,11 +153,12 @@ describe("User Account & Collection", () => {
       expect(collection.owner.toString()).to.equal(user.publicKey.toString());
       expect(collection.collectionId).to.equal(COLLECTION_ID);
       expect(collection.name).to.equal(COLLECTION_NAME);
-      expect(collection.contentCid).to.equal(CONTENT_CID);
+      expect(collection.version).to.equal(1);
       expect(collection.accessThresholdUsd.toString()).to.equal(ACCESS_THRESHOLD_USD.toString());
       expect(collection.rewardPoolBalance.toString()).to.equal("0");
       expect(collection.ownerRewardBalance.toString()).to.equal("0");
       expect(collection.stakerRewardBalance.toString()).to.equal("0");
+      expect(collection.launchStage).to.deep.equal({ created: {} });
     });
 
     it("Fails if collection_id exceeds MAX_ID_LEN", async () => {
@@ -285,4 +287,338 @@ describe("User Account & Collection", () => {
       }
     });
   });
+
+  describe("Collection Fee Overrides", () => {
+    const emptyUpdate = {
+      cidHash: null,
+      accessThresholdUsd: null,
+      oracleFeed: null,
+      totalVideos: null,
+      stakerSplitBps: null,
+      creatorRoyaltyBps: null,
+      lpFeeStakerBps: null,
+    };
+
+    before(async () => {
+      const { ensureProtocolInitialized, ensureUserAccountInitialized } = await import("./helpers/setup");
+      await ensureProtocolInitialized();
+      await ensureUserAccountInitialized(user);
+    });
+
+    it("Fails if staker split is outside protocol bounds", async () => {
+      const [collectionPDA] = getCollectionPDA(user.publicKey, COLLECTION_ID);
+
+      try {
+        await program.methods
+          .updateCollection({ ...emptyUpdate, stakerSplitBps: 9500 })
+          .accountsPartial({
+            owner: user.publicKey,
+            collection: collectionPDA,
+            globalState: getGlobalStatePDA()[0],
+          })
+          .signers([user])
+          .rpc();
+        expect.fail("Should have failed");
+      } catch (err: unknown) {
+        expect(err.toString()).to.include("InvalidFeeConfig");
+      }
+    });
+
+    it("Fails if creator share exceeds the protocol maximum", async () => {
+      const [collectionPDA] = getCollectionPDA(user.publicKey, COLLECTION_ID);
+
+      try {
+        await program.methods
+          .setCollectionFeeOverrides(2500)
+          .accountsPartial({
+            owner: user.publicKey,
+            collection: collectionPDA,
+          })
+          .signers([user])
+          .rpc();
+        expect.fail("Should have failed");
+      } catch (err: unknown) {
+        expect(err.toString()).to.include("InvalidFeeConfig");
+      }
+    });
+
+    it("Fails if stakers and royalty leave the peers escrow below its floor", async () => {
+      const [collectionPDA] = getCollectionPDA(user.publicKey, COLLECTION_ID);
+
+      try {
+        await program.methods
+          .updateCollection({ ...emptyUpdate, stakerSplitBps: 8000, creatorRoyaltyBps: 1500 })
+          .accountsPartial({
+            owner: user.publicKey,
+            collection: collectionPDA,
+            globalState: getGlobalStatePDA()[0],
+          })
+          .signers([user])
+          .rpc();
+        expect.fail("Should have failed");
+      } catch (err: unknown) {
+        expect(err.toString()).to.include("InvalidFeeConfig");
+      }
+    });
+
+    it("Sets a creator royalty before the first purchase", async () => {
+      const [collectionPDA] = getCollectionPDA(user.publicKey, COLLECTION_ID);
+      const [globalStatePDA] = getGlobalStatePDA();
+
+      await program.methods
+        .updateCollection({ ...emptyUpdate, creatorRoyaltyBps: 1000 })
+        .accountsPartial({
+          owner: user.publicKey,
+          collection: collectionPDA,
+          globalState: globalStatePDA,
+        })
+        .signers([user])
+        .rpc();
+
+      const collection = await program.account.collectionState.fetch(collectionPDA);
+      const globalState = await program.account.globalState.fetch(globalStatePDA);
+      expect(collection.creatorRoyaltyBps).to.equal(1000);
+      // The royalty pins the current protocol staker split
+      expect(collection.stakerSplitOverrideBps).to.equal(globalState.stakerSplitBps);
+    });
+
+    it("Keeps the royalty's pinned split when the creator share override is cleared", async () => {
+      const [collectionPDA] = getCollectionPDA(user.publicKey, COLLECTION_ID);
+      const [globalStatePDA] = getGlobalStatePDA();
+
+      await program.methods
+        .setCollectionFeeOverrides(null)
+        .accountsPartial({
+          owner: user.publicKey,
+          collection: collectionPDA,
+          globalState: globalStatePDA,
+        })
+        .signers([user])
+        .rpc();
+
+      const collection = await program.account.collectionState.fetch(collectionPDA);
+      const globalState = await program.account.globalState.fetch(globalStatePDA);
+      expect(collection.creatorRoyaltyBps).to.equal(1000);
+      expect(collection.stakerSplitOverrideBps).to.equal(globalState.stakerSplitBps);
+    });
+
+    for (const lpFeeStakerBps of [1_999, 8_001]) {
+      it(`Fails if the LP fee staker share is outside the protocol bounds (${lpFeeStakerBps} bps)`, async () => {
+        const [collectionPDA] = getCollectionPDA(user.publicKey, COLLECTION_ID);
+
+        try {
+          await program.methods
+            .updateCollection({ ...emptyUpdate, lpFeeStakerBps })
+            .accountsPartial({
+              owner: user.publicKey,
+              collection: collectionPDA,
+              globalState: getGlobalStatePDA()[0],
+            })
+            .signers([user])
+            .rpc();
+          expect.fail("Should have failed");
+        } catch (err: unknown) {
+          expect(err.toString()).to.include("InvalidFeeConfig");
+        }
+      });
+    }
+
+    it("Changes the LP fee staker share while nothing is staked", async () => {
+      const [collectionPDA] = getCollectionPDA(user.publicKey, COLLECTION_ID);
+
+      await program.methods
+        .updateCollection({ ...emptyUpdate, lpFeeStakerBps: 6000 })
+        .accountsPartial({
+          owner: user.publicKey,
+          collection: collectionPDA,
+          globalState: getGlobalStatePDA()[0],
+        })
+        .signers([user])
+        .rpc();
+
+      const collection = await program.account.collectionState.fetch(collectionPDA);
+      expect(collection.lpFeeStakerBps).to.equal(6000);
+    });
+
+    it("Publishes a new manifest and keeps the previous cid_hash", async () => {
+      const [collectionPDA] = getCollectionPDA(user.publicKey, COLLECTION_ID);
+      const before = await program.account.collectionState.fetch(collectionPDA);
+      const newCidHash = Array.from({ length: 32 }, (_, i) => (before.manifestVersion + i + 1) % 256);
+
+      await program.methods
+        .updateCollection({ ...emptyUpdate, cidHash: newCidHash })
+        .accountsPartial({
+          owner: user.publicKey,
+          collection: collectionPDA,
+          globalState: getGlobalStatePDA()[0],
+        })
+        .signers([user])
+        .rpc();
+
+      const collection = await program.account.collectionState.fetch(collectionPDA);
+      expect(collection.cidHash).to.deep.equal(newCidHash);
+      expect(collection.manifestVersion).to.equal(before.manifestVersion + 1);
+      expect(collection.previousCidHashes[collection.previousCidHashes.length - 1]).to.deep.equal(before.cidHash);
+    });
+
+    it("Grows total_videos but refuses to shrink it", async () => {
+      const [collectionPDA] = getCollectionPDA(user.publicKey, COLLECTION_ID);
+      const before = await program.account.collectionState.fetch(collectionPDA);
+      const grown = before.totalVideos + 9;
+
+      await program.methods
+        .updateCollection({ ...emptyUpdate, totalVideos: grown })
+        .accountsPartial({
+          owner: user.publicKey,
+          collection: collectionPDA,
+          globalState: getGlobalStatePDA()[0],
+        })
+        .signers([user])
+        .rpc();
+
+      const collection = await program.account.collectionState.fetch(collectionPDA);
+      expect(collection.totalVideos).to.equal(grown);
+      expect(collection.claimedShareBps.length).to.equal(grown);
+      expect(collection.censoredBitmap.length).to.equal(Math.ceil(grown / 8));
+
+      try {
+        await program.methods
+          .updateCollection({ ...emptyUpdate, totalVideos: before.totalVideos })
+          .accountsPartial({
+            owner: user.publicKey,
+            collection: collectionPDA,
+            globalState: getGlobalStatePDA()[0],
+          })
+          .signers([user])
+          .rpc();
+        expect.fail("Should have failed");
+      } catch (err: unknown) {
+        expect(err.toString()).to.include("InvalidVideoCount");
+      }
+    });
+  });
+
+  describe("Collection Metadata", () => {
+    const metadataId = "metadata-test-1";
+    let collectionPDA: PublicKey;
+    let mintPDA: PublicKey;
+
+    before(async () => {
+      const { ensureProtocolInitialized, ensureCollectionExists } = await import("./helpers/setup");
+      await ensureProtocolInitialized();
+      collectionPDA = await ensureCollectionExists(
+        provider.wallet.publicKey,
+        metadataId,
+        COLLECTION_NAME,
+        CONTENT_CID,
+        ACCESS_THRESHOLD_USD,
+      );
+      [mintPDA] = getMintPDA(collectionPDA);
+    });
+
+    it("Initializes mint metadata from the collection", async () => {
+      const metadata = await getTokenMetadata(provider.connection, mintPDA, "confirmed", TOKEN_2022_PROGRAM_ID);
+      expect(metadata.name).to.equal(COLLECTION_NAME);
+      expect(metadata.symbol).to.equal("METADATATE");
+      expect(metadata.uri.endsWith(`/collections/${metadataId}`)).to.equal(true);
+      expect(metadata.updateAuthority.toString()).to.equal(collectionPDA.toString());
+    });
+
+    it("Lets the owner update name and URI", async () => {
+      const uri = "https://example.com/collections/metadata-test-1.json";
+      await program.methods
+        .updateCollectionMetadata("Renamed Metadata", null, uri)
+        .accountsPartial({
+          owner: provider.wallet.publicKey,
+          collection: collectionPDA,
+          mint: mintPDA,
+          tokenProgram: TOKEN_2022_PROGRAM_ID,
+        })
+        .rpc();
+
+      const metadata = await getTokenMetadata(provider.connection, mintPDA, "confirmed", TOKEN_2022_PROGRAM_ID);
+      expect(metadata.name).to.equal("Renamed Metadata");
+      expect(metadata.symbol).to.equal("METADATATE");
+      expect(metadata.uri).to.equal(uri);
+      const collection = await program.account.collectionState.fetch(collectionPDA);
+      expect(collection.name).to.equal("Renamed Metadata");
+    });
+
+    it("Fails if a non-owner updates metadata", async () => {
+      try {
+        await program.methods
+          .updateCollectionMetadata(null, "HIJACK", null)
+          .accountsPartial({
+            owner: user.publicKey,
+            collection: collectionPDA,
+            mint: mintPDA,
+            tokenProgram: TOKEN_2022_PROGRAM_ID,
+          })
+          .signers([user])
+          .rpc();
+        expect.fail("Should have failed");
+      } catch (err: unknown) {
+        expect(err.toString()).to.include("Unauthorized");
+      }
+    });
+  });
+
+  describe("Launch Stages", () => {
+    const launchId = "launch-abort-test";
+    let collectionPDA: PublicKey;
+
+    before(async () => {
+      const { ensureProtocolInitialized, ensureCollectionExists } = await import("./helpers/setup");
+      await ensureProtocolInitialized();
+      collectionPDA = await ensureCollectionExists(
+        provider.wallet.publicKey,
+        launchId,
+        COLLECTION_NAME,
+        CONTENT_CID,
+        ACCESS_THRESHOLD_USD,
+      );
+    });
+
+    it("Aborts a launch before tokens are minted", async () => {
+      await program.methods
+        .abortLaunch()
+        .accountsPartial({
+          owner: provider.wallet.publicKey,
+          globalState: getGlobalStatePDA()[0],
+          collection: collectionPDA,
+          collectionMint: getMintPDA(collectionPDA)[0],
+          liquidityReserve: null,
+          capgmMint: (await program.account.globalState.fetch(getGlobalStatePDA()[0])).capgmMint,
+          capgmReserve: null,
+          ownerCapgmAccount: null,
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .rpc();
+
+      const collection = await program.account.collectionState.fetch(collectionPDA);
+      expect(collection.launchStage).to.deep.equal({ aborted: {} });
+    });
+
+    it("Fails to abort a launch twice", async () => {
+      try {
+        await program.methods
+          .abortLaunch()
+          .accountsPartial({
+            owner: provider.wallet.publicKey,
+            globalState: getGlobalStatePDA()[0],
+            collection: collectionPDA,
+            collectionMint: getMintPDA(collectionPDA)[0],
+            liquidityReserve: null,
+            capgmMint: (await program.account.globalState.fetch(getGlobalStatePDA()[0])).capgmMint,
+            capgmReserve: null,
+            ownerCapgmAccount: null,
+            tokenProgram: TOKEN_PROGRAM_ID,
+          })
+          .rpc();
+        expect.fail("Should have failed");
+      } catch (err: unknown) {
+        expect(err.toString()).to.include("InvalidLaunchStage");
+      }
+    });
+  });
 });